@@ -0,0 +1,466 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Business-day calendars with weekends and holidays.
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+
+use crate::naive::NaiveDate;
+use crate::{Datelike, Days, Weekday};
+
+/// A holiday that falls on a date computed for each year.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{HolidayRule, NaiveDate, Weekday};
+///
+/// let memorial_day = HolidayRule::nth_weekday(5, Weekday::Mon, -1).unwrap();
+/// assert_eq!(memorial_day.for_year(2024), NaiveDate::from_ymd_opt(2024, 5, 27));
+///
+/// let good_friday = HolidayRule::easter(-2).unwrap();
+/// assert_eq!(good_friday.for_year(2024), NaiveDate::from_ymd_opt(2024, 3, 29));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    kind: HolidayKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum HolidayKind {
+    Fixed { month: u32, day: u32 },
+    NthWeekday { month: u32, weekday: Weekday, n: i8 },
+    Easter { offset: i32 },
+}
+
+impl HolidayRule {
+    /// A holiday on the same month and day every year, such as December 25.
+    ///
+    /// A holiday on February 29 only occurs in leap years.
+    ///
+    /// Returns `None` if the month and day are invalid.
+    #[must_use]
+    pub const fn fixed(month: u32, day: u32) -> Option<HolidayRule> {
+        if NaiveDate::from_ymd_opt(2000, month, day).is_none() {
+            return None;
+        }
+        Some(HolidayRule { kind: HolidayKind::Fixed { month, day } })
+    }
+
+    /// A holiday on the `n`-th `weekday` of `month`, counted from the end of the month if `n` is
+    /// negative.
+    ///
+    /// For example the fourth Thursday of November is `nth_weekday(11, Weekday::Thu, 4)`, and the
+    /// last Monday of May is `nth_weekday(5, Weekday::Mon, -1)`. The holiday does not occur in
+    /// years where the month has no `n`-th `weekday`.
+    ///
+    /// Returns `None` if `month` is invalid, or if `n` is zero or not in the range `-5..=5`.
+    #[must_use]
+    pub const fn nth_weekday(month: u32, weekday: Weekday, n: i32) -> Option<HolidayRule> {
+        if month < 1 || month > 12 || n == 0 || n < -5 || n > 5 {
+            return None;
+        }
+        Some(HolidayRule { kind: HolidayKind::NthWeekday { month, weekday, n: n as i8 } })
+    }
+
+    /// A holiday `offset` days after Western Easter Sunday (see [`NaiveDate::easter`]), or before
+    /// it if `offset` is negative.
+    ///
+    /// For example Good Friday is `easter(-2)`, Easter Monday is `easter(1)` and Whit Monday is
+    /// `easter(50)`.
+    ///
+    /// Returns `None` if `offset` is not in the range `-80..=250`, which keeps the holiday in the
+    /// same year as Easter.
+    #[must_use]
+    pub const fn easter(offset: i32) -> Option<HolidayRule> {
+        if offset < -80 || offset > 250 {
+            return None;
+        }
+        Some(HolidayRule { kind: HolidayKind::Easter { offset } })
+    }
+
+    /// Returns the date of the holiday in `year`.
+    ///
+    /// Returns `None` if the holiday does not occur in `year`, or if the date would be out of
+    /// range.
+    #[must_use]
+    pub fn for_year(&self, year: i32) -> Option<NaiveDate> {
+        match self.kind {
+            HolidayKind::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayKind::NthWeekday { month, weekday, n } if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            HolidayKind::NthWeekday { month, weekday, n } => {
+                let count = match NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5) {
+                    Some(_) => 5,
+                    None => 4,
+                };
+                let n = u8::try_from(count + 1 + i32::from(n)).ok()?;
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+            }
+            HolidayKind::Easter { offset } => {
+                let easter = NaiveDate::easter(year)?;
+                easter.checked_add_signed(crate::TimeDelta::try_days(offset.into())?)
+            }
+        }
+    }
+}
+
+/// How to move a date that is not a business day to a business day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RollConvention {
+    /// The first business day on or after the date.
+    Following,
+    /// The first business day on or after the date, unless that is in the next month, then the
+    /// last business day before the date.
+    ModifiedFollowing,
+    /// The last business day on or before the date.
+    Preceding,
+    /// The last business day on or before the date, unless that is in the previous month, then
+    /// the first business day after the date.
+    ModifiedPreceding,
+}
+
+/// A calendar of business days, which are the days that are not on a weekend and not a holiday.
+///
+/// The weekend is Saturday and Sunday by default. Holidays are given as dates or as
+/// [`HolidayRule`]s that compute the date for each year.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{BusinessCalendar, HolidayRule, NaiveDate, RollConvention, Weekday};
+///
+/// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// let calendar = BusinessCalendar::new()
+///     .with_holiday_rules([
+///         HolidayRule::fixed(1, 1).unwrap(),
+///         HolidayRule::easter(-2).unwrap(),
+///         HolidayRule::nth_weekday(5, Weekday::Mon, -1).unwrap(),
+///         HolidayRule::fixed(12, 25).unwrap(),
+///     ])
+///     .with_holidays([from_ymd(2024, 12, 24)]);
+///
+/// // T+2 from Thursday skips Good Friday and the weekend
+/// assert_eq!(calendar.add_business_days(from_ymd(2024, 3, 28), 2), Some(from_ymd(2024, 4, 2)));
+/// assert_eq!(calendar.business_days_between(from_ymd(2024, 12, 23), from_ymd(2024, 12, 30)), 3);
+///
+/// assert!(!calendar.is_business_day(from_ymd(2024, 5, 27)));
+/// let roll = |date, convention| calendar.roll(date, convention);
+/// assert_eq!(roll(from_ymd(2024, 3, 30), RollConvention::Following), Some(from_ymd(2024, 4, 1)));
+/// assert_eq!(roll(from_ymd(2024, 3, 30), RollConvention::ModifiedFollowing), Some(from_ymd(2024, 3, 28)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar {
+    /// The weekend days, as bits numbered from Monday.
+    weekend: u8,
+    /// The holiday dates, sorted and without duplicates.
+    holidays: Vec<NaiveDate>,
+    rules: Vec<HolidayRule>,
+}
+
+impl BusinessCalendar {
+    /// Makes a new calendar with a weekend on Saturday and Sunday, and without holidays.
+    #[must_use]
+    pub const fn new() -> BusinessCalendar {
+        BusinessCalendar { weekend: WEEKEND_SAT_SUN, holidays: Vec::new(), rules: Vec::new() }
+    }
+
+    /// Returns the calendar with the weekend on the given days of the week.
+    #[must_use]
+    pub fn with_weekend<I: IntoIterator<Item = Weekday>>(self, weekend: I) -> BusinessCalendar {
+        let weekend = weekend.into_iter().fold(0, |bits, day| bits | weekday_bit(day));
+        BusinessCalendar { weekend, ..self }
+    }
+
+    /// Returns the calendar with the given dates added as holidays.
+    #[must_use]
+    pub fn with_holidays<I: IntoIterator<Item = NaiveDate>>(
+        mut self,
+        holidays: I,
+    ) -> BusinessCalendar {
+        self.holidays.extend(holidays);
+        self.holidays.sort_unstable();
+        self.holidays.dedup();
+        self
+    }
+
+    /// Returns the calendar with the given holiday rules added.
+    #[must_use]
+    pub fn with_holiday_rules<I: IntoIterator<Item = HolidayRule>>(
+        mut self,
+        rules: I,
+    ) -> BusinessCalendar {
+        self.rules.extend(rules);
+        self
+    }
+
+    /// Returns the holiday dates, in order.
+    #[must_use]
+    pub fn holidays(&self) -> &[NaiveDate] {
+        &self.holidays
+    }
+
+    /// Returns the holiday rules.
+    #[must_use]
+    pub fn holiday_rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+
+    /// Returns `true` if `date` is on a weekend.
+    #[must_use]
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend & weekday_bit(date.weekday()) != 0
+    }
+
+    /// Returns `true` if `date` is a holiday, from the holiday dates or rules.
+    #[must_use]
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.binary_search(&date).is_ok()
+            || self.rules.iter().any(|rule| rule.for_year(date.year()) == Some(date))
+    }
+
+    /// Returns `true` if `date` is neither on a weekend nor a holiday.
+    #[must_use]
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Moves `date` by `days` business days, forward if `days` is positive and backward if it is
+    /// negative.
+    ///
+    /// Only the business days after `date` are counted, so `date` does not need to be a business
+    /// day itself. Adding zero days returns `date`.
+    ///
+    /// Returns `None` if the result would be out of range, or if every day of the week is on the
+    /// weekend.
+    #[must_use]
+    pub fn add_business_days(&self, date: NaiveDate, days: i32) -> Option<NaiveDate> {
+        if days != 0 && self.weekend == WEEKEND_ALL {
+            return None;
+        }
+        let mut date = date;
+        for _ in 0..days.unsigned_abs() {
+            date = self.step_to_business_day(date, days > 0)?;
+        }
+        Some(date)
+    }
+
+    /// Returns the number of business days from `start` up to but not including `end`.
+    ///
+    /// The result is negative if `end` is before `start`.
+    #[must_use]
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        let (from, to, sign) = if start <= end { (start, end, 1) } else { (end, start, -1) };
+        let days = to.signed_duration_since(from).num_days();
+
+        let workdays_per_week = 7 - i64::from(self.weekend.count_ones());
+        let first_weekday = from.weekday().num_days_from_monday();
+        let remainder = (0..(days % 7) as u32)
+            .filter(|i| self.weekend & (1 << ((first_weekday + i) % 7)) == 0)
+            .count() as i64;
+        let mut count = days / 7 * workdays_per_week + remainder;
+
+        // holidays that are not on a weekend
+        let start_index = self.holidays.partition_point(|date| *date < from);
+        let end_index = self.holidays.partition_point(|date| *date < to);
+        let mut holidays = self.holidays[start_index..end_index].to_vec();
+        for year in from.year()..=to.year() {
+            holidays.extend(self.rules.iter().filter_map(|rule| rule.for_year(year)));
+        }
+        holidays.retain(|date| (from..to).contains(date) && !self.is_weekend(*date));
+        holidays.sort_unstable();
+        holidays.dedup();
+        count -= holidays.len() as i64;
+
+        sign * count
+    }
+
+    /// Moves `date` to a business day according to `convention`.
+    ///
+    /// Returns `date` if it is a business day. Returns `None` if the result would be out of
+    /// range, or if every day of the week is on the weekend.
+    #[must_use]
+    pub fn roll(&self, date: NaiveDate, convention: RollConvention) -> Option<NaiveDate> {
+        if self.is_business_day(date) {
+            return Some(date);
+        }
+        if self.weekend == WEEKEND_ALL {
+            return None;
+        }
+        let following = || self.step_to_business_day(date, true);
+        let preceding = || self.step_to_business_day(date, false);
+        match convention {
+            RollConvention::Following => following(),
+            RollConvention::Preceding => preceding(),
+            RollConvention::ModifiedFollowing => match following() {
+                Some(next) if next.month() == date.month() => Some(next),
+                _ => preceding(),
+            },
+            RollConvention::ModifiedPreceding => match preceding() {
+                Some(previous) if previous.month() == date.month() => Some(previous),
+                _ => following(),
+            },
+        }
+    }
+
+    /// Returns the first business day after `date`, or before it if `forward` is `false`.
+    fn step_to_business_day(&self, mut date: NaiveDate, forward: bool) -> Option<NaiveDate> {
+        loop {
+            date = match forward {
+                true => date.checked_add_days(Days::new(1))?,
+                false => date.checked_sub_days(Days::new(1))?,
+            };
+            if self.is_business_day(date) {
+                return Some(date);
+            }
+        }
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar::new()
+    }
+}
+
+const WEEKEND_SAT_SUN: u8 = 0b110_0000;
+const WEEKEND_ALL: u8 = 0b111_1111;
+
+const fn weekday_bit(weekday: Weekday) -> u8 {
+    1 << weekday.num_days_from_monday()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BusinessCalendar, HolidayRule, RollConvention};
+    use crate::{NaiveDate, Weekday};
+
+    fn us_calendar() -> BusinessCalendar {
+        BusinessCalendar::new().with_holiday_rules([
+            HolidayRule::fixed(1, 1).unwrap(),
+            HolidayRule::nth_weekday(1, Weekday::Mon, 3).unwrap(),
+            HolidayRule::easter(-2).unwrap(),
+            HolidayRule::nth_weekday(5, Weekday::Mon, -1).unwrap(),
+            HolidayRule::fixed(7, 4).unwrap(),
+            HolidayRule::nth_weekday(11, Weekday::Thu, 4).unwrap(),
+            HolidayRule::fixed(12, 25).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn test_holiday_rules() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(HolidayRule::fixed(2, 30), None);
+        assert_eq!(HolidayRule::fixed(2, 29).unwrap().for_year(2023), None);
+        assert_eq!(HolidayRule::fixed(2, 29).unwrap().for_year(2024), Some(ymd(2024, 2, 29)));
+        assert_eq!(HolidayRule::nth_weekday(13, Weekday::Mon, 1), None);
+        assert_eq!(HolidayRule::nth_weekday(1, Weekday::Mon, 0), None);
+        assert_eq!(HolidayRule::nth_weekday(1, Weekday::Mon, -6), None);
+        assert_eq!(HolidayRule::easter(251), None);
+
+        let thanksgiving = HolidayRule::nth_weekday(11, Weekday::Thu, 4).unwrap();
+        assert_eq!(thanksgiving.for_year(2024), Some(ymd(2024, 11, 28)));
+        // May 2021 has five Mondays, May 2024 has four
+        let last_monday = HolidayRule::nth_weekday(5, Weekday::Mon, -1).unwrap();
+        assert_eq!(last_monday.for_year(2021), Some(ymd(2021, 5, 31)));
+        assert_eq!(last_monday.for_year(2024), Some(ymd(2024, 5, 27)));
+        let fifth_monday = HolidayRule::nth_weekday(5, Weekday::Mon, 5).unwrap();
+        assert_eq!(fifth_monday.for_year(2024), None);
+        let fifth_last_monday = HolidayRule::nth_weekday(5, Weekday::Mon, -5).unwrap();
+        assert_eq!(fifth_last_monday.for_year(2021), Some(ymd(2021, 5, 3)));
+        assert_eq!(fifth_last_monday.for_year(2024), None);
+
+        assert_eq!(HolidayRule::easter(1).unwrap().for_year(2025), Some(ymd(2025, 4, 21)));
+        assert_eq!(HolidayRule::easter(50).unwrap().for_year(2024), Some(ymd(2024, 5, 20)));
+        assert_eq!(HolidayRule::fixed(1, 1).unwrap().for_year(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_business_calendar() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let calendar = us_calendar().with_holidays([ymd(2024, 12, 24), ymd(2024, 12, 24)]);
+        assert_eq!(calendar.holidays(), [ymd(2024, 12, 24)]);
+        assert_eq!(calendar.holiday_rules().len(), 7);
+        assert!(calendar.is_weekend(ymd(2024, 7, 6)));
+        assert!(calendar.is_holiday(ymd(2024, 7, 4)) && !calendar.is_weekend(ymd(2024, 7, 4)));
+        assert!(calendar.is_business_day(ymd(2024, 7, 5)));
+        assert!(!calendar.is_business_day(ymd(2024, 12, 24)));
+
+        let add = |date, days| calendar.add_business_days(date, days);
+        assert_eq!(add(ymd(2024, 7, 3), 1), Some(ymd(2024, 7, 5)));
+        assert_eq!(add(ymd(2024, 7, 3), 2), Some(ymd(2024, 7, 8)));
+        assert_eq!(add(ymd(2024, 7, 6), 1), Some(ymd(2024, 7, 8)));
+        assert_eq!(add(ymd(2024, 7, 8), -2), Some(ymd(2024, 7, 3)));
+        assert_eq!(add(ymd(2024, 7, 6), 0), Some(ymd(2024, 7, 6)));
+        assert_eq!(add(NaiveDate::MAX, 1), None);
+
+        let between = |start, end| calendar.business_days_between(start, end);
+        assert_eq!(between(ymd(2024, 7, 1), ymd(2024, 7, 8)), 4);
+        assert_eq!(between(ymd(2024, 7, 8), ymd(2024, 7, 1)), -4);
+        assert_eq!(between(ymd(2024, 7, 1), ymd(2024, 7, 1)), 0);
+        // 262 weekdays, and 8 holidays on weekdays (Christmas Eve and the rules)
+        assert_eq!(between(ymd(2024, 1, 1), ymd(2025, 1, 1)), 262 - 8);
+        for (start, end) in [((2023, 12, 20), (2024, 1, 17)), ((2024, 3, 27), (2024, 4, 3))] {
+            let (start, end) = (ymd(start.0, start.1, start.2), ymd(end.0, end.1, end.2));
+            let counted = start.iter_days().take_while(|d| *d < end);
+            let expected = counted.filter(|d| calendar.is_business_day(*d)).count() as i64;
+            assert_eq!(between(start, end), expected);
+        }
+    }
+
+    #[test]
+    fn test_business_calendar_weekend() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Friday and Saturday
+        let calendar = BusinessCalendar::new().with_weekend([Weekday::Fri, Weekday::Sat]);
+        assert!(calendar.is_weekend(ymd(2024, 7, 5)));
+        assert!(calendar.is_business_day(ymd(2024, 7, 7)));
+        assert_eq!(calendar.add_business_days(ymd(2024, 7, 4), 1), Some(ymd(2024, 7, 7)));
+        assert_eq!(calendar.business_days_between(ymd(2024, 7, 1), ymd(2024, 7, 15)), 10);
+
+        let never = BusinessCalendar::new().with_weekend([
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+        assert_eq!(never.add_business_days(ymd(2024, 7, 1), 1), None);
+        assert_eq!(never.roll(ymd(2024, 7, 1), RollConvention::Following), None);
+        assert_eq!(never.business_days_between(ymd(2024, 7, 1), ymd(2024, 8, 1)), 0);
+        assert_eq!(BusinessCalendar::default(), BusinessCalendar::new());
+    }
+
+    #[test]
+    fn test_roll_conventions() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let calendar = us_calendar();
+        let roll = |date, convention| calendar.roll(date, convention);
+        // Saturday, June 29 and Sunday, June 30
+        for day in [29, 30] {
+            let date = ymd(2024, 6, day);
+            assert_eq!(roll(date, RollConvention::Following), Some(ymd(2024, 7, 1)));
+            assert_eq!(roll(date, RollConvention::ModifiedFollowing), Some(ymd(2024, 6, 28)));
+            assert_eq!(roll(date, RollConvention::Preceding), Some(ymd(2024, 6, 28)));
+            assert_eq!(roll(date, RollConvention::ModifiedPreceding), Some(ymd(2024, 6, 28)));
+        }
+        // New Year's Day on Monday
+        let date = ymd(2024, 1, 1);
+        assert_eq!(roll(date, RollConvention::Preceding), Some(ymd(2023, 12, 29)));
+        assert_eq!(roll(date, RollConvention::ModifiedPreceding), Some(ymd(2024, 1, 2)));
+        assert_eq!(roll(date, RollConvention::ModifiedFollowing), Some(ymd(2024, 1, 2)));
+        // business days are not moved
+        let date = ymd(2024, 1, 2);
+        assert_eq!(roll(date, RollConvention::Preceding), Some(date));
+    }
+}
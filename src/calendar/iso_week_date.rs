@@ -0,0 +1,291 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! ISO 8601 week dates.
+
+use core::{fmt, str};
+
+use crate::format::{parse, Item, Numeric, Pad, ParseError, ParseResult, Parsed};
+use crate::naive::{IsoWeek, NaiveDate};
+use crate::{Datelike, Weekday};
+
+/// An ISO 8601 week date, made of an ISO week-numbering year, a week number and a weekday.
+///
+/// Weeks start on Monday, and week 1 of a year is the week with the first Thursday of the
+/// (Gregorian) year. A year has 52 or 53 weeks, and its first and last days can belong to a week
+/// of the previous or the next ISO year.
+///
+/// An `IsoWeekDate` has the same range as [`NaiveDate`], and converts to and from it with the
+/// `From` trait. Unlike [`IsoWeek`] it also holds the weekday, so it identifies a single day.
+///
+/// The `Display` and `FromStr` formats are the same as [`%G-W%V-%u`](crate::format::strftime).
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::IsoWeekDate;
+/// use chrono::{NaiveDate, Weekday};
+///
+/// let date = IsoWeekDate::from_isoywd_opt(2020, 53, Weekday::Fri).unwrap();
+/// assert_eq!(NaiveDate::from(date), NaiveDate::from_ymd_opt(2021, 1, 1).unwrap());
+/// assert_eq!(date.to_string(), "2020-W53-5");
+/// assert_eq!("2020-W53-5".parse::<IsoWeekDate>(), Ok(date));
+///
+/// let next = date.checked_add_weeks(1).unwrap();
+/// assert_eq!((next.year(), next.week(), next.weekday()), (2021, 1, Weekday::Fri));
+/// assert_eq!(IsoWeekDate::weeks_in_year(2020), 53);
+/// assert_eq!(IsoWeekDate::weeks_in_year(2021), 52);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeekDate {
+    date: NaiveDate,
+}
+
+impl IsoWeekDate {
+    /// Makes a new `IsoWeekDate` from the ISO week-numbering year, the week number (starting
+    /// from 1) and the weekday.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the year has no such week, or if the date is out of range for
+    /// `NaiveDate`.
+    #[must_use]
+    pub const fn from_isoywd_opt(year: i32, week: u32, weekday: Weekday) -> Option<IsoWeekDate> {
+        match NaiveDate::from_isoywd_opt(year, week, weekday) {
+            Some(date) => Some(IsoWeekDate { date }),
+            None => None,
+        }
+    }
+
+    /// Returns the ISO week-numbering year.
+    ///
+    /// This year number might not match the calendar year number of the date.
+    #[must_use]
+    pub fn year(&self) -> i32 {
+        self.date.iso_week().year()
+    }
+
+    /// Returns the week number, starting from 1.
+    #[must_use]
+    pub fn week(&self) -> u32 {
+        self.date.iso_week().week()
+    }
+
+    /// Returns the day of the week.
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    /// Returns the ISO week of the date.
+    #[must_use]
+    pub fn iso_week(&self) -> IsoWeek {
+        self.date.iso_week()
+    }
+
+    /// Returns the `NaiveDate` for the same day.
+    #[must_use]
+    pub const fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the date of the Monday of the week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the day is out of range for `NaiveDate`.
+    #[must_use]
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        self.date.add_days(-(self.date.weekday().num_days_from_monday() as i32))
+    }
+
+    /// Returns the date of the Sunday of the week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the day is out of range for `NaiveDate`.
+    #[must_use]
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        self.date.add_days(6 - self.date.weekday().num_days_from_monday() as i32)
+    }
+
+    /// Adds a number of weeks to the date, keeping the weekday.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub const fn checked_add_weeks(self, weeks: i32) -> Option<IsoWeekDate> {
+        let days = match weeks.checked_mul(7) {
+            Some(days) => days,
+            None => return None,
+        };
+        match self.date.add_days(days) {
+            Some(date) => Some(IsoWeekDate { date }),
+            None => None,
+        }
+    }
+
+    /// Subtracts a number of weeks from the date, keeping the weekday.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub const fn checked_sub_weeks(self, weeks: i32) -> Option<IsoWeekDate> {
+        match weeks.checked_neg() {
+            Some(weeks) => self.checked_add_weeks(weeks),
+            None => None,
+        }
+    }
+
+    /// Returns the number of weeks in the ISO week-numbering `year`, which is 52 or 53.
+    ///
+    /// A year has 53 weeks if it starts on a Thursday, or if it is a leap year that starts on a
+    /// Wednesday.
+    #[must_use]
+    pub const fn weeks_in_year(year: i32) -> u32 {
+        // The weekday of December 31, with 0 for Sunday.
+        const fn weekday_of_last_day(year: i64) -> i64 {
+            (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)).rem_euclid(7)
+        }
+        let year = year as i64;
+        if weekday_of_last_day(year) == 4 || weekday_of_last_day(year - 1) == 3 {
+            53
+        } else {
+            52
+        }
+    }
+}
+
+impl From<NaiveDate> for IsoWeekDate {
+    fn from(date: NaiveDate) -> Self {
+        IsoWeekDate { date }
+    }
+}
+
+impl From<IsoWeekDate> for NaiveDate {
+    fn from(date: IsoWeekDate) -> Self {
+        date.date
+    }
+}
+
+impl fmt::Debug for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// The `Display` output is the same as that of [`d.format("%G-W%V-%u")`](crate::format::strftime).
+impl fmt::Display for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, week) = (self.year(), self.week());
+        let weekday = self.weekday().number_from_monday();
+        if (0..=9999).contains(&year) {
+            write!(f, "{:04}-W{:02}-{}", year, week, weekday)
+        } else {
+            // ISO 8601 requires the explicit sign for out-of-range years
+            write!(f, "{:+05}-W{:02}-{}", year, week, weekday)
+        }
+    }
+}
+
+/// Parsing a `str` into an `IsoWeekDate` uses the format [`%G-W%V-%u`](crate::format::strftime).
+impl str::FromStr for IsoWeekDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<IsoWeekDate> {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Numeric(Numeric::IsoYear, Pad::Zero),
+            Item::Literal("-W"),
+            Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+            Item::Literal("-"),
+            Item::Numeric(Numeric::WeekdayFromMon, Pad::Zero),
+        ];
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        parsed.to_naive_date().map(IsoWeekDate::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IsoWeekDate;
+    use crate::format::ParseErrorKind;
+    use crate::{Datelike, NaiveDate, Weekday};
+
+    #[test]
+    fn test_iso_week_date() {
+        assert_eq!(IsoWeekDate::from_isoywd_opt(2021, 53, Weekday::Mon), None);
+        assert_eq!(IsoWeekDate::from_isoywd_opt(2021, 0, Weekday::Mon), None);
+
+        let date = IsoWeekDate::from_isoywd_opt(2025, 1, Weekday::Mon).unwrap();
+        assert_eq!(date.date(), NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+        assert_eq!((date.year(), date.week(), date.weekday()), (2025, 1, Weekday::Mon));
+        assert_eq!(date.iso_week(), date.date().iso_week());
+        assert_eq!(IsoWeekDate::from(date.date()), date);
+
+        let sunday = IsoWeekDate::from_isoywd_opt(2025, 1, Weekday::Sun).unwrap();
+        assert_eq!(sunday.first_day(), Some(date.date()));
+        assert_eq!(sunday.last_day(), NaiveDate::from_ymd_opt(2025, 1, 5));
+        assert_eq!(date.last_day(), sunday.last_day());
+        let first = IsoWeekDate::from(NaiveDate::MIN);
+        assert_eq!(first.first_day(), None);
+        assert_eq!(first.last_day().map(|d| d.weekday()), Some(Weekday::Sun));
+        assert_eq!(IsoWeekDate::from(NaiveDate::MAX).last_day(), None);
+
+        assert_eq!(date.checked_add_weeks(51).map(|d| (d.year(), d.week())), Some((2025, 52)));
+        assert_eq!(date.checked_add_weeks(52).map(|d| (d.year(), d.week())), Some((2026, 1)));
+        assert_eq!(date.checked_add_weeks(104).map(|d| (d.year(), d.week())), Some((2026, 53)));
+        assert_eq!(date.checked_sub_weeks(1).map(|d| (d.year(), d.week())), Some((2024, 52)));
+        assert_eq!(date.checked_add_weeks(i32::MAX), None);
+        assert_eq!(date.checked_sub_weeks(i32::MIN), None);
+        assert_eq!(first.checked_sub_weeks(1), None);
+    }
+
+    #[test]
+    fn test_weeks_in_year() {
+        for year in (-1000..3000).chain([NaiveDate::MIN.year() + 1, NaiveDate::MAX.year() - 1]) {
+            let last_week = NaiveDate::from_ymd_opt(year, 12, 28).unwrap().iso_week().week();
+            assert_eq!(IsoWeekDate::weeks_in_year(year), last_week, "{}", year);
+        }
+        assert_eq!(IsoWeekDate::weeks_in_year(2015), 53);
+        assert_eq!(IsoWeekDate::weeks_in_year(2024), 52);
+        assert_eq!(IsoWeekDate::weeks_in_year(2026), 53);
+        assert!(IsoWeekDate::weeks_in_year(i32::MIN) >= 52);
+        assert!(IsoWeekDate::weeks_in_year(i32::MAX) >= 52);
+    }
+
+    #[test]
+    fn test_iso_week_date_from_str() {
+        let date = |y, w, d| IsoWeekDate::from_isoywd_opt(y, w, d).unwrap();
+        assert_eq!("2020-W53-5".parse(), Ok(date(2020, 53, Weekday::Fri)));
+        assert_eq!("0001-W01-1".parse(), Ok(date(1, 1, Weekday::Mon)));
+        assert_eq!("-0001-W52-7".parse(), Ok(date(-1, 52, Weekday::Sun)));
+        assert_eq!("+12345-W10-3".parse(), Ok(date(12345, 10, Weekday::Wed)));
+
+        let parse = |s: &str| s.parse::<IsoWeekDate>().map_err(|e| e.kind());
+        assert_eq!(parse("2021-W53-1"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2021-W01-8"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2021-W01"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("2021-01-01"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2021-W01-1 "), Err(ParseErrorKind::TooLong));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_iso_week_date_display() {
+        for (y, m, d) in [(2020, 12, 31), (2021, 1, 3), (2024, 12, 30), (-1, 1, 1), (12345, 6, 7)] {
+            let naive = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let date = IsoWeekDate::from(naive);
+            let formatted = naive.format("%G-W%V-%u").to_string();
+            assert_eq!(date.to_string(), formatted);
+            assert_eq!(format!("{:?}", date), formatted);
+            assert_eq!(formatted.parse(), Ok(date));
+            assert_eq!(NaiveDate::parse_from_str(&date.to_string(), "%G-W%V-%u"), Ok(naive));
+        }
+        let date = IsoWeekDate::from_isoywd_opt(-1, 1, Weekday::Mon).unwrap();
+        assert_eq!(date.to_string(), "-0001-W01-1");
+    }
+}
@@ -0,0 +1,300 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Dates in the proleptic Julian calendar.
+
+use core::fmt;
+
+use crate::naive::date::{MAX_YEAR, MIN_YEAR};
+use crate::naive::NaiveDate;
+use crate::{Days, Weekday};
+
+/// A date in the proleptic Julian calendar.
+///
+/// The Julian calendar has a leap year every four years, without the exceptions for century years
+/// of the Gregorian calendar. It was in use before the Gregorian reform of 1582, and in some
+/// countries until the 20th century.
+///
+/// Like [`NaiveDate`] the calendar is proleptic: it extends to the years before its introduction,
+/// and year 0 is the year before year 1 (1 BCE). The supported years are the same as those of
+/// `NaiveDate`. Near the ends of that range a `JulianDate` can have no `NaiveDate` for the same
+/// day.
+///
+/// # Example
+///
+/// ```
+/// use chrono::calendar::JulianDate;
+/// use chrono::NaiveDate;
+///
+/// // The day after Julian 1582-10-04 was Gregorian 1582-10-15.
+/// let julian = JulianDate::from_ymd_opt(1582, 10, 4).unwrap();
+/// assert_eq!(julian.to_naive_date(), NaiveDate::from_ymd_opt(1582, 10, 14));
+/// assert_eq!(JulianDate::from(NaiveDate::from_ymd_opt(1582, 10, 15).unwrap()).day(), 5);
+///
+/// // 1900 is a leap year in the Julian calendar only
+/// assert!(JulianDate::from_ymd_opt(1900, 2, 29).is_some());
+/// assert_eq!(julian.to_string(), "1582-10-04");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl JulianDate {
+    /// Makes a new `JulianDate` from the year, month and day in the Julian calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the month or day is invalid, or if `year` is out of range for
+    /// `NaiveDate`.
+    #[must_use]
+    pub const fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<JulianDate> {
+        if year < MIN_YEAR || year > MAX_YEAR {
+            return None;
+        }
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(JulianDate { year, month, day })
+    }
+
+    /// Makes a new `JulianDate` from the number of days since January 1, 1 of the proleptic
+    /// Gregorian calendar, where that day is day 1.
+    ///
+    /// This is the same day number as used by [`NaiveDate::from_num_days_from_ce_opt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range.
+    #[must_use]
+    pub const fn from_num_days_from_ce_opt(days: i32) -> Option<JulianDate> {
+        // Julian 0001-01-01 is day -1, and the calendar repeats every 4 years of 1461 days.
+        let days = days as i64 + 1;
+        let (cycle, remainder) = (days.div_euclid(1461), days.rem_euclid(1461));
+        let year_of_cycle = if remainder / 365 < 3 { remainder / 365 } else { 3 };
+        let year = 1 + 4 * cycle + year_of_cycle;
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None;
+        }
+        let year = year as i32;
+        let mut day = (remainder - 365 * year_of_cycle) as u32 + 1;
+        let mut month = 1;
+        while day > days_in_month(year, month) {
+            day -= days_in_month(year, month);
+            month += 1;
+        }
+        Some(JulianDate { year, month, day })
+    }
+
+    /// Returns the number of days since January 1, 1 of the proleptic Gregorian calendar, where
+    /// that day is day 1.
+    ///
+    /// This is the same day number as returned by [`Datelike::num_days_from_ce`](crate::Datelike::num_days_from_ce) for a
+    /// `NaiveDate`, so two dates for the same day have the same number.
+    #[must_use]
+    pub const fn num_days_from_ce(&self) -> i32 {
+        let years = self.year - 1;
+        let mut ordinal = self.day;
+        let mut month = 1;
+        while month < self.month {
+            ordinal += days_in_month(self.year, month);
+            month += 1;
+        }
+        // Julian 0001-01-01 is day -1.
+        years * 365 + years.div_euclid(4) + ordinal as i32 - 2
+    }
+
+    /// Returns the `NaiveDate` for the same day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range for `NaiveDate`.
+    #[must_use]
+    pub const fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(self.num_days_from_ce())
+    }
+
+    /// Returns the year number.
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month number starting from 1.
+    #[must_use]
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month starting from 1.
+    #[must_use]
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the day of the week.
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        // day 1 is a Monday
+        match (self.num_days_from_ce() - 1).rem_euclid(7) {
+            0 => Weekday::Mon,
+            1 => Weekday::Tue,
+            2 => Weekday::Wed,
+            3 => Weekday::Thu,
+            4 => Weekday::Fri,
+            5 => Weekday::Sat,
+            _ => Weekday::Sun,
+        }
+    }
+
+    /// Returns `true` if the year is a leap year in the Julian calendar.
+    #[must_use]
+    pub const fn leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Add a duration in [`Days`] to the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub const fn checked_add_days(self, days: Days) -> Option<JulianDate> {
+        if days.0 > i32::MAX as u64 {
+            return None;
+        }
+        match self.num_days_from_ce().checked_add(days.0 as i32) {
+            Some(days) => JulianDate::from_num_days_from_ce_opt(days),
+            None => None,
+        }
+    }
+
+    /// Subtract a duration in [`Days`] from the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub const fn checked_sub_days(self, days: Days) -> Option<JulianDate> {
+        if days.0 > i32::MAX as u64 {
+            return None;
+        }
+        match self.num_days_from_ce().checked_sub(days.0 as i32) {
+            Some(days) => JulianDate::from_num_days_from_ce_opt(days),
+            None => None,
+        }
+    }
+}
+
+impl From<NaiveDate> for JulianDate {
+    fn from(date: NaiveDate) -> Self {
+        // The calendars differ by less than 2000 days within the range of `NaiveDate`, so the
+        // date is always in range.
+        JulianDate::from_num_days_from_ce_opt(date.num_days_from_ce())
+            .expect("Julian date out of range")
+    }
+}
+
+impl fmt::Debug for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// The `Display` output has the same format as that of [`NaiveDate`], `YYYY-MM-DD`.
+impl fmt::Display for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        } else {
+            // ISO 8601 requires the explicit sign for out-of-range years
+            write!(f, "{:+05}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+}
+
+const fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JulianDate;
+    use crate::{Datelike, Days, NaiveDate, Weekday};
+
+    #[test]
+    fn test_julian_date() {
+        assert_eq!(JulianDate::from_ymd_opt(2023, 2, 29), None);
+        assert_eq!(JulianDate::from_ymd_opt(2023, 13, 1), None);
+        assert_eq!(JulianDate::from_ymd_opt(2023, 4, 31), None);
+        assert_eq!(JulianDate::from_ymd_opt(2023, 1, 0), None);
+        assert!(JulianDate::from_ymd_opt(-4, 2, 29).unwrap().leap_year());
+        assert!(!JulianDate::from_ymd_opt(-1, 2, 28).unwrap().leap_year());
+        assert_eq!(JulianDate::from_ymd_opt(NaiveDate::MAX.year() + 1, 1, 1), None);
+
+        let julian = |y, m, d| JulianDate::from_ymd_opt(y, m, d).unwrap();
+        let gregorian = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        for (julian, gregorian) in [
+            (julian(1, 1, 3), gregorian(1, 1, 1)),
+            (julian(0, 12, 30), gregorian(0, 12, 28)),
+            (julian(-4712, 1, 1), gregorian(-4713, 11, 24)),
+            (julian(1582, 10, 5), gregorian(1582, 10, 15)),
+            (julian(1752, 9, 2), gregorian(1752, 9, 13)),
+            (julian(1900, 2, 29), gregorian(1900, 3, 13)),
+            (julian(1918, 1, 31), gregorian(1918, 2, 13)),
+            (julian(2024, 2, 29), gregorian(2024, 3, 13)),
+            (julian(2100, 2, 29), gregorian(2100, 3, 14)),
+        ] {
+            assert_eq!(julian.num_days_from_ce(), gregorian.num_days_from_ce());
+            assert_eq!(julian.to_naive_date(), Some(gregorian));
+            assert_eq!(JulianDate::from(gregorian), julian);
+            assert_eq!(julian.weekday(), gregorian.weekday());
+        }
+        assert_eq!(julian(1582, 10, 4).weekday(), Weekday::Thu);
+
+        // every day round-trips through the day number
+        let mut date = julian(-5, 1, 1);
+        for days in date.num_days_from_ce()..julian(5, 1, 1).num_days_from_ce() {
+            assert_eq!(date.num_days_from_ce(), days);
+            assert_eq!(JulianDate::from_num_days_from_ce_opt(days), Some(date));
+            date = date.checked_add_days(Days::new(1)).unwrap();
+        }
+        assert_eq!(date, julian(5, 1, 1));
+        assert_eq!(date.checked_sub_days(Days::new(366 + 365)), Some(julian(3, 1, 1)));
+
+        // the ends of the range
+        let min = JulianDate::from(NaiveDate::MIN);
+        let max = JulianDate::from(NaiveDate::MAX);
+        assert_eq!(min.to_naive_date(), Some(NaiveDate::MIN));
+        assert_eq!(max.to_naive_date(), Some(NaiveDate::MAX));
+        assert_eq!(min.checked_sub_days(Days::new(1)).unwrap().to_naive_date(), None);
+        let first = JulianDate::from_ymd_opt(NaiveDate::MIN.year(), 1, 1).unwrap();
+        assert_eq!(first.checked_sub_days(Days::new(1)), None);
+        assert_eq!(JulianDate::from_num_days_from_ce_opt(first.num_days_from_ce() - 1), None);
+        let last = JulianDate::from_ymd_opt(NaiveDate::MAX.year(), 12, 31).unwrap();
+        assert_eq!(last.checked_add_days(Days::new(1)), None);
+        assert_eq!(last.to_naive_date(), None);
+        assert_eq!(JulianDate::from_num_days_from_ce_opt(i32::MIN), None);
+        assert_eq!(JulianDate::from_num_days_from_ce_opt(i32::MAX), None);
+        assert_eq!(first.checked_add_days(Days::new(u64::MAX)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_julian_date_display() {
+        assert_eq!(JulianDate::from_ymd_opt(1582, 10, 4).unwrap().to_string(), "1582-10-04");
+        assert_eq!(JulianDate::from_ymd_opt(-1, 1, 1).unwrap().to_string(), "-0001-01-01");
+        assert_eq!(format!("{:?}", JulianDate::from_ymd_opt(12345, 6, 7).unwrap()), "+12345-06-07");
+    }
+}
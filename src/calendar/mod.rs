@@ -0,0 +1,18 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Dates in calendar systems other than the proleptic Gregorian calendar of [`NaiveDate`].
+//!
+//! Every date converts to and from a [`NaiveDate`] that represents the same day.
+//!
+//! - [`JulianDate`] is a date in the proleptic Julian calendar, as found in historical records.
+//! - [`IsoWeekDate`] is an ISO 8601 week date, made of an ISO week-numbering year, a week number
+//!   and a weekday.
+//!
+//! [`NaiveDate`]: crate::NaiveDate
+
+mod iso_week_date;
+mod julian;
+
+pub use self::iso_week_date::IsoWeekDate;
+pub use self::julian::JulianDate;
@@ -14,22 +14,22 @@ use core::{fmt, hash, str};
 #[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
-#[cfg(all(feature = "unstable-locales", feature = "alloc"))]
-use crate::format::Locale;
 use crate::format::{
     parse, parse_and_remainder, parse_rfc3339, Fixed, Item, ParseError, ParseResult, Parsed,
     StrftimeItems, TOO_LONG,
 };
+#[cfg(feature = "unstable-locales")]
+use crate::format::{parse_localized, Locale};
 #[cfg(feature = "alloc")]
 use crate::format::{write_rfc2822, write_rfc3339, DelayedFormat, SecondsFormat};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
+use crate::offset::{Disambiguation, FixedOffset, Offset, TimeZone, Utc};
 #[allow(deprecated)]
 use crate::Date;
 use crate::{expect, try_opt};
-use crate::{Datelike, Months, TimeDelta, Timelike, Weekday};
+use crate::{Datelike, Months, Period, TimeDelta, Timelike, Weekday};
 
 #[cfg(any(feature = "rkyv", feature = "rkyv-16", feature = "rkyv-32", feature = "rkyv-64"))]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -456,6 +456,7 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Returns `None` if:
     /// - The local time at the resulting date does not exist or is ambiguous, for example during a
     ///   daylight saving time transition.
+    ///   See [`DateTime::checked_add_span`] to resolve such a result instead.
     /// - The resulting UTC datetime would be out of range.
     /// - The resulting local datetime would be out of range (unless `months` is zero).
     #[must_use]
@@ -493,6 +494,7 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Returns `None` if:
     /// - The local time at the resulting date does not exist or is ambiguous, for example during a
     ///   daylight saving time transition.
+    ///   See [`DateTime::checked_sub_span`] to resolve such a result instead.
     /// - The resulting UTC datetime would be out of range.
     /// - The resulting local datetime would be out of range (unless `months` is zero).
     #[must_use]
@@ -513,6 +515,7 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Returns `None` if:
     /// - The local time at the resulting date does not exist or is ambiguous, for example during a
     ///   daylight saving time transition.
+    ///   See [`DateTime::checked_add_span`] to resolve such a result instead.
     /// - The resulting UTC datetime would be out of range.
     /// - The resulting local datetime would be out of range (unless `days` is zero).
     #[must_use]
@@ -536,6 +539,7 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Returns `None` if:
     /// - The local time at the resulting date does not exist or is ambiguous, for example during a
     ///   daylight saving time transition.
+    ///   See [`DateTime::checked_sub_span`] to resolve such a result instead.
     /// - The resulting UTC datetime would be out of range.
     /// - The resulting local datetime would be out of range (unless `days` is zero).
     #[must_use]
@@ -549,6 +553,86 @@ impl<Tz: TimeZone> DateTime<Tz> {
             .filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC)
     }
 
+    /// Adds a calendar span to the date and time, with the given [`CalendarArithmetic`].
+    ///
+    /// The span is added to the local date and time. With [`CalendarArithmetic::WallClock`] the
+    /// result keeps the same wall clock time, and a result that falls in a gap or a fold of the
+    /// time zone is resolved with the [`Disambiguation`] policy. With
+    /// [`CalendarArithmetic::Exact`] the result is the same elapsed time away as if the offset
+    /// never changed, so one day is always 24 hours.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The resulting local time does not exist or is ambiguous and the policy is
+    ///   [`Disambiguation::Reject`].
+    /// - The resulting UTC datetime would be out of range.
+    /// - The resulting local datetime would be out of range.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    #[cfg_attr(feature = "std", doc = "```")]
+    /// use chrono::{CalendarArithmetic, Days, Disambiguation, TimeZone, TzInfo};
+    ///
+    /// let prague = TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    /// let meeting = prague.with_ymd_and_hms(2024, 3, 30, 9, 0, 0).unwrap();
+    ///
+    /// let wall_clock = CalendarArithmetic::WallClock(Disambiguation::Compatible);
+    /// let next = meeting.clone().checked_add_span(Days::new(1), wall_clock).unwrap();
+    /// assert_eq!(next.to_rfc3339(), "2024-03-31T09:00:00+02:00");
+    ///
+    /// let next = meeting.checked_add_span(Days::new(1), CalendarArithmetic::Exact).unwrap();
+    /// assert_eq!(next.to_rfc3339(), "2024-03-31T10:00:00+02:00");
+    /// ```
+    #[must_use]
+    pub fn checked_add_span<S: CalendarSpan>(
+        self,
+        span: S,
+        arithmetic: CalendarArithmetic,
+    ) -> Option<DateTime<Tz>> {
+        self.map_local_with(|local| span.checked_add_to(local), arithmetic)
+    }
+
+    /// Subtracts a calendar span from the date and time, with the given [`CalendarArithmetic`].
+    ///
+    /// See [`DateTime::checked_add_span`] for the semantics.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The resulting local time does not exist or is ambiguous and the policy is
+    ///   [`Disambiguation::Reject`].
+    /// - The resulting UTC datetime would be out of range.
+    /// - The resulting local datetime would be out of range.
+    #[must_use]
+    pub fn checked_sub_span<S: CalendarSpan>(
+        self,
+        span: S,
+        arithmetic: CalendarArithmetic,
+    ) -> Option<DateTime<Tz>> {
+        self.map_local_with(|local| span.checked_sub_from(local), arithmetic)
+    }
+
+    /// Maps the local datetime with the given function, and converts the result back according
+    /// to `arithmetic`.
+    fn map_local_with<F>(&self, f: F, arithmetic: CalendarArithmetic) -> Option<DateTime<Tz>>
+    where
+        F: FnOnce(NaiveDateTime) -> Option<NaiveDateTime>,
+    {
+        let local = f(self.overflowing_naive_local())?;
+        let dt = match arithmetic {
+            CalendarArithmetic::WallClock(policy) => {
+                self.timezone().from_local_datetime_with(&local, policy)?
+            }
+            CalendarArithmetic::Exact => {
+                let utc = local.checked_sub_offset(self.offset.fix())?;
+                self.timezone().from_utc_datetime(&utc)
+            }
+        };
+        Some(dt).filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC && dt <= &DateTime::<Utc>::MAX_UTC)
+    }
+
     /// Subtracts another `DateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     #[inline]
@@ -936,6 +1020,118 @@ where
         .filter(|dt| dt >= &DateTime::<Utc>::MIN_UTC && dt <= &DateTime::<Utc>::MAX_UTC)
 }
 
+/// How a calendar span is added to a `DateTime` in a time zone with offset changes.
+///
+/// Used by [`DateTime::checked_add_span`] and [`DateTime::checked_sub_span`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CalendarArithmetic {
+    /// Add the span to the local wall clock time, and resolve a result that is ambiguous or
+    /// does not exist with the given policy.
+    ///
+    /// A recurring event at 09:00 stays at 09:00 when adding days or months across a daylight
+    /// saving time transition.
+    WallClock(Disambiguation),
+    /// Add the span as an exact duration, measured in the offset of the starting `DateTime`.
+    ///
+    /// One day is always 24 hours, and a month is as many 24 hour days as the calendar month
+    /// has. The wall clock time of the result can differ when crossing an offset change.
+    Exact,
+}
+
+/// A span of time that can be added to or subtracted from a local date and time.
+///
+/// This is implemented for [`Months`], [`Days`], [`TimeDelta`] and [`Period`], and is used by
+/// [`DateTime::checked_add_span`] and [`DateTime::checked_sub_span`].
+///
+/// This trait is sealed and can't be implemented outside of chrono.
+pub trait CalendarSpan: Copy + private::Sealed {
+    /// Adds the span to the local date and time, returning `None` if the result would be out of
+    /// range.
+    fn checked_add_to(self, local: NaiveDateTime) -> Option<NaiveDateTime>;
+
+    /// Subtracts the span from the local date and time, returning `None` if the result would be
+    /// out of range.
+    fn checked_sub_from(self, local: NaiveDateTime) -> Option<NaiveDateTime>;
+}
+
+/// Seals [`CalendarSpan`], and holds the span operations that are only used inside chrono.
+mod private {
+    use crate::{Days, Months, Period, TimeDelta};
+
+    pub trait Sealed: Sized {
+        /// Multiplies the span by `rhs`, returning `None` on overflow.
+        ///
+        /// Spans that can't be negative, such as `Days` and `Months`, return `None` if `rhs` is
+        /// negative.
+        fn checked_mul(self, rhs: i32) -> Option<Self>;
+    }
+
+    impl Sealed for Months {
+        fn checked_mul(self, rhs: i32) -> Option<Self> {
+            self.0.checked_mul(u32::try_from(rhs).ok()?).map(Months)
+        }
+    }
+
+    impl Sealed for Days {
+        fn checked_mul(self, rhs: i32) -> Option<Self> {
+            self.0.checked_mul(u64::try_from(rhs).ok()?).map(Days)
+        }
+    }
+
+    impl Sealed for TimeDelta {
+        fn checked_mul(self, rhs: i32) -> Option<Self> {
+            TimeDelta::checked_mul(&self, rhs)
+        }
+    }
+
+    impl Sealed for Period {
+        fn checked_mul(self, rhs: i32) -> Option<Self> {
+            Period::checked_mul(self, rhs)
+        }
+    }
+}
+
+impl CalendarSpan for Months {
+    fn checked_add_to(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_add_months(self)
+    }
+
+    fn checked_sub_from(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_sub_months(self)
+    }
+}
+
+impl CalendarSpan for Days {
+    fn checked_add_to(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_add_days(self)
+    }
+
+    fn checked_sub_from(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_sub_days(self)
+    }
+}
+
+impl CalendarSpan for TimeDelta {
+    fn checked_add_to(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_add_signed(self)
+    }
+
+    fn checked_sub_from(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_sub_signed(self)
+    }
+}
+
+impl CalendarSpan for Period {
+    fn checked_add_to(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_add_period(self)
+    }
+
+    fn checked_sub_from(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        local.checked_sub_period(self)
+    }
+}
+
 impl DateTime<FixedOffset> {
     /// Parses an RFC 2822 date-and-time string into a `DateTime<FixedOffset>` value.
     ///
@@ -988,7 +1184,10 @@ impl DateTime<FixedOffset> {
     /// values in a wide range of formats, only some of which represent actual date-and-time
     /// instances (rather than periods, ranges, dates, or times). Some valid ISO 8601 values are
     /// also simultaneously valid RFC 3339 values, but not all RFC 3339 values are valid ISO 8601
-    /// values (or the other way around).
+    /// values (or the other way around). Use [`format::iso8601::parse_iso8601`] to parse the
+    /// other ISO 8601 date and time representations.
+    ///
+    /// [`format::iso8601::parse_iso8601`]: crate::format::iso8601::parse_iso8601
     pub fn parse_from_rfc3339(s: &str) -> ParseResult<DateTime<FixedOffset>> {
         let mut parsed = Parsed::new();
         let (s, _) = parse_rfc3339(&mut parsed, s)?;
@@ -1033,6 +1232,67 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses a string with the specified format string and locale into a
+    /// `DateTime<FixedOffset>` value.
+    ///
+    /// Month and weekday names and AM/PM are parsed with the names of `locale`, ignoring case
+    /// and accents. See [`format::parse_localized`](crate::format::parse_localized) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, FixedOffset, Locale, TimeZone};
+    ///
+    /// let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap();
+    /// let fmt = "%A %e %B %Y, %T %z";
+    /// let formatted = dt.format_localized(fmt, Locale::fr_BE).to_string();
+    /// assert_eq!(formatted, "vendredi 28 novembre 2014, 12:00:09 +0100");
+    /// assert_eq!(DateTime::parse_from_str_localized(&formatted, fmt, Locale::fr_BE), Ok(dt));
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(
+        s: &str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_datetime()
+    }
+
+    /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value, taking
+    /// missing fields from `default`.
+    ///
+    /// Unlike [`parse_from_str`](#method.parse_from_str) the input does not need to contain an
+    /// offset or a complete date and time. A missing offset is taken from `default`, and the
+    /// missing date and time fields from the local date and time of `default`. See
+    /// [`Parsed::fill_missing_from_datetime`] for the exact rules.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let default = tz.with_ymd_and_hms(2024, 6, 15, 9, 10, 11).unwrap();
+    ///
+    /// let dt = DateTime::parse_from_str_with_default("14:30", "%H:%M", &default);
+    /// assert_eq!(dt, Ok(tz.with_ymd_and_hms(2024, 6, 15, 14, 30, 0).unwrap()));
+    ///
+    /// let dt = DateTime::parse_from_str_with_default("Mar 5 14:30 +0000", "%b %d %H:%M %z", &default);
+    /// assert_eq!(dt.unwrap().to_rfc3339(), "2024-03-05T14:30:00+00:00");
+    /// ```
+    pub fn parse_from_str_with_default<Tz: TimeZone>(
+        s: &str,
+        fmt: &str,
+        default: &DateTime<Tz>,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.fill_missing_from_datetime(default);
+        parsed.to_datetime()
+    }
+
     /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value, and a
     /// slice with the remaining portion of the string.
     ///
@@ -1723,6 +1983,44 @@ impl<Tz: TimeZone> Sub<Days> for DateTime<Tz> {
     }
 }
 
+/// Add a `Period` to `DateTime`.
+///
+/// The `Period` is added to the local date and time, see [`NaiveDateTime::checked_add_period`].
+/// A result that falls in a gap or fold of the time zone is resolved with
+/// [`Disambiguation::Compatible`].
+///
+/// # Panics
+///
+/// Panics if the resulting date would be out of range.
+/// Consider using `DateTime<Tz>::checked_add_span` to get an `Option` instead.
+impl<Tz: TimeZone> Add<Period> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    fn add(self, period: Period) -> Self::Output {
+        self.checked_add_span(period, CalendarArithmetic::WallClock(Disambiguation::Compatible))
+            .expect("`DateTime + Period` out of range")
+    }
+}
+
+/// Subtract a `Period` from `DateTime`.
+///
+/// The `Period` is subtracted from the local date and time, see
+/// [`NaiveDateTime::checked_sub_period`]. A result that falls in a gap or fold of the time zone
+/// is resolved with [`Disambiguation::Compatible`].
+///
+/// # Panics
+///
+/// Panics if the resulting date would be out of range.
+/// Consider using `DateTime<Tz>::checked_sub_span` to get an `Option` instead.
+impl<Tz: TimeZone> Sub<Period> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    fn sub(self, period: Period) -> Self::Output {
+        self.checked_sub_span(period, CalendarArithmetic::WallClock(Disambiguation::Compatible))
+            .expect("`DateTime - Period` out of range")
+    }
+}
+
 impl<Tz: TimeZone> fmt::Debug for DateTime<Tz> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.overflowing_naive_local().fmt(f)?;
@@ -1213,6 +1213,1220 @@ pub mod ts_seconds_option {
     }
 }
 
+/// Ser/de to/from timestamps in nanoseconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_nanoseconds`], but for a `DateTime<Tz>` where `Tz` can be any time zone for
+/// which there is a `From<DateTime<Utc>>` implementation. Only the timestamp is serialized, so the
+/// offset of a deserialized value is the one that conversion gives.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_nanoseconds_tz;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_nanoseconds_tz")]
+///     time: DateTime<FixedOffset>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap();
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000000000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_nanoseconds_tz {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of nanoseconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        super::ts_nanoseconds::serialize(&dt.with_timezone(&Utc), serializer)
+    }
+
+    /// Deserialize a `DateTime` from a nanoseconds timestamp
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        super::ts_nanoseconds::deserialize(d).map(DateTime::from)
+    }
+}
+
+/// Ser/de to/from optional timestamps in nanoseconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_nanoseconds_option`], but for an `Option<DateTime<Tz>>` where `Tz` can be any
+/// time zone for which there is a `From<DateTime<Utc>>` implementation.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_nanoseconds_tz_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_nanoseconds_tz_option")]
+///     time: Option<DateTime<FixedOffset>>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = Some(offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap());
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000000000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_nanoseconds_tz_option {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of nanoseconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        let utc = opt.as_ref().map(|dt| dt.with_timezone(&Utc));
+        super::ts_nanoseconds_option::serialize(&utc, serializer)
+    }
+
+    /// Deserialize a `DateTime` from a nanoseconds timestamp or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        Ok(super::ts_nanoseconds_option::deserialize(d)?.map(DateTime::from))
+    }
+}
+
+/// Ser/de to/from timestamps in microseconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_microseconds`], but for a `DateTime<Tz>` where `Tz` can be any time zone for
+/// which there is a `From<DateTime<Utc>>` implementation. Only the timestamp is serialized, so the
+/// offset of a deserialized value is the one that conversion gives.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_microseconds_tz;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_microseconds_tz")]
+///     time: DateTime<FixedOffset>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap();
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_microseconds_tz {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of microseconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        super::ts_microseconds::serialize(&dt.with_timezone(&Utc), serializer)
+    }
+
+    /// Deserialize a `DateTime` from a microseconds timestamp
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        super::ts_microseconds::deserialize(d).map(DateTime::from)
+    }
+}
+
+/// Ser/de to/from optional timestamps in microseconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_microseconds_option`], but for an `Option<DateTime<Tz>>` where `Tz` can be any
+/// time zone for which there is a `From<DateTime<Utc>>` implementation.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_microseconds_tz_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_microseconds_tz_option")]
+///     time: Option<DateTime<FixedOffset>>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = Some(offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap());
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_microseconds_tz_option {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of microseconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        let utc = opt.as_ref().map(|dt| dt.with_timezone(&Utc));
+        super::ts_microseconds_option::serialize(&utc, serializer)
+    }
+
+    /// Deserialize a `DateTime` from a microseconds timestamp or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        Ok(super::ts_microseconds_option::deserialize(d)?.map(DateTime::from))
+    }
+}
+
+/// Ser/de to/from timestamps in milliseconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_milliseconds`], but for a `DateTime<Tz>` where `Tz` can be any time zone for
+/// which there is a `From<DateTime<Utc>>` implementation. Only the timestamp is serialized, so the
+/// offset of a deserialized value is the one that conversion gives.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_milliseconds_tz;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_milliseconds_tz")]
+///     time: DateTime<FixedOffset>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap();
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_milliseconds_tz {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of milliseconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        super::ts_milliseconds::serialize(&dt.with_timezone(&Utc), serializer)
+    }
+
+    /// Deserialize a `DateTime` from a milliseconds timestamp
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        super::ts_milliseconds::deserialize(d).map(DateTime::from)
+    }
+}
+
+/// Ser/de to/from optional timestamps in milliseconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_milliseconds_option`], but for an `Option<DateTime<Tz>>` where `Tz` can be any
+/// time zone for which there is a `From<DateTime<Utc>>` implementation.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_milliseconds_tz_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_milliseconds_tz_option")]
+///     time: Option<DateTime<FixedOffset>>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = Some(offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap());
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_milliseconds_tz_option {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of milliseconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        let utc = opt.as_ref().map(|dt| dt.with_timezone(&Utc));
+        super::ts_milliseconds_option::serialize(&utc, serializer)
+    }
+
+    /// Deserialize a `DateTime` from a milliseconds timestamp or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        Ok(super::ts_milliseconds_option::deserialize(d)?.map(DateTime::from))
+    }
+}
+
+/// Ser/de to/from timestamps in seconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_seconds`], but for a `DateTime<Tz>` where `Tz` can be any time zone for
+/// which there is a `From<DateTime<Utc>>` implementation. Only the timestamp is serialized, so the
+/// offset of a deserialized value is the one that conversion gives.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_tz;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_tz")]
+///     time: DateTime<FixedOffset>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap();
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_tz {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of seconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        super::ts_seconds::serialize(&dt.with_timezone(&Utc), serializer)
+    }
+
+    /// Deserialize a `DateTime` from a seconds timestamp
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        super::ts_seconds::deserialize(d).map(DateTime::from)
+    }
+}
+
+/// Ser/de to/from optional timestamps in seconds, for a `DateTime` in any time zone
+///
+/// Works like [`ts_seconds_option`], but for an `Option<DateTime<Tz>>` where `Tz` can be any
+/// time zone for which there is a `From<DateTime<Utc>>` implementation.
+///
+/// Intended for use with `serde`s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_tz_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_tz_option")]
+///     time: Option<DateTime<FixedOffset>>,
+/// }
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let time = Some(offset.with_ymd_and_hms(2015, 5, 15, 11, 0, 0).unwrap());
+/// let my_s = S { time };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_tz_option {
+    use serde::{de, ser};
+
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into an integer number of seconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        let utc = opt.as_ref().map(|dt| dt.with_timezone(&Utc));
+        super::ts_seconds_option::serialize(&utc, serializer)
+    }
+
+    /// Deserialize a `DateTime` from a seconds timestamp or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        Ok(super::ts_seconds_option::deserialize(d)?.map(DateTime::from))
+    }
+}
+
+/// Ser/de to/from timestamps in seconds as a floating point number
+///
+/// Intended for use with `serde`'s `with` attribute. This is the format of a Python
+/// `datetime.timestamp()`, or a JavaScript `Date.now() / 1000`.
+///
+/// An `f64` has about 16 significant digits, so for current dates the timestamp is only precise
+/// to about a microsecond. Deserializing rounds to the nearest nanosecond.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, NaiveDate, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_f64;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "ts_seconds_f64")]
+///     time: DateTime<Utc>,
+/// }
+///
+/// let time = NaiveDate::from_ymd_opt(2018, 5, 17)
+///     .unwrap()
+///     .and_hms_milli_opt(02, 04, 59, 500)
+///     .unwrap()
+///     .and_local_timezone(Utc)
+///     .unwrap();
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1526522699.5}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_f64 {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use crate::serde::invalid_ts;
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into a floating point number of seconds since the epoch
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::ts_seconds_f64::serialize as to_f64_ts;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_f64_ts")]
+    ///     time: DateTime<FixedOffset>,
+    /// }
+    ///
+    /// let offset = FixedOffset::east_opt(3600).unwrap();
+    /// let my_s = S { time: offset.timestamp_opt(1431684000, 250_000_000).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":1431684000.25}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        serializer.serialize_f64(to_f64(dt))
+    }
+
+    /// Deserialize a `DateTime` from a floating point seconds timestamp
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::ts_seconds_f64::deserialize as from_f64_ts;
+    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_f64_ts")]
+    ///     time: DateTime<Utc>,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": 1431684000.25 }"#)?;
+    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(1431684000, 250_000_000).unwrap() });
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": -0.5 }"#)?;
+    /// assert_eq!(my_s, S { time: Utc.timestamp_opt(-1, 500_000_000).unwrap() });
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        d.deserialize_f64(SecondsF64TimestampVisitor).map(DateTime::from)
+    }
+
+    pub(super) fn to_f64<Tz: TimeZone>(dt: &DateTime<Tz>) -> f64 {
+        dt.timestamp() as f64 + f64::from(dt.timestamp_subsec_nanos()) / 1e9
+    }
+
+    pub(super) struct SecondsF64TimestampVisitor;
+
+    impl<'de> de::Visitor<'de> for SecondsF64TimestampVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in seconds")
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // `as` saturates, so check the range first. `f64::floor` is not available in `core`.
+            if !(value > i64::MIN as f64 && value < i64::MAX as f64) {
+                return Err(invalid_ts(value));
+            }
+            let mut secs = value as i64;
+            if secs as f64 > value {
+                secs -= 1;
+            }
+            let nanos = ((value - secs as f64) * 1e9 + 0.5) as u32;
+            let (secs, nanos) = match nanos {
+                1_000_000_000.. => (secs + 1, nanos - 1_000_000_000),
+                _ => (secs, nanos),
+            };
+            DateTime::from_timestamp(secs, nanos).ok_or_else(|| invalid_ts(value))
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::from_timestamp(value, 0).ok_or_else(|| invalid_ts(value))
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            i64::try_from(value)
+                .ok()
+                .and_then(|value| DateTime::from_timestamp(value, 0))
+                .ok_or_else(|| invalid_ts(value))
+        }
+    }
+}
+
+/// Ser/de to/from optional timestamps in seconds as a floating point number
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, TimeZone, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::ts_seconds_f64_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(default, with = "ts_seconds_f64_option")]
+///     time: Option<DateTime<Utc>>,
+/// }
+///
+/// let time = Some(Utc.timestamp_opt(1431684000, 250_000_000).unwrap());
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":1431684000.25}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// let my_s: S = serde_json::from_str(r#"{}"#)?;
+/// assert_eq!(my_s.time, None);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ts_seconds_f64_option {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::ts_seconds_f64::{to_f64, SecondsF64TimestampVisitor};
+    use crate::{DateTime, TimeZone, Utc};
+
+    /// Serialize a datetime into a floating point number of seconds since the epoch or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        match *opt {
+            Some(ref dt) => serializer.serialize_some(&to_f64(dt)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a `DateTime` from a floating point seconds timestamp or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<Utc>>,
+    {
+        d.deserialize_option(OptionSecondsF64TimestampVisitor).map(|opt| opt.map(DateTime::from))
+    }
+
+    struct OptionSecondsF64TimestampVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionSecondsF64TimestampVisitor {
+        type Value = Option<DateTime<Utc>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in seconds or none")
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_f64(SecondsF64TimestampVisitor).map(Some)
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        /// Deserialize a timestamp in seconds since the epoch
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+}
+
+/// Ser/de to/from an [RFC 2822] formatted string
+///
+/// Intended for use with `serde`'s `with` attribute. RFC 2822 is the format used in email
+/// headers, like `Fri, 15 May 2015 10:00:00 +0000`.
+///
+/// RFC 2822 is only defined on years 0 through 9999; serializing a `DateTime` outside that range
+/// fails.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, FixedOffset, TimeZone};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc2822;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc2822")]
+///     time: DateTime<FixedOffset>,
+/// }
+///
+/// let time = FixedOffset::east_opt(2 * 3600).unwrap().with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap();
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"Fri, 15 May 2015 10:00:00 +0200"}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822#section-3.3
+#[cfg(feature = "alloc")]
+pub mod rfc2822 {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use crate::format::write_rfc2822;
+    use crate::{DateTime, Datelike, FixedOffset, Offset, TimeZone};
+
+    /// Serialize a datetime into an RFC 2822 formatted string
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc2822::serialize as to_rfc2822;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc2822")]
+    ///     time: DateTime<Utc>,
+    /// }
+    ///
+    /// let my_s = S { time: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"Fri, 15 May 2015 10:00:00 +0000"}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        ser::Serialize::serialize(&Rfc2822(dt), serializer)
+    }
+
+    /// Deserialize a `DateTime` from an RFC 2822 formatted string
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc2822::deserialize as from_rfc2822;
+    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc2822")]
+    ///     time: DateTime<Utc>,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": "Fri, 15 May 2015 12:00:00 +0200" }"#)?;
+    /// assert_eq!(my_s, S { time: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap() });
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<FixedOffset>>,
+    {
+        d.deserialize_str(Rfc2822Visitor).map(DateTime::from)
+    }
+
+    pub(super) struct Rfc2822<'a, Tz: TimeZone>(pub(super) &'a DateTime<Tz>);
+
+    impl<Tz: TimeZone> ser::Serialize for Rfc2822<'_, Tz> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            if !(0..=9999).contains(&self.0.naive_local().year()) {
+                return Err(ser::Error::custom("RFC 2822 is only defined on years 0 through 9999"));
+            }
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<Tz: TimeZone> fmt::Display for Rfc2822<'_, Tz> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write_rfc2822(f, self.0.naive_local(), self.0.offset().fix())
+        }
+    }
+
+    pub(super) struct Rfc2822Visitor;
+
+    impl<'de> de::Visitor<'de> for Rfc2822Visitor {
+        type Value = DateTime<FixedOffset>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an RFC 2822 formatted date and time string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::parse_from_rfc2822(value).map_err(E::custom)
+        }
+    }
+}
+
+/// Ser/de to/from an optional [RFC 2822] formatted string
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, TimeZone, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc2822_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc2822_option")]
+///     time: Option<DateTime<Utc>>,
+/// }
+///
+/// let time = Some(Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap());
+/// let my_s = S { time: time.clone() };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"Fri, 15 May 2015 10:00:00 +0000"}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822#section-3.3
+#[cfg(feature = "alloc")]
+pub mod rfc2822_option {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::rfc2822::{Rfc2822, Rfc2822Visitor};
+    use crate::{DateTime, FixedOffset, TimeZone};
+
+    /// Serialize a datetime into an RFC 2822 formatted string or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc2822_option::serialize as to_rfc2822opt;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc2822opt")]
+    ///     time: Option<DateTime<Utc>>,
+    /// }
+    ///
+    /// let my_s = S { time: None };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":null}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        match *opt {
+            Some(ref dt) => serializer.serialize_some(&Rfc2822(dt)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a `DateTime` from an RFC 2822 formatted string or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc2822_option::deserialize as from_rfc2822opt;
+    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc2822opt")]
+    ///     time: Option<DateTime<Utc>>,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": "Fri, 15 May 2015 10:00:00 GMT" }"#)?;
+    /// assert_eq!(my_s, S { time: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).single() });
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<FixedOffset>>,
+    {
+        d.deserialize_option(OptionRfc2822Visitor).map(|opt| opt.map(DateTime::from))
+    }
+
+    struct OptionRfc2822Visitor;
+
+    impl<'de> de::Visitor<'de> for OptionRfc2822Visitor {
+        type Value = Option<DateTime<FixedOffset>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an RFC 2822 formatted date and time string or none")
+        }
+
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_str(Rfc2822Visitor).map(Some)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+}
+
+/// Ser/de to/from an [RFC 3339] formatted string, accepting some common deviations
+///
+/// Intended for use with `serde`'s `with` attribute. Serializing writes a strict RFC 3339 string,
+/// the same as the default `Serialize` implementation. Deserializing accepts the relaxed form
+/// also accepted by [`DateTime<FixedOffset>::from_str`](DateTime#impl-FromStr-for-DateTime<FixedOffset>):
+/// - a space or a `T` (in either case) as the separator between the date and time,
+/// - values that are not padded to two digits,
+/// - spaces between any of the components,
+/// - `UTC` instead of an offset, and an offset without a colon.
+///
+/// Unlike the default `Deserialize` implementations this works for a `DateTime<Tz>` in any time
+/// zone with a `From<DateTime<FixedOffset>>` implementation.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, TimeZone, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc3339_lenient;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc3339_lenient")]
+///     time: DateTime<Utc>,
+/// }
+///
+/// let time = Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap();
+///
+/// let my_s: S = serde_json::from_str(r#"{ "time": "2015-05-15 12:00:00 +0200" }"#)?;
+/// assert_eq!(my_s.time, time);
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"2015-05-15T10:00:00Z"}"#);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+pub mod rfc3339_lenient {
+    use serde::{de, ser};
+
+    use super::DateTimeVisitor;
+    use crate::{DateTime, FixedOffset, TimeZone};
+
+    /// Serialize a datetime into an RFC 3339 formatted string
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc3339_lenient::serialize as to_rfc3339;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc3339")]
+    ///     time: DateTime<FixedOffset>,
+    /// }
+    ///
+    /// let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// let my_s = S { time: offset.with_ymd_and_hms(2015, 5, 15, 12, 0, 0).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-05-15T12:00:00+02:00"}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S, Tz>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        ser::Serialize::serialize(dt, serializer)
+    }
+
+    /// Deserialize a `DateTime` from a relaxed RFC 3339 formatted string
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc3339_lenient::deserialize as from_rfc3339;
+    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc3339")]
+    ///     time: DateTime<Utc>,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": "2015-5-15t10:00:00 UTC" }"#)?;
+    /// assert_eq!(my_s, S { time: Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap() });
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<FixedOffset>>,
+    {
+        d.deserialize_str(DateTimeVisitor).map(DateTime::from)
+    }
+}
+
+/// Ser/de to/from an optional [RFC 3339] formatted string, accepting some common deviations
+///
+/// Intended for use with `serde`'s `with` attribute. See [`rfc3339_lenient`](crate::serde::rfc3339_lenient)
+/// for the accepted deviations.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{DateTime, TimeZone, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc3339_lenient_option;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc3339_lenient_option")]
+///     time: Option<DateTime<Utc>>,
+/// }
+///
+/// let my_s: S = serde_json::from_str(r#"{ "time": "2015-05-15 10:00:00Z" }"#)?;
+/// assert_eq!(my_s.time, Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).single());
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"2015-05-15T10:00:00Z"}"#);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+pub mod rfc3339_lenient_option {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::DateTimeVisitor;
+    use crate::{DateTime, FixedOffset, TimeZone};
+
+    /// Serialize a datetime into an RFC 3339 formatted string or none
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, TimeZone, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc3339_lenient_option::serialize as to_rfc3339opt;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc3339opt")]
+    ///     time: Option<DateTime<Utc>>,
+    /// }
+    ///
+    /// let my_s = S { time: Some(Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap()) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-05-15T10:00:00Z"}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S, Tz>(opt: &Option<DateTime<Tz>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+        Tz: TimeZone,
+    {
+        match *opt {
+            Some(ref dt) => serializer.serialize_some(dt),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a `DateTime` from a relaxed RFC 3339 formatted string or none
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, FixedOffset};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc3339_lenient_option::deserialize as from_rfc3339opt;
+    /// #[derive(Debug, PartialEq, Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc3339opt")]
+    ///     time: Option<DateTime<FixedOffset>>,
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": null }"#)?;
+    /// assert_eq!(my_s, S { time: None });
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D, Tz>(d: D) -> Result<Option<DateTime<Tz>>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+        Tz: TimeZone,
+        DateTime<Tz>: From<DateTime<FixedOffset>>,
+    {
+        d.deserialize_option(OptionDateTimeVisitor).map(|opt| opt.map(DateTime::from))
+    }
+
+    struct OptionDateTimeVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionDateTimeVisitor {
+        type Value = Option<DateTime<FixedOffset>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an RFC 3339 formatted date and time string or none")
+        }
+
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_str(DateTimeVisitor).map(Some)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "clock")]
@@ -1298,4 +2512,190 @@ mod tests {
         assert_eq!(dt, decoded);
         assert_eq!(dt.offset().fix(), *decoded.offset());
     }
+
+    #[test]
+    fn test_serde_ts_generic_timezone() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "crate::serde::ts_seconds_tz")]
+            secs: DateTime<FixedOffset>,
+            #[serde(with = "crate::serde::ts_milliseconds_tz")]
+            millis: DateTime<FixedOffset>,
+            #[serde(with = "crate::serde::ts_microseconds_tz_option")]
+            micros: Option<DateTime<FixedOffset>>,
+            #[serde(with = "crate::serde::ts_nanoseconds")]
+            nanos: DateTime<Utc>,
+        }
+
+        let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+        let dt = offset.timestamp_opt(1431684000, 123_456_789).unwrap();
+        let s = S { secs: dt, millis: dt, micros: Some(dt), nanos: dt.with_timezone(&Utc) };
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(
+            json,
+            r#"{"secs":1431684000,"millis":1431684000123,"micros":1431684000123456,"nanos":1431684000123456789}"#
+        );
+
+        // the instant is kept, the offset is that of UTC
+        let decoded: S = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.secs, offset.timestamp_opt(1431684000, 0).unwrap());
+        assert_eq!(decoded.secs.offset(), &FixedOffset::east_opt(0).unwrap());
+        assert_eq!(decoded.millis, offset.timestamp_opt(1431684000, 123_000_000).unwrap());
+        assert_eq!(decoded.micros, offset.timestamp_opt(1431684000, 123_456_000).single());
+        assert_eq!(decoded.nanos, dt);
+
+        // the modules for `DateTime<Utc>` keep their non-generic signatures
+        let _: fn(serde_json::value::Value) -> Result<DateTime<Utc>, _> =
+            crate::serde::ts_seconds::deserialize;
+        let _: fn(serde_json::value::Value) -> Result<Option<DateTime<Utc>>, _> =
+            crate::serde::ts_nanoseconds_option::deserialize;
+    }
+
+    #[test]
+    fn test_serde_ts_seconds_f64() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "crate::serde::ts_seconds_f64")]
+            time: DateTime<Utc>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct O {
+            #[serde(default, with = "crate::serde::ts_seconds_f64_option")]
+            time: Option<DateTime<FixedOffset>>,
+        }
+
+        let to_json = |secs, nanos| {
+            serde_json::to_string(&S { time: Utc.timestamp_opt(secs, nanos).unwrap() }).unwrap()
+        };
+        let from_json = |json: &str| serde_json::from_str::<S>(json).map(|s| s.time);
+
+        assert_eq!(to_json(0, 0), r#"{"time":0.0}"#);
+        assert_eq!(to_json(1, 500_000_000), r#"{"time":1.5}"#);
+        assert_eq!(to_json(-2, 500_000_000), r#"{"time":-1.5}"#);
+
+        assert_eq!(
+            from_json(r#"{"time":1.5}"#).unwrap(),
+            Utc.timestamp_opt(1, 500_000_000).unwrap()
+        );
+        assert_eq!(
+            from_json(r#"{"time":-1.5}"#).unwrap(),
+            Utc.timestamp_opt(-2, 500_000_000).unwrap()
+        );
+        assert_eq!(from_json(r#"{"time":-1}"#).unwrap(), Utc.timestamp_opt(-1, 0).unwrap());
+        assert_eq!(from_json(r#"{"time":7}"#).unwrap(), Utc.timestamp_opt(7, 0).unwrap());
+        // rounded to the nearest nanosecond
+        assert_eq!(
+            from_json(r#"{"time":1.9999999999}"#).unwrap(),
+            Utc.timestamp_opt(2, 0).unwrap()
+        );
+        let nanos = from_json(r#"{"time":1526522699.918355}"#).unwrap().timestamp_subsec_nanos();
+        assert!((918_354_000..918_356_000).contains(&nanos));
+        assert!(from_json(r#"{"time":1e300}"#).is_err());
+        assert!(from_json(r#"{"time":-1e300}"#).is_err());
+        assert!(from_json(r#"{"time":9.3e18}"#).is_err());
+        assert!(from_json(r#"{"time":"1.5"}"#).is_err());
+
+        let time = FixedOffset::east_opt(0).unwrap().timestamp_opt(10, 250_000_000).unwrap();
+        let json = serde_json::to_string(&O { time: Some(time) }).unwrap();
+        assert_eq!(json, r#"{"time":10.25}"#);
+        assert_eq!(serde_json::from_str::<O>(&json).unwrap(), O { time: Some(time) });
+        assert_eq!(serde_json::from_str::<O>(r#"{"time":null}"#).unwrap(), O { time: None });
+        assert_eq!(serde_json::from_str::<O>("{}").unwrap(), O { time: None });
+
+        // bincode is not self-describing and relies on `deserialize_f64`
+        let time = Utc.timestamp_opt(1431684000, 250_000_000).unwrap();
+        let encoded = bincode::serialize(&S { time }).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), S { time });
+    }
+
+    #[test]
+    fn test_serde_rfc2822() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "crate::serde::rfc2822")]
+            time: DateTime<FixedOffset>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct O {
+            #[serde(default, with = "crate::serde::rfc2822_option")]
+            time: Option<DateTime<Utc>>,
+        }
+
+        let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+        let time = offset.with_ymd_and_hms(2003, 7, 1, 10, 52, 37).unwrap();
+        let json = serde_json::to_string(&S { time }).unwrap();
+        assert_eq!(json, r#"{"time":"Tue, 1 Jul 2003 10:52:37 -0500"}"#);
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time });
+        let encoded = bincode::serialize(&S { time }).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), S { time });
+
+        let out_of_range = offset.with_ymd_and_hms(10_000, 1, 1, 0, 0, 0).unwrap();
+        assert!(serde_json::to_string(&S { time: out_of_range }).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":"2003-07-01T10:52:37-05:00"}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":1057074757}"#).is_err());
+
+        let utc = time.with_timezone(&Utc);
+        let json = serde_json::to_string(&O { time: Some(utc) }).unwrap();
+        assert_eq!(json, r#"{"time":"Tue, 1 Jul 2003 15:52:37 +0000"}"#);
+        assert_eq!(serde_json::from_str::<O>(&json).unwrap(), O { time: Some(utc) });
+        assert_eq!(serde_json::to_string(&O { time: None }).unwrap(), r#"{"time":null}"#);
+        assert_eq!(serde_json::from_str::<O>(r#"{"time":null}"#).unwrap(), O { time: None });
+        assert_eq!(serde_json::from_str::<O>("{}").unwrap(), O { time: None });
+        let encoded = bincode::serialize(&O { time: Some(utc) }).unwrap();
+        assert_eq!(bincode::deserialize::<O>(&encoded).unwrap(), O { time: Some(utc) });
+    }
+
+    #[test]
+    fn test_serde_rfc3339_lenient() {
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "crate::serde::rfc3339_lenient")]
+            time: DateTime<Utc>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct O {
+            #[serde(default, with = "crate::serde::rfc3339_lenient_option")]
+            time: Option<DateTime<FixedOffset>>,
+        }
+
+        let time = Utc.with_ymd_and_hms(2015, 5, 15, 10, 0, 0).unwrap();
+        for json in [
+            r#"{"time":"2015-05-15T10:00:00Z"}"#,
+            r#"{"time":"2015-05-15t10:00:00z"}"#,
+            r#"{"time":"2015-05-15 10:00:00 UTC"}"#,
+            r#"{"time":"2015-5-15T10:0:0+00:00"}"#,
+            r#"{"time":"2015-05-15 11:00:00+0100"}"#,
+            r#"{"time":"2015 - 05 - 15T10 : 00 : 00 Z"}"#,
+        ] {
+            assert_eq!(serde_json::from_str::<S>(json).unwrap(), S { time }, "{}", json);
+        }
+        assert!(serde_json::from_str::<S>(r#"{"time":"2015-05-15T10:00:00"}"#).is_err());
+        assert!(serde_json::from_str::<S>(r#"{"time":"2015-05-15"}"#).is_err());
+        assert_eq!(
+            serde_json::to_string(&S { time }).unwrap(),
+            r#"{"time":"2015-05-15T10:00:00Z"}"#
+        );
+        let encoded = bincode::serialize(&S { time }).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&encoded).unwrap(), S { time });
+
+        let fixed = time.fixed_offset();
+        let json = serde_json::to_string(&O { time: Some(fixed) }).unwrap();
+        assert_eq!(json, r#"{"time":"2015-05-15T10:00:00Z"}"#);
+        assert_eq!(serde_json::from_str::<O>(&json).unwrap(), O { time: Some(fixed) });
+        assert_eq!(serde_json::from_str::<O>(r#"{"time":null}"#).unwrap(), O { time: None });
+        assert_eq!(serde_json::from_str::<O>("{}").unwrap(), O { time: None });
+        let encoded = bincode::serialize(&O { time: Some(fixed) }).unwrap();
+        assert_eq!(bincode::deserialize::<O>(&encoded).unwrap(), O { time: Some(fixed) });
+    }
 }
@@ -3,7 +3,10 @@ use crate::naive::{NaiveDate, NaiveTime};
 use crate::offset::{FixedOffset, TimeZone, Utc};
 #[cfg(feature = "clock")]
 use crate::offset::{Local, Offset};
-use crate::{Datelike, Days, LocalResult, Months, NaiveDateTime, TimeDelta, Timelike, Weekday};
+use crate::{
+    CalendarArithmetic, Datelike, Days, Disambiguation, LocalResult, Months, NaiveDateTime, Period,
+    TimeDelta, Timelike, Weekday,
+};
 
 #[derive(Clone)]
 struct DstTester;
@@ -123,6 +126,155 @@ impl TimeZone for DstTester {
     }
 }
 
+#[test]
+fn test_local_result_resolve() {
+    let fold = NaiveDate::from_ymd_opt(2023, 4, 15).unwrap().and_hms_opt(1, 30, 0).unwrap();
+    let result = DstTester.from_local_datetime(&fold);
+    let earlier = DateTime::parse_from_rfc3339("2023-04-15T01:30:00+09:00").unwrap();
+    let later = DateTime::parse_from_rfc3339("2023-04-15T01:30:00+08:00").unwrap();
+    assert_eq!(result.clone().resolve(Disambiguation::Earlier).unwrap(), earlier);
+    assert_eq!(result.clone().resolve(Disambiguation::Compatible).unwrap(), earlier);
+    assert_eq!(result.clone().resolve(Disambiguation::Later).unwrap(), later);
+    assert_eq!(result.resolve(Disambiguation::Reject), None);
+
+    let single = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let result = DstTester.from_local_datetime(&single);
+    assert_eq!(result.clone().resolve(Disambiguation::Reject), result.single());
+
+    let gap = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap().and_hms_opt(2, 30, 0).unwrap();
+    assert_eq!(DstTester.from_local_datetime(&gap).resolve(Disambiguation::Later), None);
+}
+
+#[test]
+fn test_from_local_datetime_with() {
+    let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s);
+    let fixed = |dt: DateTime<DstTester>| (dt.naive_local(), *dt.offset());
+    let at = |m, d, h, n, offset: i32| {
+        let local = NaiveDate::from_ymd_opt(2023, m, d).unwrap().and_hms_opt(h, n, 0).unwrap();
+        (local, FixedOffset::east_opt(offset * 3600).unwrap())
+    };
+
+    // gap from 02:00 to 03:00, +08:00 to +09:00
+    let gap = ymdhms(2023, 9, 15, 2, 30, 0).unwrap();
+    let resolve = |policy| DstTester.from_local_datetime_with(&gap, policy).map(fixed);
+    assert_eq!(resolve(Disambiguation::Earlier).unwrap(), at(9, 15, 1, 30, 8));
+    assert_eq!(resolve(Disambiguation::Later).unwrap(), at(9, 15, 3, 30, 9));
+    assert_eq!(resolve(Disambiguation::Compatible).unwrap(), at(9, 15, 3, 30, 9));
+    assert_eq!(resolve(Disambiguation::Reject), None);
+
+    let start = ymdhms(2023, 9, 15, 2, 0, 0).unwrap();
+    assert_eq!(
+        gap.and_local_timezone_with(DstTester, Disambiguation::Compatible).map(fixed),
+        resolve(Disambiguation::Compatible)
+    );
+    assert_eq!(
+        DstTester.from_local_datetime_with(&start, Disambiguation::Earlier).map(fixed).unwrap(),
+        at(9, 15, 1, 0, 8)
+    );
+
+    // fold from 02:00 back to 01:00, +09:00 to +08:00
+    let fold = ymdhms(2023, 4, 15, 1, 30, 0).unwrap();
+    let resolve = |policy| DstTester.from_local_datetime_with(&fold, policy).map(fixed);
+    assert_eq!(resolve(Disambiguation::Earlier).unwrap(), at(4, 15, 1, 30, 9));
+    assert_eq!(resolve(Disambiguation::Later).unwrap(), at(4, 15, 1, 30, 8));
+    assert_eq!(resolve(Disambiguation::Compatible).unwrap(), at(4, 15, 1, 30, 9));
+    assert_eq!(resolve(Disambiguation::Reject), None);
+
+    // no transition
+    let normal = ymdhms(2023, 6, 1, 12, 0, 0).unwrap();
+    for policy in [
+        Disambiguation::Earlier,
+        Disambiguation::Later,
+        Disambiguation::Compatible,
+        Disambiguation::Reject,
+    ] {
+        assert_eq!(
+            DstTester.from_local_datetime_with(&normal, policy).map(fixed).unwrap(),
+            at(6, 1, 12, 0, 8)
+        );
+    }
+
+    // out of range
+    let max = NaiveDateTime::MAX;
+    let offset = FixedOffset::west_opt(3600).unwrap();
+    assert_eq!(offset.from_local_datetime_with(&max, Disambiguation::Compatible), None);
+}
+
+#[test]
+fn test_checked_add_span() {
+    let wall_clock = CalendarArithmetic::WallClock(Disambiguation::Compatible);
+    let exact = CalendarArithmetic::Exact;
+    let fixed = |dt: DateTime<DstTester>| (dt.naive_local(), *dt.offset());
+    let at = |m, d, h, n, offset: i32| {
+        let local = NaiveDate::from_ymd_opt(2023, m, d).unwrap().and_hms_opt(h, n, 0).unwrap();
+        (local, FixedOffset::east_opt(offset * 3600).unwrap())
+    };
+    let ymdhms = |y, m, d, h, n, s| DstTester.with_ymd_and_hms(y, m, d, h, n, s).single().unwrap();
+
+    // into the gap from 02:00 to 03:00 on 09-15
+    let dt = ymdhms(2023, 9, 14, 2, 30, 0);
+    assert_eq!(dt.clone().checked_add_days(Days::new(1)), None);
+    let next = dt.clone().checked_add_span(Days::new(1), wall_clock).unwrap();
+    assert_eq!(fixed(next), at(9, 15, 3, 30, 9));
+    let reject = CalendarArithmetic::WallClock(Disambiguation::Reject);
+    assert_eq!(dt.clone().checked_add_span(Days::new(1), reject), None);
+    let earlier = CalendarArithmetic::WallClock(Disambiguation::Earlier);
+    let next = dt.checked_add_span(Days::new(1), earlier).unwrap();
+    assert_eq!(fixed(next), at(9, 15, 1, 30, 8));
+
+    // across the gap, wall clock time is kept or the exact duration is
+    let dt = ymdhms(2023, 9, 15, 12, 0, 0);
+    let prev = dt.clone().checked_sub_span(Days::new(1), wall_clock).unwrap();
+    assert_eq!(fixed(prev), at(9, 14, 12, 0, 8));
+    let prev = dt.clone().checked_sub_span(Days::new(1), exact).unwrap();
+    assert_eq!(fixed(prev.clone()), at(9, 14, 11, 0, 8));
+    assert_eq!(dt.clone().signed_duration_since(prev), TimeDelta::try_days(1).unwrap());
+
+    // into the fold from 02:00 back to 01:00 on 04-15
+    let dt = ymdhms(2023, 3, 15, 1, 30, 0);
+    assert_eq!(dt.clone().checked_add_months(Months::new(1)), None);
+    let next = dt.clone().checked_add_span(Months::new(1), wall_clock).unwrap();
+    assert_eq!(fixed(next), at(4, 15, 1, 30, 9));
+    let later = CalendarArithmetic::WallClock(Disambiguation::Later);
+    let next = dt.clone().checked_add_span(Months::new(1), later).unwrap();
+    assert_eq!(fixed(next), at(4, 15, 1, 30, 8));
+    let next = dt.checked_add_span(Months::new(2), exact).unwrap();
+    assert_eq!(fixed(next), at(5, 15, 0, 30, 8));
+
+    // `TimeDelta` is added to the local time
+    let dt = ymdhms(2023, 9, 15, 1, 30, 0);
+    let next = dt.clone().checked_add_span(TimeDelta::try_hours(1).unwrap(), wall_clock).unwrap();
+    assert_eq!(fixed(next), at(9, 15, 3, 30, 9));
+    let next = dt.checked_add_span(TimeDelta::try_hours(1).unwrap(), exact).unwrap();
+    assert_eq!(fixed(next), at(9, 15, 3, 30, 9));
+
+    // out of range
+    let max = FixedOffset::east_opt(3600).unwrap().from_utc_datetime(&NaiveDateTime::MAX);
+    assert_eq!(max.checked_add_span(Days::new(1), wall_clock), None);
+    assert_eq!(max.checked_add_span(Days::new(1), exact), None);
+}
+
+#[test]
+fn test_datetime_add_sub_period() {
+    let fixed = |dt: DateTime<DstTester>| (dt.naive_local(), *dt.offset());
+    let at = |m, d, h, n, offset: i32| {
+        let local = NaiveDate::from_ymd_opt(2023, m, d).unwrap().and_hms_opt(h, n, 0).unwrap();
+        (local, FixedOffset::east_opt(offset * 3600).unwrap())
+    };
+    let ymdhms = |y, m, d, h, n, s| DstTester.with_ymd_and_hms(y, m, d, h, n, s).single().unwrap();
+
+    let period = Period::new(0, 1, 1, TimeDelta::try_hours(1).unwrap());
+    let dt = ymdhms(2023, 8, 14, 1, 30, 0);
+    assert_eq!(fixed(dt.clone() + period), at(9, 15, 3, 30, 9));
+    assert_eq!(fixed(dt - period), at(7, 13, 0, 30, 8));
+
+    // into the fold from 02:00 back to 01:00 on 04-15
+    let dt = ymdhms(2023, 3, 15, 1, 30, 0);
+    assert_eq!(fixed(dt + Period::months(1)), at(4, 15, 1, 30, 9));
+    let dt = ymdhms(2023, 5, 15, 1, 30, 0);
+    assert_eq!(fixed(dt - Period::months(1)), at(4, 15, 1, 30, 9));
+}
+
 #[test]
 fn test_datetime_from_timestamp_millis() {
     let valid_map = [
@@ -1158,10 +1310,18 @@ fn test_datetime_parse_from_str() {
     //
     // wrong timezone format
     assert!(parse("Aug 09 2013 23:54:35 -0900", "%b %d %Y %H:%M:%S %Z").is_err());
-    // bad timezone data?
-    assert!(parse("Aug 09 2013 23:54:35 PST", "%b %d %Y %H:%M:%S %Z").is_err());
+    // known abbreviation
+    assert_eq!(
+        parse("Aug 09 2013 23:54:35 PST", "%b %d %Y %H:%M:%S %Z"),
+        Ok(ymdhms(&FixedOffset::west_opt(8 * 60 * 60).unwrap(), 2013, 8, 9, 23, 54, 35))
+    );
+    // ambiguous abbreviation
+    assert!(parse("Aug 09 2013 23:54:35 CST", "%b %d %Y %H:%M:%S %Z").is_err());
+    assert_eq!(parse("Aug 09 2013 23:54:35 CST -0900", "%b %d %Y %H:%M:%S %Z %z"), Ok(dt));
     // bad timezone data
     assert!(parse("Aug 09 2013 23:54:35 XXXXX", "%b %d %Y %H:%M:%S %Z").is_err());
+    // conflicting with the offset
+    assert!(parse("Aug 09 2013 23:54:35 PST -0900", "%b %d %Y %H:%M:%S %Z %z").is_err());
 
     //
     // %z
@@ -1289,6 +1449,71 @@ fn test_datetime_parse_from_str() {
     assert!(parse("Aug 09 2013 -09:00:23:54:35", "%b %d %Y %#z%H:%M:%S").is_err());
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn test_datetime_parse_from_str_timezone_id() {
+    use crate::format::{parse, Parsed, StrftimeItems};
+
+    // a custom lookup replaces the system time zone database
+    fn lookup(name: &str) -> Option<crate::TzInfo> {
+        match name {
+            "Test/Zone" => crate::TzInfo::from_posix_tz("TST-3").ok(),
+            _ => None,
+        }
+    }
+    let mut parsed = Parsed::new();
+    parsed.set_time_zone_lookup(lookup);
+    parse(&mut parsed, "2024-07-01 12:00 Test/Zone", StrftimeItems::new("%F %R %:Z")).unwrap();
+    let tst = FixedOffset::east_opt(3 * 3600).unwrap();
+    assert_eq!(parsed.to_datetime(), Ok(ymdhms(&tst, 2024, 7, 1, 12, 0, 0)));
+    let mut parsed = Parsed::new();
+    parsed.set_time_zone_lookup(lookup);
+    assert!(parse(&mut parsed, "Europe/Prague", StrftimeItems::new("%:Z")).is_err());
+
+    // The time zone database is not available on every system that runs the tests.
+    if crate::TzInfo::from_zoneinfo("Europe/Prague").is_err() {
+        return;
+    }
+    let parse_dt = DateTime::parse_from_str;
+    let cet = FixedOffset::east_opt(3600).unwrap();
+    let cest = FixedOffset::east_opt(7200).unwrap();
+
+    assert_eq!(
+        parse_dt("2024-07-01 12:00 Europe/Prague", "%Y-%m-%d %H:%M %:Z"),
+        Ok(ymdhms(&cest, 2024, 7, 1, 12, 0, 0))
+    );
+    assert_eq!(
+        parse_dt("2024-01-01 12:00 Europe/Prague", "%Y-%m-%d %H:%M %:Z"),
+        Ok(ymdhms(&cet, 2024, 1, 1, 12, 0, 0))
+    );
+    // ambiguous local time, the offset has to be given
+    assert!(parse_dt("2024-10-27 02:30 Europe/Prague", "%Y-%m-%d %H:%M %:Z").is_err());
+    assert_eq!(
+        parse_dt("2024-10-27 02:30 Europe/Prague +01:00", "%Y-%m-%d %H:%M %:Z %:z"),
+        Ok(ymdhms(&cet, 2024, 10, 27, 2, 30, 0))
+    );
+    // non-existent local time
+    assert!(parse_dt("2024-03-31 02:30 Europe/Prague", "%Y-%m-%d %H:%M %:Z").is_err());
+    // offset that conflicts with the time zone
+    assert!(parse_dt("2024-07-01 12:00 Europe/Prague +01:00", "%Y-%m-%d %H:%M %:Z %:z").is_err());
+    // unknown time zone
+    assert!(parse_dt("2024-07-01 12:00 Europe/Atlantis", "%Y-%m-%d %H:%M %:Z").is_err());
+
+    // `%:Z` writes the identifier of a `TzInfo`, so it round-trips
+    let prague = crate::TzInfo::from_zoneinfo("Europe/Prague").unwrap();
+    let dt = prague.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+    let formatted = dt.format("%Y-%m-%d %H:%M %:Z (%Z)").to_string();
+    assert_eq!(formatted, "2024-07-01 12:00 Europe/Prague (CEST)");
+    assert_eq!(parse_dt(&formatted, "%Y-%m-%d %H:%M %:Z (CEST)"), Ok(dt.fixed_offset()));
+    // offsets without a time zone identifier write the same as `%Z`
+    assert_eq!(dt.fixed_offset().format("%:Z").to_string(), "+02:00");
+
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, "Europe/Prague, 12:00", StrftimeItems::new("%:Z, %H:%M")).unwrap();
+    assert_eq!(parsed.time_zone().and_then(|tz| tz.name()), Some("Europe/Prague"));
+    assert!(parse(&mut parsed, "Asia/Tokyo", StrftimeItems::new("%:Z")).is_err());
+}
+
 #[test]
 fn test_to_string_round_trip() {
     let dt = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
@@ -0,0 +1,213 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Day-count conventions for year fractions.
+
+use crate::naive::NaiveDate;
+use crate::Datelike;
+
+/// A day-count convention, which determines the number of days and the fraction of a year
+/// between two dates when computing accrued interest.
+///
+/// The conventions follow the definitions in section 4.16 of the 2006 ISDA Definitions.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DayCount, NaiveDate};
+///
+/// let start = NaiveDate::from_ymd_opt(2003, 11, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2004, 5, 1).unwrap();
+///
+/// assert_eq!(DayCount::Act360.day_count(start, end), 182);
+/// assert_eq!(DayCount::Act360.year_fraction(start, end), 182.0 / 360.0);
+/// // 61 days in 2003 and 121 days in the leap year 2004
+/// assert_eq!(DayCount::ActActIsda.year_fraction(start, end), 61.0 / 365.0 + 121.0 / 366.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DayCount {
+    /// Actual/360: the actual number of days divided by 360.
+    Act360,
+    /// Actual/365 (Fixed): the actual number of days divided by 365.
+    Act365Fixed,
+    /// Actual/Actual (ISDA): the actual number of days in a leap year divided by 366, plus the
+    /// actual number of days in a non-leap year divided by 365.
+    ActActIsda,
+    /// 30/360 US: every month has 30 days, with the end-of-month rules for February.
+    ///
+    /// The day of the month of each date is adjusted before counting:
+    ///
+    /// - If both dates are on the last day of February, the end date becomes the 30th.
+    /// - If the start date is on the last day of February, it becomes the 30th.
+    /// - If the end date is on the 31st and the (adjusted) start date is on the 30th or 31st, the
+    ///   end date becomes the 30th.
+    /// - If the start date is on the 31st, it becomes the 30th.
+    Thirty360Us,
+    /// 30E/360 (Eurobond basis): every month has 30 days, and the 31st of a month becomes the
+    /// 30th for both dates.
+    ThirtyE360,
+}
+
+impl DayCount {
+    /// Returns the number of days from `start` to `end` according to this convention.
+    ///
+    /// The result is negative if `end` is before `start`.
+    #[must_use]
+    pub fn day_count(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end < start {
+            return -self.day_count(end, start);
+        }
+        match *self {
+            DayCount::Act360 | DayCount::Act365Fixed | DayCount::ActActIsda => {
+                end.signed_duration_since(start).num_days()
+            }
+            DayCount::Thirty360Us => {
+                let (mut d1, mut d2) = (start.day(), end.day());
+                let start_eom_february = is_last_of_february(start);
+                if start_eom_february && is_last_of_february(end) {
+                    d2 = 30;
+                }
+                if start_eom_february {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 >= 30 {
+                    d2 = 30;
+                }
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                thirty_360(start, d1, end, d2)
+            }
+            DayCount::ThirtyE360 => thirty_360(start, start.day().min(30), end, end.day().min(30)),
+        }
+    }
+
+    /// Returns the fraction of a year from `start` to `end` according to this convention.
+    ///
+    /// The result is negative if `end` is before `start`.
+    #[must_use]
+    pub fn year_fraction(&self, start: NaiveDate, end: NaiveDate) -> f64 {
+        if end < start {
+            return -self.year_fraction(end, start);
+        }
+        let days = self.day_count(start, end) as f64;
+        match *self {
+            DayCount::Act360 | DayCount::Thirty360Us | DayCount::ThirtyE360 => days / 360.0,
+            DayCount::Act365Fixed => days / 365.0,
+            DayCount::ActActIsda => {
+                let (y1, y2) = (start.year(), end.year());
+                if y1 == y2 {
+                    return days / days_in_year(y1);
+                }
+                // `y1 < y2`, so the start of `y1 + 1` and `y2` are in range.
+                let next_year = NaiveDate::from_yo_opt(y1 + 1, 1).unwrap();
+                let end_year = NaiveDate::from_yo_opt(y2, 1).unwrap();
+                let first = next_year.signed_duration_since(start).num_days() as f64;
+                let last = end.signed_duration_since(end_year).num_days() as f64;
+                first / days_in_year(y1) + f64::from(y2 - y1 - 1) + last / days_in_year(y2)
+            }
+        }
+    }
+}
+
+/// Returns the day count between two dates with the days of the month adjusted to `d1` and `d2`,
+/// if every month has 30 days.
+fn thirty_360(start: NaiveDate, d1: u32, end: NaiveDate, d2: u32) -> i64 {
+    let years = i64::from(end.year()) - i64::from(start.year());
+    let months = i64::from(end.month()) - i64::from(start.month());
+    let days = i64::from(d2) - i64::from(d1);
+    360 * years + 30 * months + days
+}
+
+fn is_last_of_february(date: NaiveDate) -> bool {
+    date.month() == 2 && date.succ_opt().map_or(true, |next| next.month() == 3)
+}
+
+fn days_in_year(year: i32) -> f64 {
+    match NaiveDate::from_yo_opt(year, 366) {
+        Some(_) => 366.0,
+        None => 365.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayCount;
+    use crate::NaiveDate;
+
+    #[test]
+    fn test_actual_day_counts() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // examples from the ISDA memo "EMU and Market Conventions", section 3
+        let cases = [
+            ((2003, 11, 1), (2004, 5, 1), 182, 61.0 / 365.0 + 121.0 / 366.0),
+            ((1999, 2, 1), (1999, 7, 1), 150, 150.0 / 365.0),
+            ((1999, 7, 1), (2000, 7, 1), 366, 184.0 / 365.0 + 182.0 / 366.0),
+            ((2002, 8, 15), (2003, 7, 15), 334, 334.0 / 365.0),
+            ((2003, 7, 15), (2004, 1, 15), 184, 170.0 / 365.0 + 14.0 / 366.0),
+            ((1999, 7, 30), (2000, 1, 30), 184, 155.0 / 365.0 + 29.0 / 366.0),
+            ((2000, 1, 30), (2000, 6, 30), 152, 152.0 / 366.0),
+            ((1999, 11, 30), (2000, 4, 30), 152, 32.0 / 365.0 + 120.0 / 366.0),
+        ];
+        for ((y1, m1, d1), (y2, m2, d2), days, act_act) in cases {
+            let (start, end) = (ymd(y1, m1, d1), ymd(y2, m2, d2));
+            for convention in [DayCount::Act360, DayCount::Act365Fixed, DayCount::ActActIsda] {
+                assert_eq!(convention.day_count(start, end), days);
+                assert_eq!(convention.day_count(end, start), -days);
+            }
+            assert_eq!(DayCount::Act360.year_fraction(start, end), days as f64 / 360.0);
+            assert_eq!(DayCount::Act365Fixed.year_fraction(start, end), days as f64 / 365.0);
+            assert_eq!(DayCount::ActActIsda.year_fraction(start, end), act_act);
+            assert_eq!(DayCount::ActActIsda.year_fraction(end, start), -act_act);
+        }
+        // a period over several years
+        let fraction = DayCount::ActActIsda.year_fraction(ymd(2003, 7, 1), ymd(2006, 7, 1));
+        assert_eq!(fraction, 184.0 / 365.0 + 2.0 + 181.0 / 365.0);
+        assert_eq!(DayCount::ActActIsda.year_fraction(ymd(2004, 1, 1), ymd(2005, 1, 1)), 1.0);
+    }
+
+    #[test]
+    fn test_thirty_360_day_counts() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // examples from the ISDA 30/360 test cases, including the February end-of-month cases
+        let cases = [
+            ((2007, 1, 15), (2007, 1, 30), 15, 15),
+            ((2007, 1, 15), (2007, 2, 15), 30, 30),
+            ((2007, 1, 15), (2007, 7, 15), 180, 180),
+            ((2007, 9, 30), (2008, 3, 31), 180, 180),
+            ((2007, 9, 30), (2007, 10, 31), 30, 30),
+            ((2007, 9, 30), (2008, 9, 30), 360, 360),
+            ((2007, 1, 15), (2007, 1, 31), 16, 15),
+            ((2007, 1, 31), (2007, 2, 28), 28, 28),
+            ((2007, 2, 28), (2007, 3, 31), 30, 32),
+            ((2006, 8, 31), (2007, 2, 28), 178, 178),
+            ((2007, 2, 28), (2007, 8, 31), 180, 182),
+            ((2007, 2, 14), (2007, 2, 28), 14, 14),
+            ((2007, 2, 26), (2008, 2, 29), 363, 363),
+            ((2008, 2, 29), (2009, 2, 28), 360, 359),
+            ((2008, 2, 29), (2008, 3, 30), 30, 31),
+            ((2008, 2, 29), (2008, 3, 31), 30, 31),
+            ((2007, 2, 28), (2008, 2, 29), 360, 361),
+            ((2007, 2, 28), (2007, 3, 5), 5, 7),
+            ((2007, 10, 31), (2007, 11, 28), 28, 28),
+            ((2007, 8, 31), (2008, 2, 29), 179, 179),
+            ((2008, 2, 29), (2008, 8, 31), 180, 181),
+            ((2008, 8, 31), (2009, 2, 28), 178, 178),
+            ((2009, 2, 28), (2009, 8, 31), 180, 182),
+        ];
+        for ((y1, m1, d1), (y2, m2, d2), us, european) in cases {
+            let (start, end) = (ymd(y1, m1, d1), ymd(y2, m2, d2));
+            assert_eq!(DayCount::Thirty360Us.day_count(start, end), us, "{} {}", start, end);
+            assert_eq!(DayCount::ThirtyE360.day_count(start, end), european, "{} {}", start, end);
+            assert_eq!(DayCount::Thirty360Us.year_fraction(start, end), us as f64 / 360.0);
+            assert_eq!(DayCount::ThirtyE360.year_fraction(start, end), european as f64 / 360.0);
+        }
+        let (start, end) = (ymd(2007, 2, 28), ymd(2007, 8, 31));
+        assert_eq!(DayCount::Thirty360Us.day_count(end, start), -180);
+        assert_eq!(DayCount::Thirty360Us.day_count(start, start), 0);
+        assert_eq!(DayCount::ThirtyE360.year_fraction(end, start), -182.0 / 360.0);
+    }
+}
@@ -0,0 +1,353 @@
+//! Parsing of ISO 8601 durations into a [`TimeDelta`] or [`Period`].
+
+use core::str::FromStr;
+
+use super::{ParseError, ParseResult, IMPOSSIBLE, INVALID, OUT_OF_RANGE, TOO_SHORT};
+use crate::{Period, TimeDelta};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+
+/// The components of a parsed ISO 8601 duration, with the sign already applied.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct IsoDuration {
+    years: i64,
+    months: i64,
+    /// Days, including weeks.
+    days: i64,
+    /// Hours, minutes and seconds.
+    nanos: i128,
+}
+
+/// The designators of the duration components, in the order they have to appear in.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Designator {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl Designator {
+    fn from_byte(byte: u8, in_time: bool) -> Option<Designator> {
+        Some(match (byte, in_time) {
+            (b'Y', false) => Designator::Years,
+            (b'M', false) => Designator::Months,
+            (b'W', false) => Designator::Weeks,
+            (b'D', false) => Designator::Days,
+            (b'H', true) => Designator::Hours,
+            (b'M', true) => Designator::Minutes,
+            (b'S', true) => Designator::Seconds,
+            _ => return None,
+        })
+    }
+
+    /// The length of the unit in nanoseconds, for the time designators.
+    fn nanos(self) -> Option<i128> {
+        match self {
+            Designator::Hours => Some(3600 * NANOS_PER_SEC),
+            Designator::Minutes => Some(60 * NANOS_PER_SEC),
+            Designator::Seconds => Some(NANOS_PER_SEC),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an ISO 8601 duration such as `P1Y2M3DT4H5M6.7S`.
+///
+/// The accepted format is an optional sign, `P`, the date components `Y`, `M`, `W` and `D`, and
+/// after a `T` the time components `H`, `M` and `S`. Each component is optional but they must be
+/// in this order, and at least one must be present. Only the last component may have a fraction,
+/// which is only allowed for the time components. A component may have its own `-` sign, as
+/// written by the `Display` implementation of [`Period`] for periods with mixed signs.
+fn parse_iso8601_duration(s: &str) -> ParseResult<IsoDuration> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        Some(_) => (false, s),
+        None => return Err(TOO_SHORT),
+    };
+    let mut s = match s.as_bytes().first() {
+        Some(b'P') => &s[1..],
+        Some(_) => return Err(INVALID),
+        None => return Err(TOO_SHORT),
+    };
+
+    let mut parsed = IsoDuration::default();
+    let mut in_time = false;
+    let mut last: Option<Designator> = None;
+    let mut had_fraction = false;
+    while !s.is_empty() {
+        if s.as_bytes()[0] == b'T' {
+            if in_time {
+                return Err(INVALID);
+            }
+            in_time = true;
+            s = &s[1..];
+            if s.is_empty() {
+                return Err(TOO_SHORT);
+            }
+            continue;
+        }
+        if had_fraction {
+            // only the smallest component can have a fraction
+            return Err(INVALID);
+        }
+
+        let (rest, component_negative) = match s.as_bytes()[0] {
+            b'-' => (&s[1..], true),
+            _ => (s, false),
+        };
+        let (rest, int, fraction) = number(rest)?;
+        let designator = match rest.as_bytes().first() {
+            Some(&b) => Designator::from_byte(b, in_time).ok_or(INVALID)?,
+            None => return Err(TOO_SHORT),
+        };
+        if last.map_or(false, |last| designator <= last) {
+            return Err(INVALID);
+        }
+        last = Some(designator);
+        s = &rest[1..];
+
+        let negate = negative != component_negative;
+        match designator.nanos() {
+            Some(unit) => {
+                let (numerator, digits) = fraction.unwrap_or((0, 0));
+                let mut nanos =
+                    i128::from(int) * unit + i128::from(numerator) * unit / 10i128.pow(digits);
+                if negate {
+                    nanos = -nanos;
+                }
+                parsed.nanos += nanos;
+                had_fraction = fraction.is_some();
+            }
+            None => {
+                if fraction.is_some() {
+                    return Err(INVALID);
+                }
+                let value = if negate { -int } else { int };
+                match designator {
+                    Designator::Years => parsed.years = value,
+                    Designator::Months => parsed.months = value,
+                    Designator::Weeks => parsed.days = value.checked_mul(7).ok_or(OUT_OF_RANGE)?,
+                    _ => parsed.days = parsed.days.checked_add(value).ok_or(OUT_OF_RANGE)?,
+                }
+            }
+        }
+    }
+    if last.is_none() {
+        return Err(TOO_SHORT);
+    }
+    Ok(parsed)
+}
+
+/// A decimal fraction as its numerator and number of digits.
+type Fraction = (u32, u32);
+
+/// Parses an unsigned number with an optional fraction after a `.` or `,`.
+///
+/// Only the first 9 digits of the fraction are kept.
+fn number(s: &str) -> ParseResult<(&str, i64, Option<Fraction>)> {
+    let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return Err(if s.is_empty() { TOO_SHORT } else { INVALID });
+    }
+    let int = s[..digits].parse::<i64>().map_err(|_| OUT_OF_RANGE)?;
+    let s = &s[digits..];
+    match s.as_bytes().first() {
+        Some(b'.') | Some(b',') => {
+            let s = &s[1..];
+            let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(if s.is_empty() { TOO_SHORT } else { INVALID });
+            }
+            let kept = digits.min(9);
+            let numerator = s[..kept].parse::<u32>().map_err(|_| INVALID)?;
+            Ok((&s[digits..], int, Some((numerator, kept as u32))))
+        }
+        _ => Ok((s, int, None)),
+    }
+}
+
+/// Converts a number of nanoseconds to a `TimeDelta`, if it is in range.
+fn time_delta_from_nanos(nanos: i128) -> ParseResult<TimeDelta> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).map_err(|_| OUT_OF_RANGE)?;
+    TimeDelta::new(secs, nanos.rem_euclid(NANOS_PER_SEC) as u32).ok_or(OUT_OF_RANGE)
+}
+
+/// Parsing a `str` into a `TimeDelta` uses the ISO 8601 duration format, as written by its
+/// `Display` implementation.
+///
+/// Days and weeks are taken as 24 hours and 7 days. Years and months do not have a fixed length,
+/// and fail to parse with [`ParseErrorKind::Impossible`](super::ParseErrorKind::Impossible) unless
+/// they are zero; use [`Period`] for those.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::ParseErrorKind;
+/// use chrono::TimeDelta;
+///
+/// assert_eq!("PT1H30M".parse::<TimeDelta>(), Ok(TimeDelta::try_minutes(90).unwrap()));
+/// assert_eq!("-P3W".parse::<TimeDelta>(), Ok(TimeDelta::try_weeks(-3).unwrap()));
+/// assert_eq!("PT0.5S".parse::<TimeDelta>(), Ok(TimeDelta::try_milliseconds(500).unwrap()));
+/// assert_eq!("PT1,5M".parse::<TimeDelta>(), Ok(TimeDelta::try_seconds(90).unwrap()));
+///
+/// let delta = TimeDelta::new(86_402, 500_000_000).unwrap();
+/// assert_eq!(delta.to_string().parse::<TimeDelta>(), Ok(delta));
+///
+/// assert_eq!("P1M".parse::<TimeDelta>().unwrap_err().kind(), ParseErrorKind::Impossible);
+/// assert_eq!("PT1H30".parse::<TimeDelta>().unwrap_err().kind(), ParseErrorKind::TooShort);
+/// assert_eq!("PT1M1H".parse::<TimeDelta>().unwrap_err().kind(), ParseErrorKind::Invalid);
+/// assert_eq!("P999999999999D".parse::<TimeDelta>().unwrap_err().kind(), ParseErrorKind::OutOfRange);
+/// ```
+impl FromStr for TimeDelta {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<TimeDelta> {
+        let parsed = parse_iso8601_duration(s)?;
+        if parsed.years != 0 || parsed.months != 0 {
+            return Err(IMPOSSIBLE);
+        }
+        time_delta_from_nanos(i128::from(parsed.days) * NANOS_PER_DAY + parsed.nanos)
+    }
+}
+
+/// Parsing a `str` into a `Period` uses the ISO 8601 duration format, as written by its
+/// `Display` implementation.
+///
+/// Weeks are added to the days. The hours, minutes and seconds together make up the time span of
+/// the `Period`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::ParseErrorKind;
+/// use chrono::{Period, TimeDelta};
+///
+/// assert_eq!(
+///     "P1Y2M3DT4H".parse::<Period>(),
+///     Ok(Period::new(1, 2, 3, TimeDelta::try_hours(4).unwrap()))
+/// );
+/// assert_eq!("-P1Y2W".parse::<Period>(), Ok(Period::new(-1, 0, -14, TimeDelta::zero())));
+///
+/// let period = Period::new(1, -1, 0, TimeDelta::try_minutes(-90).unwrap());
+/// assert_eq!(period.to_string().parse::<Period>(), Ok(period));
+///
+/// assert_eq!("P1.5Y".parse::<Period>().unwrap_err().kind(), ParseErrorKind::Invalid);
+/// assert_eq!("P3000000000M".parse::<Period>().unwrap_err().kind(), ParseErrorKind::OutOfRange);
+/// ```
+impl FromStr for Period {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Period> {
+        let parsed = parse_iso8601_duration(s)?;
+        let component = |value: i64| i32::try_from(value).map_err(|_| OUT_OF_RANGE);
+        Ok(Period::new(
+            component(parsed.years)?,
+            component(parsed.months)?,
+            component(parsed.days)?,
+            time_delta_from_nanos(parsed.nanos)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_iso8601_duration, IsoDuration};
+    use crate::format::ParseErrorKind::*;
+    use crate::{Period, TimeDelta};
+
+    #[test]
+    fn test_parse_iso8601_duration() {
+        let ok = |years, months, days, nanos| Ok(IsoDuration { years, months, days, nanos });
+        let parse = |s| parse_iso8601_duration(s).map_err(|e| e.kind());
+
+        assert_eq!(parse("P1Y"), ok(1, 0, 0, 0));
+        assert_eq!(parse("P1Y2M3W4D"), ok(1, 2, 25, 0));
+        assert_eq!(parse("PT1H2M3S"), ok(0, 0, 0, 3_723_000_000_000));
+        assert_eq!(parse("P1MT1M"), ok(0, 1, 0, 60_000_000_000));
+        assert_eq!(parse("+P1D"), ok(0, 0, 1, 0));
+        assert_eq!(parse("-P1DT1S"), ok(0, 0, -1, -1_000_000_000));
+        assert_eq!(parse("P1DT-1S"), ok(0, 0, 1, -1_000_000_000));
+        assert_eq!(parse("-P-1D"), ok(0, 0, 1, 0));
+        assert_eq!(parse("PT0.000000001S"), ok(0, 0, 0, 1));
+        assert_eq!(parse("PT0.0000000019S"), ok(0, 0, 0, 1));
+        assert_eq!(parse("PT0.5H"), ok(0, 0, 0, 1_800_000_000_000));
+        assert_eq!(parse("PT1,25M"), ok(0, 0, 0, 75_000_000_000));
+        assert_eq!(parse("P0D"), ok(0, 0, 0, 0));
+
+        assert_eq!(parse(""), Err(TooShort));
+        assert_eq!(parse("-"), Err(TooShort));
+        assert_eq!(parse("P"), Err(TooShort));
+        assert_eq!(parse("PT"), Err(TooShort));
+        assert_eq!(parse("P1DT"), Err(TooShort));
+        assert_eq!(parse("P1"), Err(TooShort));
+        assert_eq!(parse("PT1."), Err(TooShort));
+        assert_eq!(parse("1D"), Err(Invalid));
+        assert_eq!(parse("p1d"), Err(Invalid));
+        assert_eq!(parse("P1H"), Err(Invalid));
+        assert_eq!(parse("PT1D"), Err(Invalid));
+        assert_eq!(parse("P1D1Y"), Err(Invalid));
+        assert_eq!(parse("P1D1D"), Err(Invalid));
+        assert_eq!(parse("PT1S1M"), Err(Invalid));
+        assert_eq!(parse("PT1TS"), Err(Invalid));
+        assert_eq!(parse("PT1.5M1S"), Err(Invalid));
+        assert_eq!(parse("P1.5D"), Err(Invalid));
+        assert_eq!(parse("PT.5S"), Err(Invalid));
+        assert_eq!(parse("P+1D"), Err(Invalid));
+        assert_eq!(parse("P1D "), Err(Invalid));
+        assert_eq!(parse("P99999999999999999999D"), Err(OutOfRange));
+        assert_eq!(parse("P9999999999999999999W"), Err(OutOfRange));
+    }
+
+    #[test]
+    fn test_time_delta_from_str() {
+        let parse = |s: &str| s.parse::<TimeDelta>().map_err(|e| e.kind());
+        assert_eq!(parse("P1DT1S"), Ok(TimeDelta::new(86_401, 0).unwrap()));
+        assert_eq!(parse("-PT0.5S"), Ok(TimeDelta::try_milliseconds(-500).unwrap()));
+        assert_eq!(parse("P0Y0M1D"), Ok(TimeDelta::try_days(1).unwrap()));
+        assert_eq!(parse("P1Y"), Err(Impossible));
+
+        for delta in [
+            TimeDelta::zero(),
+            TimeDelta::nanoseconds(1),
+            TimeDelta::nanoseconds(-1),
+            TimeDelta::new(1, 100_000_000).unwrap(),
+            TimeDelta::new(-86_400, 20).unwrap(),
+            TimeDelta::max_value(),
+            TimeDelta::min_value(),
+        ] {
+            assert_eq!(parse(&delta.to_string()), Ok(delta));
+        }
+        let max = TimeDelta::max_value();
+        let too_large = format!("PT{}.{:09}S", max.num_seconds() + 1, 0);
+        assert_eq!(parse(&too_large), Err(OutOfRange));
+    }
+
+    #[test]
+    fn test_period_from_str() {
+        let parse = |s: &str| s.parse::<Period>().map_err(|e| e.kind());
+        assert_eq!(
+            parse("P1Y2M3W4DT1.5S"),
+            Ok(Period::new(1, 2, 25, TimeDelta::new(1, 500_000_000).unwrap()))
+        );
+        assert_eq!(parse("P2147483648Y"), Err(OutOfRange));
+        assert_eq!(parse("-P2147483648Y"), Ok(Period::years(i32::MIN)));
+
+        for period in [
+            Period::ZERO,
+            Period::new(1, 2, 3, TimeDelta::new(3723, 400).unwrap()),
+            Period::new(-1, -2, -3, -TimeDelta::new(3723, 400).unwrap()),
+            Period::new(1, -2, 0, TimeDelta::zero()),
+            Period::new(0, 0, 1, -TimeDelta::new(0, 1).unwrap()),
+            Period::time(TimeDelta::try_hours(25).unwrap()),
+            Period::new(i32::MIN, i32::MAX, i32::MIN, TimeDelta::max_value()),
+        ] {
+            assert_eq!(parse(&period.to_string()), Ok(period), "{}", period);
+        }
+    }
+}
@@ -38,6 +38,8 @@ pub struct DelayedFormat<I> {
     time: Option<NaiveTime>,
     /// The name and local-to-UTC difference for the offset (timezone), if any.
     off: Option<(String, FixedOffset)>,
+    /// The identifier of the time zone of the offset, if any.
+    tz_id: Option<String>,
     /// An iterator returning formatting items.
     items: I,
     /// Locale used for text.
@@ -56,6 +58,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             date,
             time,
             off: None,
+            tz_id: None,
             items,
             #[cfg(feature = "unstable-locales")]
             locale: None,
@@ -78,6 +81,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             date,
             time,
             off: Some(name_and_diff),
+            tz_id: offset.time_zone_id().map(Into::into),
             items,
             #[cfg(feature = "unstable-locales")]
             locale: None,
@@ -93,7 +97,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         items: I,
         locale: Locale,
     ) -> DelayedFormat<I> {
-        DelayedFormat { date, time, off: None, items, locale: Some(locale) }
+        DelayedFormat { date, time, off: None, tz_id: None, items, locale: Some(locale) }
     }
 
     /// Makes a new `DelayedFormat` value out of local date and time, UTC offset and locale.
@@ -110,7 +114,8 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         Off: Offset + Display,
     {
         let name_and_diff = (offset.to_string(), offset.fix());
-        DelayedFormat { date, time, off: Some(name_and_diff), items, locale: Some(locale) }
+        let tz_id = offset.time_zone_id().map(Into::into);
+        DelayedFormat { date, time, off: Some(name_and_diff), tz_id, items, locale: Some(locale) }
     }
 }
 
@@ -129,6 +134,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> Display for Delayed
                 self.date.as_ref(),
                 self.time.as_ref(),
                 self.off.as_ref(),
+                self.tz_id.as_deref(),
                 item.borrow(),
                 locale,
             )?;
@@ -156,6 +162,7 @@ where
         date: date.copied(),
         time: time.copied(),
         off: off.cloned(),
+        tz_id: None,
         items,
         #[cfg(feature = "unstable-locales")]
         locale: None,
@@ -177,6 +184,7 @@ pub fn format_item(
         date: date.copied(),
         time: time.copied(),
         off: off.cloned(),
+        tz_id: None,
         items: [item].into_iter(),
         #[cfg(feature = "unstable-locales")]
         locale: None,
@@ -190,6 +198,7 @@ fn format_inner(
     date: Option<&NaiveDate>,
     time: Option<&NaiveTime>,
     off: Option<&(String, FixedOffset)>,
+    tz_id: Option<&str>,
     item: &Item<'_>,
     locale: Option<Locale>,
 ) -> fmt::Result {
@@ -221,6 +230,7 @@ fn format_inner(
                 NumDaysFromSun => (1, date.map(|d| i64::from(d.weekday().num_days_from_sunday()))),
                 WeekdayFromMon => (1, date.map(|d| i64::from(d.weekday().number_from_monday()))),
                 Ordinal => (3, date.map(|d| i64::from(d.ordinal()))),
+                JulianDay => (1, date.map(|d| i64::from(d.to_julian_day()))),
                 Hour => (2, time.map(|t| i64::from(t.hour()))),
                 Hour12 => (2, time.map(|t| i64::from(t.hour12().1))),
                 Minute => (2, time.map(|t| i64::from(t.minute()))),
@@ -348,6 +358,7 @@ fn format_inner(
                     w.write_str(name)?;
                     Ok(())
                 }),
+                TimezoneId => off.map(|(name, _)| w.write_str(tz_id.unwrap_or(name))),
                 TimezoneOffset | TimezoneOffsetZ => off.map(|&(_, off)| {
                     OffsetFormat {
                         precision: OffsetPrecision::Minutes,
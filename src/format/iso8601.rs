@@ -0,0 +1,853 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! ISO 8601 parsing and formatting.
+//!
+//! [`parse_iso8601`] accepts the date and time representations of ISO 8601 that identify a
+//! complete date or time, in both the *basic* format without separators and the *extended*
+//! format with `-` and `:` separators:
+//!
+//! | Representation           | Extended             | Basic              |
+//! |--------------------------|----------------------|--------------------|
+//! | Calendar date            | `2024-01-31`         | `20240131`         |
+//! | Ordinal date             | `2024-031`           | `2024031`          |
+//! | Week date                | `2024-W05-3`         | `2024W053`         |
+//! | Reduced precision date   | `2024-01`, `2024-W05`, `2024` | `2024W05`, `2024` |
+//! | Expanded year            | `+12024-01-31`, `-0001-12-31` | `+0120240131` |
+//! | Time                     | `10:15:30.25`        | `101530.25`        |
+//! | Reduced precision time   | `10:15`, `10`        | `1015`, `10`       |
+//! | Decimal fraction         | `10:15.5`, `10.25`   | `1015.5`, `10.25`  |
+//! | Date and time            | `2024-01-31T10:15:30`| `20240131T101530`  |
+//! | Offset from UTC          | `Z`, `+01:00`, `-05` | `Z`, `+0100`, `-05`|
+//!
+//! A date with reduced precision is resolved to the first day of the month, week or year, and
+//! can only be used on its own. A decimal fraction can be written with either `.` or `,` and
+//! applies to the last component of the time; digits beyond nanosecond precision are truncated.
+//! The end of a day can be written as `24:00`, which is the start of the next day. A second of
+//! `60` is parsed as a leap second.
+//!
+//! An expanded year, with a sign, has at least four digits in the extended format. In the basic
+//! format it can't be told apart from the month and day that follow, so there it always has six
+//! digits, which is enough for the range of [`NaiveDate`].
+//!
+//! The formatters write one of the complete representations, with the style and precision given
+//! by an [`Iso8601Format`].
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::format::iso8601::{format_iso8601, parse_iso8601, Iso8601Format};
+//! use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+//!
+//! let date: NaiveDate = parse_iso8601("2024-W05-3")?;
+//! assert_eq!(date, NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+//!
+//! let dt: NaiveDateTime = parse_iso8601("2024-031T10.5")?;
+//! assert_eq!(dt, date.and_hms_opt(10, 30, 0).unwrap());
+//!
+//! let dt: DateTime<FixedOffset> = parse_iso8601("20240131T101500Z")?;
+//! assert_eq!(format_iso8601(dt, Iso8601Format::EXTENDED).to_string(), "2024-01-31T10:15:00+00:00");
+//! assert_eq!(format_iso8601(dt, Iso8601Format::BASIC.with_z(true)).to_string(), "20240131T101500Z");
+//! # Ok::<(), chrono::ParseError>(())
+//! ```
+
+use core::fmt::{self, Write};
+use core::ops::RangeInclusive;
+
+use super::{ErrorItem, Fixed, Numeric, ParseError, ParseResult, SecondsFormat};
+use super::{INVALID, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
+use crate::format::write_hundreds;
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{FixedOffset, Offset};
+use crate::{DateTime, Datelike, Weekday};
+
+/// Parses an ISO 8601 string into a [`NaiveDate`], [`NaiveTime`], [`NaiveDateTime`] or
+/// [`DateTime<FixedOffset>`].
+///
+/// See the [module documentation](self) for the accepted representations. A date and time with
+/// an offset from UTC can only be parsed as a `DateTime<FixedOffset>`, and a `DateTime` requires
+/// an offset.
+///
+/// # Errors
+///
+/// Returns an error if the string is not a valid ISO 8601 representation of the type, or if the
+/// value it describes does not exist. A time of `24:00` can not be parsed as a `NaiveTime`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::iso8601::parse_iso8601;
+/// use chrono::format::ParseErrorKind;
+/// use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+/// assert_eq!(parse_iso8601::<NaiveDate>("2024-123"), Ok(date));
+/// assert_eq!(parse_iso8601::<NaiveDate>("2024-05"), Ok(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()));
+///
+/// let end_of_day = parse_iso8601::<NaiveDateTime>("2024-05-02T24:00");
+/// assert_eq!(end_of_day, Ok(date.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap()));
+/// assert_eq!(parse_iso8601::<NaiveTime>("T13,25"), Ok(NaiveTime::from_hms_opt(13, 15, 0).unwrap()));
+///
+/// let err = parse_iso8601::<NaiveDate>("2024-13-01").unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::OutOfRange);
+/// assert_eq!(err.position(), Some(5));
+/// ```
+pub fn parse_iso8601<T: Iso8601>(s: &str) -> ParseResult<T> {
+    T::parse_iso8601(s)
+}
+
+/// Returns a value that formats `value` as ISO 8601 with the given `format` when displayed.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::iso8601::{format_iso8601, Iso8601DateForm, Iso8601Format};
+/// use chrono::format::SecondsFormat;
+/// use chrono::NaiveDate;
+///
+/// let dt = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_milli_opt(10, 15, 0, 500).unwrap();
+/// assert_eq!(format_iso8601(dt, Iso8601Format::EXTENDED).to_string(), "2024-01-31T10:15:00.500");
+///
+/// let week = Iso8601Format::BASIC.with_date_form(Iso8601DateForm::Week);
+/// assert_eq!(format_iso8601(dt.date(), week).to_string(), "2024W053");
+///
+/// let ordinal = Iso8601Format::EXTENDED
+///     .with_date_form(Iso8601DateForm::Ordinal)
+///     .with_seconds_format(SecondsFormat::Secs);
+/// assert_eq!(format_iso8601(dt, ordinal).to_string(), "2024-031T10:15:00");
+/// ```
+pub fn format_iso8601<T: Iso8601>(value: T, format: Iso8601Format) -> FormattedIso8601<T> {
+    FormattedIso8601 { value, format }
+}
+
+/// The date and time types that can be parsed from and formatted as ISO 8601.
+///
+/// This is implemented for [`NaiveDate`], [`NaiveTime`], [`NaiveDateTime`] and
+/// [`DateTime<FixedOffset>`]. Use the [`parse_iso8601`] and [`format_iso8601`] functions instead
+/// of calling the methods of this trait directly.
+pub trait Iso8601: Sized {
+    /// Parses an ISO 8601 representation of this type.
+    fn parse_iso8601(s: &str) -> ParseResult<Self>;
+
+    /// Writes the ISO 8601 representation of `self` with the given format.
+    fn write_iso8601(&self, w: &mut fmt::Formatter, format: &Iso8601Format) -> fmt::Result;
+}
+
+/// Whether ISO 8601 values are written in the basic or the extended format.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum Iso8601Style {
+    /// The basic format without separators, such as `20240131T101500`.
+    Basic,
+    /// The extended format with `-` and `:` separators, such as `2024-01-31T10:15:00`.
+    Extended,
+}
+
+/// The representation used to write the date.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum Iso8601DateForm {
+    /// A calendar date with year, month and day, such as `2024-01-31`.
+    Calendar,
+    /// An ordinal date with year and day of the year, such as `2024-031`.
+    Ordinal,
+    /// A week date with ISO week-based year, week and weekday, such as `2024-W05-3`.
+    Week,
+}
+
+/// The options used by [`format_iso8601`].
+///
+/// Years outside the range 0 to 9999 are always written with a sign and at least four digits.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Iso8601Format {
+    style: Iso8601Style,
+    date_form: Iso8601DateForm,
+    seconds: SecondsFormat,
+    use_z: bool,
+}
+
+impl Iso8601Format {
+    /// The extended format with calendar dates and as many fractional digits as needed, such as
+    /// `2024-01-31T10:15:00.500+01:00`.
+    pub const EXTENDED: Iso8601Format = Iso8601Format {
+        style: Iso8601Style::Extended,
+        date_form: Iso8601DateForm::Calendar,
+        seconds: SecondsFormat::AutoSi,
+        use_z: false,
+    };
+
+    /// The basic format with calendar dates and as many fractional digits as needed, such as
+    /// `20240131T101500.500+0100`.
+    pub const BASIC: Iso8601Format =
+        Iso8601Format { style: Iso8601Style::Basic, ..Iso8601Format::EXTENDED };
+
+    /// Returns the format with a different style.
+    #[must_use]
+    pub const fn with_style(self, style: Iso8601Style) -> Iso8601Format {
+        Iso8601Format { style, ..self }
+    }
+
+    /// Returns the format with a different representation of the date.
+    #[must_use]
+    pub const fn with_date_form(self, date_form: Iso8601DateForm) -> Iso8601Format {
+        Iso8601Format { date_form, ..self }
+    }
+
+    /// Returns the format with a different number of fractional digits for the seconds.
+    #[must_use]
+    pub const fn with_seconds_format(self, seconds: SecondsFormat) -> Iso8601Format {
+        Iso8601Format { seconds, ..self }
+    }
+
+    /// Returns the format that writes a zero offset from UTC as `Z` if `use_z` is true.
+    #[must_use]
+    pub const fn with_z(self, use_z: bool) -> Iso8601Format {
+        Iso8601Format { use_z, ..self }
+    }
+
+    /// Returns the style.
+    #[must_use]
+    pub const fn style(&self) -> Iso8601Style {
+        self.style
+    }
+
+    /// Returns the representation of the date.
+    #[must_use]
+    pub const fn date_form(&self) -> Iso8601DateForm {
+        self.date_form
+    }
+
+    /// Returns the number of fractional digits for the seconds.
+    #[must_use]
+    pub const fn seconds_format(&self) -> SecondsFormat {
+        self.seconds
+    }
+
+    /// Returns whether a zero offset from UTC is written as `Z`.
+    #[must_use]
+    pub const fn use_z(&self) -> bool {
+        self.use_z
+    }
+
+    fn separator(&self, w: &mut impl Write, c: char) -> fmt::Result {
+        match self.style {
+            Iso8601Style::Basic => Ok(()),
+            Iso8601Style::Extended => w.write_char(c),
+        }
+    }
+
+    /// Writes a year with four digits, or with a sign if it is outside the range 0 to 9999. An
+    /// expanded year has six digits in the basic format.
+    fn write_year(&self, w: &mut impl Write, year: i32) -> fmt::Result {
+        if (0..=9999).contains(&year) {
+            write_hundreds(w, (year / 100) as u8)?;
+            write_hundreds(w, (year % 100) as u8)
+        } else {
+            match self.style {
+                Iso8601Style::Basic => write!(w, "{:+07}", year),
+                Iso8601Style::Extended => write!(w, "{:+05}", year),
+            }
+        }
+    }
+
+    fn write_date(&self, w: &mut impl Write, date: NaiveDate) -> fmt::Result {
+        match self.date_form {
+            Iso8601DateForm::Calendar => {
+                self.write_year(w, date.year())?;
+                self.separator(w, '-')?;
+                write_hundreds(w, date.month() as u8)?;
+                self.separator(w, '-')?;
+                write_hundreds(w, date.day() as u8)
+            }
+            Iso8601DateForm::Ordinal => {
+                self.write_year(w, date.year())?;
+                self.separator(w, '-')?;
+                write!(w, "{:03}", date.ordinal())
+            }
+            Iso8601DateForm::Week => {
+                let week = date.iso_week();
+                self.write_year(w, week.year())?;
+                self.separator(w, '-')?;
+                w.write_char('W')?;
+                write_hundreds(w, week.week() as u8)?;
+                self.separator(w, '-')?;
+                write!(w, "{}", date.weekday().number_from_monday())
+            }
+        }
+    }
+
+    fn write_time(&self, w: &mut impl Write, time: NaiveTime) -> fmt::Result {
+        let (hour, minute, mut second) = time.hms();
+        let mut nano = time.nanosecond();
+        if nano >= 1_000_000_000 {
+            second += 1;
+            nano -= 1_000_000_000;
+        }
+        write_hundreds(w, hour as u8)?;
+        self.separator(w, ':')?;
+        write_hundreds(w, minute as u8)?;
+        self.separator(w, ':')?;
+        write_hundreds(w, second as u8)?;
+        match self.seconds {
+            SecondsFormat::Millis => write!(w, ".{:03}", nano / 1_000_000),
+            SecondsFormat::Micros => write!(w, ".{:06}", nano / 1_000),
+            SecondsFormat::Nanos => write!(w, ".{:09}", nano),
+            SecondsFormat::AutoSi if nano == 0 => Ok(()),
+            SecondsFormat::AutoSi if nano % 1_000_000 == 0 => {
+                write!(w, ".{:03}", nano / 1_000_000)
+            }
+            SecondsFormat::AutoSi if nano % 1_000 == 0 => write!(w, ".{:06}", nano / 1_000),
+            SecondsFormat::AutoSi => write!(w, ".{:09}", nano),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_offset(&self, w: &mut impl Write, offset: FixedOffset) -> fmt::Result {
+        let offset = offset.local_minus_utc();
+        if self.use_z && offset == 0 {
+            return w.write_char('Z');
+        }
+        w.write_char(if offset < 0 { '-' } else { '+' })?;
+        // ISO 8601 has no seconds in offsets, truncate them so the offset stays below `24:00`
+        let minutes = offset.abs() / 60;
+        write_hundreds(w, (minutes / 60) as u8)?;
+        self.separator(w, ':')?;
+        write_hundreds(w, (minutes % 60) as u8)
+    }
+}
+
+impl Default for Iso8601Format {
+    fn default() -> Self {
+        Iso8601Format::EXTENDED
+    }
+}
+
+/// A value formatted as ISO 8601 when displayed, returned by [`format_iso8601`].
+#[derive(Clone, Copy, Debug)]
+pub struct FormattedIso8601<T> {
+    value: T,
+    format: Iso8601Format,
+}
+
+impl<T: Iso8601> fmt::Display for FormattedIso8601<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.write_iso8601(f, &self.format)
+    }
+}
+
+impl Iso8601 for NaiveDate {
+    fn parse_iso8601(s: &str) -> ParseResult<NaiveDate> {
+        Parser::run(s, |p| p.date().map(|(date, _)| date))
+    }
+
+    fn write_iso8601(&self, w: &mut fmt::Formatter, format: &Iso8601Format) -> fmt::Result {
+        format.write_date(w, *self)
+    }
+}
+
+impl Iso8601 for NaiveTime {
+    fn parse_iso8601(s: &str) -> ParseResult<NaiveTime> {
+        Parser::run(s, |p| {
+            p.eat(b'T');
+            match p.time()? {
+                (_, true) => Err(OUT_OF_RANGE),
+                (time, false) => Ok(time),
+            }
+        })
+    }
+
+    fn write_iso8601(&self, w: &mut fmt::Formatter, format: &Iso8601Format) -> fmt::Result {
+        format.write_time(w, *self)
+    }
+}
+
+impl Iso8601 for NaiveDateTime {
+    fn parse_iso8601(s: &str) -> ParseResult<NaiveDateTime> {
+        Parser::run(s, |p| p.datetime())
+    }
+
+    fn write_iso8601(&self, w: &mut fmt::Formatter, format: &Iso8601Format) -> fmt::Result {
+        format.write_date(w, self.date())?;
+        w.write_char('T')?;
+        format.write_time(w, self.time())
+    }
+}
+
+impl Iso8601 for DateTime<FixedOffset> {
+    fn parse_iso8601(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        Parser::run(s, |p| {
+            let datetime = p.datetime()?;
+            let offset = p.offset()?;
+            datetime.and_local_timezone(offset).single().ok_or(OUT_OF_RANGE)
+        })
+    }
+
+    fn write_iso8601(&self, w: &mut fmt::Formatter, format: &Iso8601Format) -> fmt::Result {
+        self.naive_local().write_iso8601(w, format)?;
+        format.write_offset(w, self.offset().fix())
+    }
+}
+
+/// A cursor over the input. Errors of the syntax are located at the cursor.
+struct Parser<'a> {
+    s: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    /// Runs `f` on the complete input `s`.
+    fn run<T>(s: &str, f: impl FnOnce(&mut Parser) -> ParseResult<T>) -> ParseResult<T> {
+        let mut parser = Parser { s };
+        let result = f(&mut parser).and_then(|value| match parser.s {
+            "" => Ok(value),
+            rest => Err(TOO_LONG.with_context(rest, None)),
+        });
+        result.map_err(|e| e.locate(s))
+    }
+
+    fn error(&self, err: ParseError, at: usize, item: ErrorItem) -> ParseError {
+        err.with_context(&self.s[at..], Some(item))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().first().copied()
+    }
+
+    fn peek_digit(&self) -> bool {
+        self.peek().map_or(false, |c| c.is_ascii_digit())
+    }
+
+    fn count_digits(&self) -> usize {
+        self.s.bytes().take_while(u8::is_ascii_digit).count()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.s = &self.s[1..];
+        }
+        eaten
+    }
+
+    fn expect(&mut self, c: u8) -> ParseResult<()> {
+        match self.peek() {
+            Some(found) if found == c => {
+                self.s = &self.s[1..];
+                Ok(())
+            }
+            Some(_) => Err(self.error(INVALID, 0, ErrorItem::Literal(c as char))),
+            None => Err(self.error(TOO_SHORT, 0, ErrorItem::Literal(c as char))),
+        }
+    }
+
+    /// Reads a number of exactly `digits` digits, which must be in `range`.
+    fn number(
+        &mut self,
+        digits: usize,
+        range: RangeInclusive<u32>,
+        item: ErrorItem,
+    ) -> ParseResult<u32> {
+        let mut value = 0u32;
+        for i in 0..digits {
+            match self.s.as_bytes().get(i) {
+                Some(c) if c.is_ascii_digit() => value = value * 10 + u32::from(c - b'0'),
+                Some(_) => return Err(self.error(INVALID, i, item)),
+                None => return Err(self.error(TOO_SHORT, i, item)),
+            }
+        }
+        if !range.contains(&value) {
+            return Err(self.error(OUT_OF_RANGE, 0, item));
+        }
+        self.s = &self.s[digits..];
+        Ok(value)
+    }
+
+    /// Reads a date, and returns whether it has full precision.
+    fn date(&mut self) -> ParseResult<(NaiveDate, bool)> {
+        let year_item = ErrorItem::Numeric(&Numeric::Year);
+        let year = match self.peek() {
+            Some(sign @ (b'+' | b'-')) => {
+                self.s = &self.s[1..];
+                // only the extended format has a separator after an expanded year
+                let digits = match self.count_digits() {
+                    digits if self.s.as_bytes().get(digits) == Some(&b'-') => digits.clamp(4, 9),
+                    _ => 6,
+                };
+                let year = self.number(digits, 0..=999_999_999, year_item)? as i32;
+                if sign == b'-' {
+                    -year
+                } else {
+                    year
+                }
+            }
+            _ => self.number(4, 0..=9999, year_item)? as i32,
+        };
+
+        let extended = self.eat(b'-');
+        if self.eat(b'W') {
+            let week = self.number(2, 1..=53, ErrorItem::Numeric(&Numeric::IsoWeek))?;
+            let weekday_item = ErrorItem::Numeric(&Numeric::WeekdayFromMon);
+            let weekday = match extended {
+                true if self.eat(b'-') => Some(self.number(1, 1..=7, weekday_item)?),
+                false if self.peek_digit() => Some(self.number(1, 1..=7, weekday_item)?),
+                _ => None,
+            };
+            let day = Weekday::try_from((weekday.unwrap_or(1) - 1) as u8).map_err(|_| INVALID)?;
+            let date = NaiveDate::from_isoywd_opt(year, week, day).ok_or(OUT_OF_RANGE)?;
+            return Ok((date, weekday.is_some()));
+        }
+        if !extended && !self.peek_digit() {
+            return Ok((NaiveDate::from_ymd_opt(year, 1, 1).ok_or(OUT_OF_RANGE)?, false));
+        }
+        match self.count_digits() {
+            3 => {
+                let ordinal = self.number(3, 1..=366, ErrorItem::Numeric(&Numeric::Ordinal))?;
+                Ok((NaiveDate::from_yo_opt(year, ordinal).ok_or(OUT_OF_RANGE)?, true))
+            }
+            // `YYYYMM` is not allowed in the basic format, it could be mistaken for `YYMMDD`
+            digits if !extended && digits != 4 => {
+                Err(self.error(INVALID, 0, ErrorItem::Numeric(&Numeric::Month)))
+            }
+            _ => {
+                let month = self.number(2, 1..=12, ErrorItem::Numeric(&Numeric::Month))?;
+                let day_item = ErrorItem::Numeric(&Numeric::Day);
+                let day = match extended {
+                    true if !self.eat(b'-') => None,
+                    _ => Some(self.number(2, 1..=31, day_item)?),
+                };
+                let date = NaiveDate::from_ymd_opt(year, month, day.unwrap_or(1));
+                Ok((date.ok_or(OUT_OF_RANGE)?, day.is_some()))
+            }
+        }
+    }
+
+    /// Reads a time, and returns whether it is `24:00`, the end of the day.
+    fn time(&mut self) -> ParseResult<(NaiveTime, bool)> {
+        let hour = self.number(2, 0..=24, ErrorItem::Numeric(&Numeric::Hour))?;
+        let minute_item = ErrorItem::Numeric(&Numeric::Minute);
+        let second_item = ErrorItem::Numeric(&Numeric::Second);
+        let (mut minute, mut second) = (None, None);
+        if self.eat(b':') {
+            minute = Some(self.number(2, 0..=59, minute_item)?);
+            if self.eat(b':') {
+                second = Some(self.number(2, 0..=60, second_item)?);
+            }
+        } else if self.peek_digit() {
+            minute = Some(self.number(2, 0..=59, minute_item)?);
+            if self.peek_digit() {
+                second = Some(self.number(2, 0..=60, second_item)?);
+            }
+        }
+        let fraction = match self.eat(b'.') || self.eat(b',') {
+            true => u64::from(self.fraction()?),
+            false => 0,
+        };
+
+        if hour == 24 {
+            if minute.unwrap_or(0) != 0 || second.unwrap_or(0) != 0 || fraction != 0 {
+                return Err(OUT_OF_RANGE);
+            }
+            return Ok((NaiveTime::MIN, true));
+        }
+        // The fraction is in nanoseconds of the last component.
+        let (secs, nanos) = match (minute, second) {
+            (None, _) => (hour * 3600, fraction * 3600),
+            (Some(minute), None) => (hour * 3600 + minute * 60, fraction * 60),
+            (Some(minute), Some(60)) => {
+                let secs = hour * 3600 + minute * 60 + 59;
+                let time = NaiveTime::from_num_seconds_from_midnight_opt(
+                    secs,
+                    1_000_000_000 + fraction as u32,
+                );
+                return Ok((time.ok_or(OUT_OF_RANGE)?, false));
+            }
+            (Some(minute), Some(second)) => (hour * 3600 + minute * 60 + second, fraction),
+        };
+        let secs = secs + (nanos / 1_000_000_000) as u32;
+        let nano = (nanos % 1_000_000_000) as u32;
+        Ok((NaiveTime::from_num_seconds_from_midnight_opt(secs, nano).ok_or(OUT_OF_RANGE)?, false))
+    }
+
+    /// Reads the digits of a decimal fraction as nanoseconds, ignoring digits past the ninth.
+    fn fraction(&mut self) -> ParseResult<u32> {
+        let digits = self.count_digits();
+        if digits == 0 {
+            let err = if self.s.is_empty() { TOO_SHORT } else { INVALID };
+            return Err(self.error(err, 0, ErrorItem::Fixed(&Fixed::Nanosecond)));
+        }
+        let mut nano = 0;
+        for i in 0..9 {
+            let digit = if i < digits { u32::from(self.s.as_bytes()[i] - b'0') } else { 0 };
+            nano = nano * 10 + digit;
+        }
+        self.s = &self.s[digits..];
+        Ok(nano)
+    }
+
+    /// Reads an offset from UTC, `Z` or a sign followed by hours and optionally minutes.
+    fn offset(&mut self) -> ParseResult<FixedOffset> {
+        let item = ErrorItem::Fixed(&Fixed::TimezoneOffsetColonZ);
+        let negative = match self.peek() {
+            Some(b'Z') => {
+                self.s = &self.s[1..];
+                return FixedOffset::east_opt(0).ok_or(OUT_OF_RANGE);
+            }
+            Some(b'+') => false,
+            Some(b'-') => true,
+            Some(_) => return Err(self.error(INVALID, 0, item)),
+            None => return Err(self.error(TOO_SHORT, 0, item)),
+        };
+        self.s = &self.s[1..];
+        let hours = self.number(2, 0..=23, item)?;
+        let minutes = match self.eat(b':') || self.peek_digit() {
+            true => self.number(2, 0..=59, item)?,
+            false => 0,
+        };
+        let offset = (hours * 3600 + minutes * 60) as i32;
+        FixedOffset::east_opt(if negative { -offset } else { offset }).ok_or(OUT_OF_RANGE)
+    }
+
+    /// Reads a complete date, `T` and a time.
+    fn datetime(&mut self) -> ParseResult<NaiveDateTime> {
+        let (date, complete) = self.date()?;
+        if !complete && !self.s.is_empty() {
+            // a date with reduced precision can not be combined with a time
+            return Err(INVALID.with_context(self.s, None));
+        }
+        self.expect(b'T')?;
+        let (time, end_of_day) = self.time()?;
+        match end_of_day {
+            true => Ok(date.succ_opt().ok_or(OUT_OF_RANGE)?.and_time(time)),
+            false => Ok(date.and_time(time)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_iso8601;
+    #[cfg(feature = "alloc")]
+    use super::{format_iso8601, Iso8601DateForm, Iso8601Format, Iso8601Style};
+    use crate::format::ParseErrorKind;
+    #[cfg(feature = "alloc")]
+    use crate::format::SecondsFormat;
+    use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+    use crate::{DateTime, FixedOffset, TimeZone};
+
+    #[test]
+    fn test_parse_iso8601_date() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let parse = |s| parse_iso8601::<NaiveDate>(s).map_err(|e| e.kind());
+        let date = ymd(2024, 1, 31);
+        for s in ["2024-01-31", "20240131", "2024-031", "2024031", "2024-W05-3", "2024W053"] {
+            assert_eq!(parse(s), Ok(date), "{}", s);
+        }
+        // reduced precision
+        assert_eq!(parse("2024-01"), Ok(ymd(2024, 1, 1)));
+        assert_eq!(parse("2024"), Ok(ymd(2024, 1, 1)));
+        assert_eq!(parse("2024-W05"), Ok(ymd(2024, 1, 29)));
+        assert_eq!(parse("2024W05"), Ok(ymd(2024, 1, 29)));
+        assert_eq!(parse("2021-W01-1"), Ok(ymd(2021, 1, 4)));
+        assert_eq!(parse("2020-W53-7"), Ok(ymd(2021, 1, 3)));
+        // expanded years
+        assert_eq!(parse("+12024-01-31"), Ok(ymd(12024, 1, 31)));
+        assert_eq!(parse("-0001-12-31"), Ok(ymd(-1, 12, 31)));
+        assert_eq!(parse("+0000-01-01"), Ok(ymd(0, 1, 1)));
+        assert_eq!(parse("+0120240131"), Ok(ymd(12024, 1, 31)));
+        assert_eq!(parse("-0000011231"), Ok(ymd(-1, 12, 31)));
+        assert_eq!(parse("-000001365"), Ok(ymd(-1, 12, 31)));
+        assert_eq!(parse("+012024W053"), Ok(ymd(12024, 1, 31)));
+        assert_eq!(parse("+012024"), Ok(ymd(12024, 1, 1)));
+
+        assert_eq!(parse(""), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("202"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("2024-"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("2024-1-31"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("202401"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2024-01-31T"), Err(ParseErrorKind::TooLong));
+        assert_eq!(parse("2024-0131"), Err(ParseErrorKind::TooLong));
+        assert_eq!(parse("2024-13-01"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2023-02-29"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2023-366"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2021-W53-1"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2024-W05-8"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("+999999-01-01"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("+24-01-01"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("+12024"), Err(ParseErrorKind::TooShort));
+    }
+
+    #[test]
+    fn test_parse_iso8601_time() {
+        let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+
+        let parse = |s| parse_iso8601::<NaiveTime>(s).map_err(|e| e.kind());
+        assert_eq!(parse("10:15:30"), Ok(hmsn(10, 15, 30, 0)));
+        assert_eq!(parse("101530"), Ok(hmsn(10, 15, 30, 0)));
+        assert_eq!(parse("T101530"), Ok(hmsn(10, 15, 30, 0)));
+        assert_eq!(parse("10:15"), Ok(hmsn(10, 15, 0, 0)));
+        assert_eq!(parse("1015"), Ok(hmsn(10, 15, 0, 0)));
+        assert_eq!(parse("10"), Ok(hmsn(10, 0, 0, 0)));
+        assert_eq!(parse("10:15:30.25"), Ok(hmsn(10, 15, 30, 250_000_000)));
+        assert_eq!(parse("10:15:30,123456789123"), Ok(hmsn(10, 15, 30, 123_456_789)));
+        assert_eq!(parse("10:15.5"), Ok(hmsn(10, 15, 30, 0)));
+        assert_eq!(parse("10.25"), Ok(hmsn(10, 15, 0, 0)));
+        assert_eq!(parse("10,000001"), Ok(hmsn(10, 0, 0, 3_600_000)));
+        assert_eq!(parse("23:59:60"), Ok(hmsn(23, 59, 59, 1_000_000_000)));
+        assert_eq!(parse("23:59:60.5"), Ok(hmsn(23, 59, 59, 1_500_000_000)));
+
+        assert_eq!(parse("24:00"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("25:00"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("10:60"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("10:15:61"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("10:15:30."), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("10:15:30.x"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("10:1530"), Err(ParseErrorKind::TooLong));
+        assert_eq!(parse("1"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("10:15Z"), Err(ParseErrorKind::TooLong));
+    }
+
+    #[test]
+    fn test_parse_iso8601_datetime() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let parse = |s| parse_iso8601::<NaiveDateTime>(s).map_err(|e| e.kind());
+        let dt = ymd(2024, 1, 31).and_hms_opt(10, 15, 0).unwrap();
+        for s in ["2024-01-31T10:15:00", "20240131T101500", "2024-031T10:15", "2024W053T10.25"] {
+            assert_eq!(parse(s), Ok(dt), "{}", s);
+        }
+        assert_eq!(parse("2024-12-31T24:00"), Ok(ymd(2025, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(parse("2024-12-31T24:00:00.0"), Ok(ymd(2025, 1, 1).and_time(NaiveTime::MIN)));
+        assert_eq!(parse("2024-12-31T24:00:01"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2024-01-31"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("2024-01-31 10:15"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2024-01T10:15"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2024-01-31T10:15Z"), Err(ParseErrorKind::TooLong));
+
+        let parse = |s| parse_iso8601::<DateTime<FixedOffset>>(s).map_err(|e| e.kind());
+        let offset = |secs| FixedOffset::east_opt(secs).unwrap();
+        let dt_z = offset(0).from_local_datetime(&dt).unwrap();
+        assert_eq!(parse("2024-01-31T10:15:00Z"), Ok(dt_z));
+        assert_eq!(parse("20240131T1015Z"), Ok(dt_z));
+        assert_eq!(parse("2024-01-31T10:15:00+00:00"), Ok(dt_z));
+        assert_eq!(
+            parse("2024-01-31T10:15-05"),
+            Ok(offset(-5 * 3600).from_local_datetime(&dt).unwrap())
+        );
+        assert_eq!(
+            parse("20240131T1015+0530"),
+            Ok(offset(19800).from_local_datetime(&dt).unwrap())
+        );
+        assert_eq!(parse("2024-01-31T10:15"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("2024-01-31T10:15+5"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("2024-01-31T10:15 +05"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2024-01-31T10:15+24:00"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2024-01-31T10:15+05:60"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("2024-01-31T10:15+05:00:00"), Err(ParseErrorKind::TooLong));
+    }
+
+    #[test]
+    fn test_parse_iso8601_error_position() {
+        let err = parse_iso8601::<NaiveDateTime>("2024-01-31T10:1x").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Invalid);
+        assert_eq!(err.position(), Some(15));
+        #[cfg(feature = "alloc")]
+        assert_eq!(err.found(), Some('x'));
+        let err = parse_iso8601::<NaiveDate>("2024-01-31!").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::TooLong);
+        assert_eq!(err.position(), Some(10));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_format_iso8601() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+
+        let dt = ymd(2024, 1, 31).and_hms_micro_opt(10, 15, 0, 1500).unwrap();
+        let extended = Iso8601Format::default();
+        let basic = extended.with_style(Iso8601Style::Basic);
+        assert_eq!(basic, Iso8601Format::BASIC);
+        assert_eq!(format_iso8601(dt, extended).to_string(), "2024-01-31T10:15:00.001500");
+        assert_eq!(format_iso8601(dt, basic).to_string(), "20240131T101500.001500");
+        assert_eq!(format_iso8601(dt.time(), basic).to_string(), "101500.001500");
+
+        let forms = [
+            (Iso8601DateForm::Calendar, "2024-01-31", "20240131"),
+            (Iso8601DateForm::Ordinal, "2024-031", "2024031"),
+            (Iso8601DateForm::Week, "2024-W05-3", "2024W053"),
+        ];
+        for (form, ext, bas) in forms {
+            assert_eq!(format_iso8601(dt.date(), extended.with_date_form(form)).to_string(), ext);
+            assert_eq!(format_iso8601(dt.date(), basic.with_date_form(form)).to_string(), bas);
+        }
+        // the ISO week-based year can differ from the calendar year
+        let week = extended.with_date_form(Iso8601DateForm::Week);
+        assert_eq!(format_iso8601(ymd(2021, 1, 3), week).to_string(), "2020-W53-7");
+        assert_eq!(format_iso8601(ymd(-1, 12, 31), extended).to_string(), "-0001-12-31");
+        assert_eq!(format_iso8601(ymd(-1, 12, 31), basic).to_string(), "-0000011231");
+        assert_eq!(format_iso8601(ymd(12024, 1, 31), extended).to_string(), "+12024-01-31");
+        assert_eq!(format_iso8601(ymd(12024, 1, 31), basic).to_string(), "+0120240131");
+
+        let seconds = [
+            (SecondsFormat::Secs, "10:15:00"),
+            (SecondsFormat::Millis, "10:15:00.001"),
+            (SecondsFormat::Micros, "10:15:00.001500"),
+            (SecondsFormat::Nanos, "10:15:00.001500000"),
+        ];
+        for (secform, expected) in seconds {
+            let format = extended.with_seconds_format(secform);
+            assert_eq!(format.seconds_format(), secform);
+            assert_eq!(format_iso8601(dt.time(), format).to_string(), expected);
+        }
+        let leap = hmsn(23, 59, 59, 1_250_000_000);
+        assert_eq!(format_iso8601(leap, extended).to_string(), "23:59:60.250");
+
+        let with_offset =
+            |secs| FixedOffset::east_opt(secs).unwrap().from_local_datetime(&dt).unwrap();
+        let secs = extended.with_seconds_format(SecondsFormat::Secs);
+        assert_eq!(format_iso8601(with_offset(0), secs).to_string(), "2024-01-31T10:15:00+00:00");
+        assert_eq!(
+            format_iso8601(with_offset(0), secs.with_z(true)).to_string(),
+            "2024-01-31T10:15:00Z"
+        );
+        assert_eq!(
+            format_iso8601(with_offset(-19800), secs.with_style(Iso8601Style::Basic)).to_string(),
+            "20240131T101500-0530"
+        );
+        assert_eq!(
+            format_iso8601(with_offset(3659), secs).to_string(),
+            "2024-01-31T10:15:00+01:00"
+        );
+        assert_eq!(
+            format_iso8601(with_offset(-86399), secs).to_string(),
+            "2024-01-31T10:15:00-23:59"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_iso8601_round_trip() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let dt = FixedOffset::east_opt(-9000)
+            .unwrap()
+            .from_local_datetime(
+                &ymd(2020, 12, 31).and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+            )
+            .unwrap();
+        for style in [Iso8601Style::Basic, Iso8601Style::Extended] {
+            for form in [Iso8601DateForm::Calendar, Iso8601DateForm::Ordinal, Iso8601DateForm::Week]
+            {
+                let format = Iso8601Format::EXTENDED.with_style(style).with_date_form(form);
+                let s = format_iso8601(dt, format).to_string();
+                assert_eq!(parse_iso8601::<DateTime<FixedOffset>>(&s), Ok(dt), "{}", s);
+                let s = format_iso8601(dt.date_naive(), format).to_string();
+                assert_eq!(parse_iso8601::<NaiveDate>(&s), Ok(dt.date_naive()), "{}", s);
+                for date in [NaiveDate::MIN, ymd(-1, 12, 31), ymd(10_000, 1, 1), NaiveDate::MAX] {
+                    let s = format_iso8601(date, format).to_string();
+                    assert_eq!(parse_iso8601::<NaiveDate>(&s), Ok(date), "{}", s);
+                }
+            }
+        }
+    }
+}
@@ -33,6 +33,7 @@
 #[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
 use alloc::boxed::Box;
 use core::fmt;
+use core::hash::Hash;
 use core::str::FromStr;
 #[cfg(feature = "std")]
 use std::error::Error;
@@ -43,9 +44,14 @@ mod formatting;
 mod parsed;
 
 // due to the size of parsing routines, they are in separate modules.
+mod duration;
 mod parse;
 pub(crate) mod scan;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub(crate) mod serde;
+mod tz_abbreviations;
 
+pub mod iso8601;
 pub mod strftime;
 
 #[allow(unused)]
@@ -53,6 +59,8 @@ pub mod strftime;
 // not require `alloc`.
 pub(crate) mod locales;
 
+#[doc(inline)]
+pub use crate::__strftime_items as strftime_items;
 pub(crate) use formatting::write_hundreds;
 #[cfg(feature = "alloc")]
 pub(crate) use formatting::write_rfc2822;
@@ -66,8 +74,13 @@ pub use formatting::{format, format_item, DelayedFormat};
 pub use locales::Locale;
 pub(crate) use parse::parse_rfc3339;
 pub use parse::{parse, parse_and_remainder};
-pub use parsed::Parsed;
+#[cfg(feature = "unstable-locales")]
+pub use parse::{parse_and_remainder_localized, parse_localized};
+#[cfg(feature = "alloc")]
+pub use parsed::ParsedDiagnostics;
+pub use parsed::{CenturyResolution, DateResolution, Parsed, ParsedField};
 pub use strftime::StrftimeItems;
+pub use tz_abbreviations::{AbbreviationAmbiguity, TzAbbreviations};
 
 /// An uninhabited type used for `InternalNumeric` and `InternalFixed` below.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -145,6 +158,9 @@ pub enum Numeric {
     /// The number of non-leap seconds since the midnight UTC on January 1, 1970 (FW=1, PW=∞).
     /// For formatting, it assumes UTC upon the absence of time zone offset.
     Timestamp,
+    /// The [Julian Day Number](crate::NaiveDate::from_julian_day) of the date (FW=1, PW=∞).
+    /// May be negative, given an initial sign.
+    JulianDay,
 
     /// Internal uses only.
     ///
@@ -208,8 +224,19 @@ pub enum Fixed {
     Nanosecond9,
     /// Timezone name.
     ///
-    /// It does not support parsing, its use in the parser is an immediate failure.
+    /// In the parser, the name is looked up in the [`TzAbbreviations`] table of [`Parsed`]
+    /// to set the offset. Names that are not in the table are skipped.
     TimezoneName,
+    /// Timezone identifier from the IANA time zone database, such as `Europe/Prague`.
+    ///
+    /// Formats the [`time_zone_id`](crate::Offset::time_zone_id) of the offset, such as the name
+    /// of a [`TzInfo`](crate::TzInfo) time zone, and the same as
+    /// [`TimezoneName`](#variant.TimezoneName) if there is none. In the parser, the time zone is
+    /// looked up with [`Parsed::set_time_zone_lookup`], by default in the system's zoneinfo
+    /// directories, and used to resolve the offset. This requires the `std` feature; without it,
+    /// or without a zoneinfo database and a custom lookup, parsing fails with
+    /// [`ParseErrorKind::BadFormat`].
+    TimezoneId,
     /// Offset from the local time to UTC (`+09:00` or `-04:00` or `+00:00`).
     ///
     /// In the parser, the colon can be omitted and/or surrounded with any amount of whitespace.
@@ -345,22 +372,27 @@ pub enum Item<'a> {
     Error,
 }
 
+#[cfg(test)]
 const fn num(numeric: Numeric) -> Item<'static> {
     Item::Numeric(numeric, Pad::None)
 }
 
+#[cfg(test)]
 const fn num0(numeric: Numeric) -> Item<'static> {
     Item::Numeric(numeric, Pad::Zero)
 }
 
+#[cfg(test)]
 const fn nums(numeric: Numeric) -> Item<'static> {
     Item::Numeric(numeric, Pad::Space)
 }
 
+#[cfg(test)]
 const fn fixed(fixed: Fixed) -> Item<'static> {
     Item::Fixed(fixed)
 }
 
+#[cfg(test)]
 const fn internal_fixed(val: InternalInternal) -> Item<'static> {
     Item::Fixed(Fixed::Internal(InternalFixed { val }))
 }
@@ -382,13 +414,302 @@ impl<'a> Item<'a> {
 }
 
 /// An error from the `parse` function.
-#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
-pub struct ParseError(ParseErrorKind);
+///
+/// Errors from parsing with formatting items can carry where in the input the error happened,
+/// and which item was being parsed. Both are included in the `Display` output, for example
+/// "input contains invalid characters: expected month at byte 5, found 'x'". Without the
+/// `alloc` feature only the position is kept.
+///
+/// Errors compare equal only if their position and item are equal as well, compare their
+/// [`kind`](ParseError::kind) to only check the category of the error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    #[cfg(feature = "alloc")]
+    context: Option<Box<ErrorContext>>,
+    #[cfg(not(feature = "alloc"))]
+    context: Option<ErrorContext>,
+}
 
 impl ParseError {
+    const fn new(kind: ParseErrorKind) -> ParseError {
+        ParseError { kind, context: None }
+    }
+
     /// The category of parse error
     pub const fn kind(&self) -> ParseErrorKind {
-        self.0
+        self.kind
+    }
+
+    /// The byte offset into the input where the error happened, if known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let err = NaiveDate::parse_from_str("2023-x1-05", "%Y-%m-%d").unwrap_err();
+    /// assert_eq!(err.position(), Some(5));
+    /// assert_eq!(err.to_string(), "input contains invalid characters: expected month at byte 5, found 'x'");
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        self.context.as_ref()?.position
+    }
+
+    /// The formatting item that was being parsed when the error happened, if known.
+    ///
+    /// This is `None` for an error while matching a literal, because a literal item borrows from
+    /// the format string. The `Display` output still includes the character that was expected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{Item, Numeric, Pad};
+    /// use chrono::NaiveDate;
+    ///
+    /// let err = NaiveDate::parse_from_str("2023-13-05", "%Y-%m-%d").unwrap_err();
+    /// assert_eq!(err.item(), Some(Item::Numeric(Numeric::Month, Pad::Zero)));
+    /// ```
+    pub fn item(&self) -> Option<Item<'static>> {
+        match self.context.as_ref()?.item()? {
+            // The padding is ignored while parsing, so we don't keep it around.
+            ErrorItem::Numeric(numeric) => Some(Item::Numeric(numeric.clone(), Pad::Zero)),
+            ErrorItem::Fixed(fixed) => Some(Item::Fixed(fixed.clone())),
+            ErrorItem::Literal(_) => None,
+        }
+    }
+
+    /// The character in the input at the error, if it was an unexpected character.
+    pub fn found(&self) -> Option<char> {
+        self.context.as_ref()?.found()
+    }
+
+    /// Add the location `s` of the error, and the item that was being parsed.
+    ///
+    /// `s` is the remainder of the input at the error. Does nothing if the error already has a
+    /// location, so the innermost and most precise one is kept.
+    pub(crate) fn with_context(self, s: &str, item: Option<ErrorItem>) -> ParseError {
+        if self.context.is_some() {
+            return self;
+        }
+        let found = match self.kind {
+            ParseErrorKind::Invalid | ParseErrorKind::TooLong => s.chars().next(),
+            _ => None,
+        };
+        #[allow(clippy::useless_conversion)] // only boxed with the `alloc` feature
+        let context = Some(ErrorContext::new(s.len(), item, found).into());
+        ParseError { kind: self.kind, context }
+    }
+
+    /// Set the byte offset of the error from the full `input`.
+    ///
+    /// The location of an error is tracked as the length of the remaining input, which works
+    /// for every suffix of the input. Calling this again with a longer input gives the position
+    /// relative to that input.
+    pub(crate) fn locate(mut self, input: &str) -> ParseError {
+        if let Some(ref mut context) = self.context {
+            context.position = input.len().checked_sub(context.remaining);
+        }
+        self
+    }
+
+    /// Move the position of the error by `offset` bytes.
+    ///
+    /// For an error located in a part of the input that starts at byte `offset`.
+    pub(crate) fn shift(mut self, offset: usize) -> ParseError {
+        if let Some(ref mut context) = self.context {
+            context.position = context.position.map(|position| position + offset);
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ErrorContext {
+    /// The length of the input after the error.
+    remaining: usize,
+    /// The byte offset of the error in the input, once known.
+    position: Option<usize>,
+    #[cfg(feature = "alloc")]
+    item: Option<ErrorItem>,
+    #[cfg(feature = "alloc")]
+    found: Option<char>,
+}
+
+impl ErrorContext {
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    fn new(remaining: usize, item: Option<ErrorItem>, found: Option<char>) -> ErrorContext {
+        ErrorContext {
+            remaining,
+            position: None,
+            #[cfg(feature = "alloc")]
+            item,
+            #[cfg(feature = "alloc")]
+            found,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn item(&self) -> Option<ErrorItem> {
+        self.item
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn item(&self) -> Option<ErrorItem> {
+        None
+    }
+
+    #[cfg(feature = "alloc")]
+    fn found(&self) -> Option<char> {
+        self.found
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn found(&self) -> Option<char> {
+        None
+    }
+}
+
+/// The formatting item that was being parsed at an error.
+///
+/// The items are kept as `'static` references, so no formatting item is copied or allocated for
+/// an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ErrorItem {
+    Numeric(&'static Numeric),
+    Fixed(&'static Fixed),
+    /// A literal, with the character that was expected.
+    Literal(char),
+}
+
+impl ErrorItem {
+    pub(crate) const fn numeric(numeric: &Numeric) -> ErrorItem {
+        use Numeric::*;
+        ErrorItem::Numeric(match *numeric {
+            Year => &Year,
+            YearDiv100 => &YearDiv100,
+            YearMod100 => &YearMod100,
+            IsoYear => &IsoYear,
+            IsoYearDiv100 => &IsoYearDiv100,
+            IsoYearMod100 => &IsoYearMod100,
+            Month => &Month,
+            Day => &Day,
+            WeekFromSun => &WeekFromSun,
+            WeekFromMon => &WeekFromMon,
+            IsoWeek => &IsoWeek,
+            NumDaysFromSun => &NumDaysFromSun,
+            WeekdayFromMon => &WeekdayFromMon,
+            Ordinal => &Ordinal,
+            Hour => &Hour,
+            Hour12 => &Hour12,
+            Minute => &Minute,
+            Second => &Second,
+            Nanosecond => &Nanosecond,
+            Timestamp => &Timestamp,
+            JulianDay => &JulianDay,
+            Internal(ref int) => match int._dummy {},
+        })
+    }
+
+    pub(crate) const fn fixed(fixed: &Fixed) -> ErrorItem {
+        use Fixed::*;
+        use InternalInternal::*;
+        ErrorItem::Fixed(match *fixed {
+            ShortMonthName => &ShortMonthName,
+            LongMonthName => &LongMonthName,
+            ShortWeekdayName => &ShortWeekdayName,
+            LongWeekdayName => &LongWeekdayName,
+            LowerAmPm => &LowerAmPm,
+            UpperAmPm => &UpperAmPm,
+            Nanosecond => &Nanosecond,
+            Nanosecond3 => &Nanosecond3,
+            Nanosecond6 => &Nanosecond6,
+            Nanosecond9 => &Nanosecond9,
+            TimezoneName => &TimezoneName,
+            TimezoneId => &TimezoneId,
+            TimezoneOffsetColon => &TimezoneOffsetColon,
+            TimezoneOffsetDoubleColon => &TimezoneOffsetDoubleColon,
+            TimezoneOffsetTripleColon => &TimezoneOffsetTripleColon,
+            TimezoneOffsetColonZ => &TimezoneOffsetColonZ,
+            TimezoneOffset => &TimezoneOffset,
+            TimezoneOffsetZ => &TimezoneOffsetZ,
+            RFC2822 => &RFC2822,
+            RFC3339 => &RFC3339,
+            Internal(InternalFixed { val: TimezoneOffsetPermissive }) => {
+                &Internal(InternalFixed { val: TimezoneOffsetPermissive })
+            }
+            Internal(InternalFixed { val: Nanosecond3NoDot }) => {
+                &Internal(InternalFixed { val: Nanosecond3NoDot })
+            }
+            Internal(InternalFixed { val: Nanosecond6NoDot }) => {
+                &Internal(InternalFixed { val: Nanosecond6NoDot })
+            }
+            Internal(InternalFixed { val: Nanosecond9NoDot }) => {
+                &Internal(InternalFixed { val: Nanosecond9NoDot })
+            }
+        })
+    }
+}
+
+impl fmt::Display for ErrorItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use InternalInternal::*;
+        let description = match *self {
+            ErrorItem::Literal(c) => return write!(f, "{:?}", c),
+            ErrorItem::Numeric(numeric) => match *numeric {
+                Numeric::Year => "year",
+                Numeric::YearDiv100 => "century",
+                Numeric::YearMod100 => "2-digit year",
+                Numeric::IsoYear => "ISO week-based year",
+                Numeric::IsoYearDiv100 => "ISO week-based century",
+                Numeric::IsoYearMod100 => "2-digit ISO week-based year",
+                Numeric::Month => "month",
+                Numeric::Day => "day",
+                Numeric::WeekFromSun | Numeric::WeekFromMon => "week number",
+                Numeric::IsoWeek => "ISO week number",
+                Numeric::NumDaysFromSun | Numeric::WeekdayFromMon => "weekday number",
+                Numeric::Ordinal => "day of the year",
+                Numeric::Hour | Numeric::Hour12 => "hour",
+                Numeric::Minute => "minute",
+                Numeric::Second => "second",
+                Numeric::Nanosecond => "nanoseconds",
+                Numeric::Timestamp => "timestamp",
+                Numeric::JulianDay => "Julian day number",
+                Numeric::Internal(ref int) => match int._dummy {},
+            },
+            ErrorItem::Fixed(fixed) => match *fixed {
+                Fixed::ShortMonthName => "abbreviated month name",
+                Fixed::LongMonthName => "month name",
+                Fixed::ShortWeekdayName => "abbreviated weekday name",
+                Fixed::LongWeekdayName => "weekday name",
+                Fixed::LowerAmPm | Fixed::UpperAmPm => "AM or PM",
+                Fixed::Nanosecond
+                | Fixed::Nanosecond3
+                | Fixed::Nanosecond6
+                | Fixed::Nanosecond9 => "fractional seconds",
+                Fixed::Internal(InternalFixed { val: Nanosecond3NoDot }) => {
+                    "3 digits of fractional seconds"
+                }
+                Fixed::Internal(InternalFixed { val: Nanosecond6NoDot }) => {
+                    "6 digits of fractional seconds"
+                }
+                Fixed::Internal(InternalFixed { val: Nanosecond9NoDot }) => {
+                    "9 digits of fractional seconds"
+                }
+                Fixed::TimezoneName => "time zone name",
+                Fixed::TimezoneId => "time zone identifier",
+                Fixed::TimezoneOffsetColon
+                | Fixed::TimezoneOffsetDoubleColon
+                | Fixed::TimezoneOffsetTripleColon
+                | Fixed::TimezoneOffsetColonZ
+                | Fixed::TimezoneOffset
+                | Fixed::TimezoneOffsetZ
+                | Fixed::Internal(InternalFixed { val: TimezoneOffsetPermissive }) => "UTC offset",
+                Fixed::RFC2822 => "RFC 2822 date and time",
+                Fixed::RFC3339 => "RFC 3339 date and time",
+            },
+        };
+        f.write_str(description)
     }
 }
 
@@ -434,7 +755,7 @@ pub type ParseResult<T> = Result<T, ParseError>;
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.0 {
+        match self.kind {
             ParseErrorKind::OutOfRange => write!(f, "input is out of range"),
             ParseErrorKind::Impossible => write!(f, "no possible date and time matching input"),
             ParseErrorKind::NotEnough => write!(f, "input is not enough for unique date and time"),
@@ -443,7 +764,25 @@ impl fmt::Display for ParseError {
             ParseErrorKind::TooLong => write!(f, "trailing input"),
             ParseErrorKind::BadFormat => write!(f, "bad or unsupported format string"),
             _ => unreachable!(),
+        }?;
+        let context = match &self.context {
+            Some(context) => context,
+            None => return Ok(()),
+        };
+        match (self.kind, context.item()) {
+            (ParseErrorKind::Invalid | ParseErrorKind::TooShort, Some(item)) => {
+                write!(f, ": expected {}", item)?
+            }
+            (_, Some(item)) => write!(f, " for {}", item)?,
+            (_, None) => {}
+        }
+        if let Some(position) = context.position {
+            write!(f, " at byte {}", position)?;
+        }
+        if let Some(found) = context.found() {
+            write!(f, ", found {:?}", found)?;
         }
+        Ok(())
     }
 }
 
@@ -456,13 +795,13 @@ impl Error for ParseError {
 }
 
 // to be used in this module and submodules
-pub(crate) const OUT_OF_RANGE: ParseError = ParseError(ParseErrorKind::OutOfRange);
-const IMPOSSIBLE: ParseError = ParseError(ParseErrorKind::Impossible);
-const NOT_ENOUGH: ParseError = ParseError(ParseErrorKind::NotEnough);
-const INVALID: ParseError = ParseError(ParseErrorKind::Invalid);
-const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
-pub(crate) const TOO_LONG: ParseError = ParseError(ParseErrorKind::TooLong);
-const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
+pub(crate) const OUT_OF_RANGE: ParseError = ParseError::new(ParseErrorKind::OutOfRange);
+pub(crate) const IMPOSSIBLE: ParseError = ParseError::new(ParseErrorKind::Impossible);
+pub(crate) const NOT_ENOUGH: ParseError = ParseError::new(ParseErrorKind::NotEnough);
+pub(crate) const INVALID: ParseError = ParseError::new(ParseErrorKind::Invalid);
+pub(crate) const TOO_SHORT: ParseError = ParseError::new(ParseErrorKind::TooShort);
+pub(crate) const TOO_LONG: ParseError = ParseError::new(ParseErrorKind::TooLong);
+const BAD_FORMAT: ParseError = ParseError::new(ParseErrorKind::BadFormat);
 
 // this implementation is here only because we need some private code from `scan`
 
@@ -4,10 +4,16 @@
 //! A collection of parsed date and time items.
 //! They can be constructed incrementally while being checked for consistency.
 
-use super::{ParseResult, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
+use super::{ParseResult, TzAbbreviations, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
+#[cfg(feature = "std")]
+use super::{BAD_FORMAT, INVALID};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+#[cfg(feature = "std")]
+use crate::offset::TzInfo;
 use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone};
 use crate::{DateTime, Datelike, TimeDelta, Timelike, Weekday};
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
 
 /// A type to hold parsed fields of date and time that can check all fields are consistent.
 ///
@@ -165,10 +171,46 @@ pub struct Parsed {
     pub timestamp: Option<i64>,
     #[doc(hidden)]
     pub offset: Option<i32>,
+    julian_day: Option<i32>,
+    century: CenturyResolution,
+    tz_abbreviations: TzAbbreviations,
+    #[cfg(feature = "std")]
+    time_zone: Option<TzInfo>,
+    #[cfg(feature = "std")]
+    time_zone_lookup: Option<TimeZoneLookup>,
     #[doc(hidden)]
     _dummy: (),
 }
 
+/// A caller-supplied function to look up time zone identifiers, compared and hashed by address.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+struct TimeZoneLookup(fn(&str) -> Option<TzInfo>);
+
+#[cfg(feature = "std")]
+impl PartialEq for TimeZoneLookup {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for TimeZoneLookup {}
+
+#[cfg(feature = "std")]
+impl core::hash::Hash for TimeZoneLookup {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (self.0 as usize).hash(state)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for TimeZoneLookup {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "TimeZoneLookup({:#x})", self.0 as usize)
+    }
+}
+
 /// Checks if `old` is either empty or has the same value as `new` (i.e. "consistent"),
 /// and if it is empty, set `old` to `new` as well.
 #[inline]
@@ -528,6 +570,20 @@ impl Parsed {
         set_if_consistent(&mut self.timestamp, value)
     }
 
+    /// Set the Julian Day Number field to the given value.
+    ///
+    /// See [`NaiveDate::from_julian_day`] for the definition of the Julian Day Number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OUT_OF_RANGE` if `value` is outside the range of an `i32`.
+    ///
+    /// Returns `IMPOSSIBLE` if this field was already set to a different value.
+    #[inline]
+    pub fn set_julian_day(&mut self, value: i64) -> ParseResult<()> {
+        set_if_consistent(&mut self.julian_day, i32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
+    }
+
     /// Set the [`offset`](Parsed::offset) field to the given value.
     ///
     /// The offset is in seconds from local time to UTC.
@@ -542,6 +598,101 @@ impl Parsed {
         set_if_consistent(&mut self.offset, i32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Set how a two-digit year without a century number is mapped to a full year.
+    ///
+    /// This applies to the [`year_mod_100`](Parsed::year_mod_100) and
+    /// [`isoyear_mod_100`](Parsed::isoyear_mod_100) fields when the full year and the century
+    /// number are not set, as with the `%y` and `%g` specifiers. The default is
+    /// `CenturyResolution::Pivot(1970)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, CenturyResolution, Parsed, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "491231", StrftimeItems::new("%y%m%d"))?;
+    /// assert_eq!(parsed.to_naive_date()?, NaiveDate::from_ymd_opt(2049, 12, 31).unwrap());
+    ///
+    /// // RFC 5280 `UTCTime` maps two-digit years to 1950 through 2049
+    /// parsed.set_century_resolution(CenturyResolution::Pivot(1950));
+    /// assert_eq!(parsed.to_naive_date()?, NaiveDate::from_ymd_opt(2049, 12, 31).unwrap());
+    /// parsed.year_mod_100 = Some(50);
+    /// assert_eq!(parsed.to_naive_date()?, NaiveDate::from_ymd_opt(1950, 12, 31).unwrap());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[inline]
+    pub fn set_century_resolution(&mut self, century: CenturyResolution) {
+        self.century = century;
+    }
+
+    /// Set the table of time zone abbreviations used to parse the `%Z` specifier.
+    ///
+    /// The default is [`TzAbbreviations::COMMON`]. Use [`TzAbbreviations::NONE`] to skip all
+    /// abbreviations without setting an offset.
+    #[inline]
+    pub fn set_tz_abbreviations(&mut self, abbreviations: TzAbbreviations) {
+        self.tz_abbreviations = abbreviations;
+    }
+
+    /// Set the function used to look up the time zone identifiers parsed with the `%:Z`
+    /// specifier.
+    ///
+    /// By default identifiers are only looked up in the system time zone directories, which
+    /// exist on Unix-like platforms; elsewhere parsing `%:Z` fails with `BAD_FORMAT` unless a
+    /// lookup is set. The zones found there are cached per thread. An identifier for which
+    /// `lookup` returns `None` fails to parse with `INVALID`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::TzInfo;
+    ///
+    /// fn lookup(name: &str) -> Option<TzInfo> {
+    ///     match name {
+    ///         "Europe/Prague" => TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").ok(),
+    ///         _ => None,
+    ///     }
+    /// }
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_time_zone_lookup(lookup);
+    /// parse(&mut parsed, "2024-07-01 12:00 Europe/Prague", StrftimeItems::new("%F %R %:Z"))?;
+    /// assert_eq!(parsed.to_datetime()?.to_rfc3339(), "2024-07-01T12:00:00+02:00");
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_time_zone_lookup(lookup);
+    /// assert!(parse(&mut parsed, "Asia/Tokyo", StrftimeItems::new("%:Z")).is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn set_time_zone_lookup(&mut self, lookup: fn(&str) -> Option<TzInfo>) {
+        self.time_zone_lookup = Some(TimeZoneLookup(lookup));
+    }
+
+    /// Set the time zone, as parsed from a time zone identifier with the `%:Z` specifier.
+    ///
+    /// [`to_datetime()`](Parsed::to_datetime) uses the time zone to determine the offset from
+    /// the local date and time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IMPOSSIBLE` if this field was already set to a different time zone.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn set_time_zone(&mut self, tz: TzInfo) -> ParseResult<()> {
+        match &self.time_zone {
+            Some(old) if *old != tz => Err(IMPOSSIBLE),
+            _ => {
+                self.time_zone = Some(tz);
+                Ok(())
+            }
+        }
+    }
+
     /// Returns a parsed naive date out of given fields.
     ///
     /// This method is able to determine the date from given subset of fields:
@@ -550,11 +701,15 @@ impl Parsed {
     /// - Year, day of the year (ordinal).
     /// - Year, week number counted from Sunday or Monday, day of the week.
     /// - ISO week date.
+    /// - Julian Day Number.
     ///
     /// Gregorian year and ISO week date year can have their century number (`*_div_100`) omitted,
-    /// the two-digit year is used to guess the century number then.
+    /// the two-digit year is used to guess the century number then. By default a two-digit year is
+    /// mapped to the years 1970 through 2069, see
+    /// [`set_century_resolution()`](Parsed::set_century_resolution) to change this.
     ///
-    /// It checks all given date fields are consistent with each other.
+    /// It checks all given date fields are consistent with each other. Use
+    /// [`diagnose()`](Parsed::diagnose) to find out which fields conflict.
     ///
     /// # Errors
     ///
@@ -570,6 +725,7 @@ impl Parsed {
             y: Option<i32>,
             q: Option<i32>,
             r: Option<i32>,
+            century: CenturyResolution,
         ) -> ParseResult<Option<i32>> {
             match (y, q, r) {
                 // if there is no further information, simply return the given full year.
@@ -603,9 +759,9 @@ impl Parsed {
                     Ok(Some(y.ok_or(OUT_OF_RANGE)?))
                 }
 
-                // we only have modulo. try to interpret a modulo as a conventional two-digit year.
+                // we only have modulo. try to interpret a modulo as a two-digit year.
                 // note: we are affected by Rust issue #18060. avoid multiple range patterns.
-                (None, None, Some(r @ 0..=99)) => Ok(Some(r + if r < 70 { 2000 } else { 1900 })),
+                (None, None, Some(r @ 0..=99)) => Ok(Some(century.resolve(r).ok_or(OUT_OF_RANGE)?)),
 
                 // otherwise it is an out-of-bound or insufficient condition.
                 (None, Some(_), None) => Err(NOT_ENOUGH),
@@ -613,8 +769,10 @@ impl Parsed {
             }
         }
 
-        let given_year = resolve_year(self.year, self.year_div_100, self.year_mod_100)?;
-        let given_isoyear = resolve_year(self.isoyear, self.isoyear_div_100, self.isoyear_mod_100)?;
+        let given_year =
+            resolve_year(self.year, self.year_div_100, self.year_mod_100, self.century)?;
+        let given_isoyear =
+            resolve_year(self.isoyear, self.isoyear_div_100, self.isoyear_mod_100, self.century)?;
 
         // verify the normal year-month-day date.
         let verify_ymd = |date: NaiveDate| {
@@ -696,9 +854,19 @@ impl Parsed {
                 (verify_ymd(date) && verify_ordinal(date), date)
             }
 
+            (_, _, &Parsed { julian_day: Some(julian_day), .. }) => {
+                // Julian Day Number
+                let date = NaiveDate::from_julian_day(julian_day).ok_or(OUT_OF_RANGE)?;
+                (verify_ymd(date) && verify_isoweekdate(date) && verify_ordinal(date), date)
+            }
+
             (_, _, _) => return Err(NOT_ENOUGH),
         };
 
+        // verify the Julian Day Number, which is not part of the other combinations.
+        let verified =
+            verified && self.julian_day.map_or(true, |day| day == parsed_date.to_julian_day());
+
         if verified {
             Ok(parsed_date)
         } else {
@@ -780,7 +948,7 @@ impl Parsed {
     pub fn to_naive_datetime_with_offset(&self, offset: i32) -> ParseResult<NaiveDateTime> {
         let date = self.to_naive_date();
         let time = self.to_naive_time();
-        if let (Ok(date), Ok(time)) = (date, time) {
+        if let (&Ok(date), &Ok(time)) = (&date, &time) {
             let datetime = date.and_time(time);
 
             // verify the timestamp field if any
@@ -797,14 +965,14 @@ impl Parsed {
 
             Ok(datetime)
         } else if let Some(timestamp) = self.timestamp {
-            use super::ParseError as PE;
             use super::ParseErrorKind::{Impossible, OutOfRange};
 
             // if date and time is problematic already, there is no point proceeding.
             // we at least try to give a correct error though.
-            match (date, time) {
-                (Err(PE(OutOfRange)), _) | (_, Err(PE(OutOfRange))) => return Err(OUT_OF_RANGE),
-                (Err(PE(Impossible)), _) | (_, Err(PE(Impossible))) => return Err(IMPOSSIBLE),
+            let kinds = (date.as_ref().map_err(|e| e.kind()), time.as_ref().map_err(|e| e.kind()));
+            match kinds {
+                (Err(OutOfRange), _) | (_, Err(OutOfRange)) => return Err(OUT_OF_RANGE),
+                (Err(Impossible), _) | (_, Err(Impossible)) => return Err(IMPOSSIBLE),
                 (_, _) => {} // one of them is insufficient
             }
 
@@ -865,6 +1033,9 @@ impl Parsed {
     /// fields, and/or from a single timestamp field. It checks all fields are consistent with each
     /// other.
     ///
+    /// If a [time zone](Parsed::set_time_zone) is set, the offset is determined from it as with
+    /// [`to_datetime_with_timezone()`](Parsed::to_datetime_with_timezone).
+    ///
     /// # Errors
     ///
     /// This method returns:
@@ -874,10 +1045,15 @@ impl Parsed {
     ///   including offset from UTC.
     /// - `OUT_OF_RANGE`
     ///   - if any of the fields of `Parsed` are set to a value beyond their acceptable
-    ///   range.
+    ///     range.
     ///   - if the value would be outside the range of a [`NaiveDateTime`] or [`FixedOffset`].
     ///   - if the date does not exist.
     pub fn to_datetime(&self) -> ParseResult<DateTime<FixedOffset>> {
+        #[cfg(feature = "std")]
+        if let Some(tz) = &self.time_zone {
+            return self.to_datetime_with_timezone(tz).map(|dt| dt.fixed_offset());
+        }
+
         // If there is no explicit offset, consider a timestamp value as indication of a UTC value.
         let offset = match (self.offset, self.timestamp) {
             (Some(off), _) => off,
@@ -964,6 +1140,347 @@ impl Parsed {
         }
     }
 
+    /// Fills the date and time fields that are missing with values from `reference`.
+    ///
+    /// This makes it possible to resolve partial input, such as a time without a date, or a day
+    /// and month without a year. Fields that are already set are never changed, so parsed values
+    /// always take precedence over the reference. The missing fields are filled as follows:
+    ///
+    /// - If a timestamp is set, nothing is filled; the timestamp determines the date and time.
+    /// - Fields more significant than the most significant field that is set are taken from
+    ///   `reference`. For example the date of "14:30", or the year of "March 5".
+    /// - Fields less significant than that are set to their minimum value. For example "March
+    ///   2024" is resolved to the first of March, and a date without a time to midnight.
+    /// - A century without the year in it is resolved to the first year of the century, and an
+    ///   ISO week-based year without a week number to its first week.
+    /// - A week number without a day of the week is resolved to the first day of that week:
+    ///   Sunday for [`WeekFromSun`](super::Numeric::WeekFromSun), and Monday for
+    ///   [`WeekFromMon`](super::Numeric::WeekFromMon) and ISO weeks.
+    /// - An hour on a 12-hour clock without AM/PM is taken as AM if any date field is set.
+    ///   Otherwise it is in the same half of the day as `reference`.
+    ///
+    /// The year, month and day are only filled if the date can not be resolved from another
+    /// combination of fields, such as the day of the year or an ISO week date. A lone day of the
+    /// week is not used to determine the date, it is only checked against it.
+    ///
+    /// The offset is not filled, see [`fill_missing_from_datetime`] for that.
+    ///
+    /// [`fill_missing_from_datetime`]: Parsed::fill_missing_from_datetime
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    /// use chrono::NaiveDate;
+    ///
+    /// let reference = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap().and_hms_opt(9, 10, 11).unwrap();
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "14:30", StrftimeItems::new("%H:%M"))?;
+    /// parsed.fill_missing_from(reference);
+    /// assert_eq!(parsed.to_naive_datetime_with_offset(0)?.to_string(), "2024-06-15 14:30:00");
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parse(&mut parsed, "March 5", StrftimeItems::new("%B %d"))?;
+    /// parsed.fill_missing_from(reference);
+    /// assert_eq!(parsed.to_naive_datetime_with_offset(0)?.to_string(), "2024-03-05 00:00:00");
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn fill_missing_from(&mut self, reference: NaiveDateTime) {
+        if self.timestamp.is_some() {
+            return;
+        }
+
+        let has_year =
+            self.year.is_some() || self.year_div_100.is_some() || self.year_mod_100.is_some();
+        let has_isoyear = self.isoyear.is_some()
+            || self.isoyear_div_100.is_some()
+            || self.isoyear_mod_100.is_some();
+        let has_month = self.month.is_some();
+        let has_date = has_year
+            || has_isoyear
+            || has_month
+            || self.day.is_some()
+            || self.ordinal.is_some()
+            || self.week_from_sun.is_some()
+            || self.week_from_mon.is_some()
+            || self.isoweek.is_some()
+            || self.julian_day.is_some();
+
+        if !has_year && !has_isoyear && self.julian_day.is_none() {
+            if self.isoweek.is_some() {
+                self.isoyear = Some(reference.iso_week().year());
+            } else {
+                self.year = Some(reference.year());
+            }
+        }
+        if self.year.is_none() && self.year_div_100.is_some() && self.year_mod_100.is_none() {
+            self.year_mod_100 = Some(0);
+        }
+        if self.isoyear.is_none()
+            && self.isoyear_div_100.is_some()
+            && self.isoyear_mod_100.is_none()
+        {
+            self.isoyear_mod_100 = Some(0);
+        }
+        if has_isoyear && !has_year && self.isoweek.is_none() {
+            self.isoweek = Some(1);
+        }
+        if self.weekday.is_none()
+            && self.day.is_none()
+            && self.ordinal.is_none()
+            && self.julian_day.is_none()
+        {
+            if self.week_from_sun.is_some() {
+                self.weekday = Some(Weekday::Sun);
+            } else if self.week_from_mon.is_some() || self.isoweek.is_some() {
+                self.weekday = Some(Weekday::Mon);
+            }
+        }
+        let other_combination = self.ordinal.is_some()
+            || self.julian_day.is_some()
+            || (self.weekday.is_some()
+                && (self.week_from_sun.is_some()
+                    || self.week_from_mon.is_some()
+                    || self.isoweek.is_some()));
+        if !other_combination {
+            if self.month.is_none() {
+                self.month = Some(if has_year { 1 } else { reference.month() });
+            }
+            if self.day.is_none() {
+                self.day = Some(if has_year || has_month { 1 } else { reference.day() });
+            }
+        }
+
+        let has_hour = self.hour_div_12.is_some() || self.hour_mod_12.is_some();
+        let has_minute = self.minute.is_some();
+        let has_second = self.second.is_some();
+
+        let mut coarser = has_date;
+        if self.hour_div_12.is_none() {
+            // AM/PM is more significant than the hour on a 12-hour clock.
+            let hour_div_12 = if coarser { 0 } else { reference.hour() / 12 };
+            self.hour_div_12 = Some(hour_div_12);
+        }
+        if self.hour_mod_12.is_none() {
+            self.hour_mod_12 = Some(if coarser || has_hour { 0 } else { reference.hour() % 12 });
+        }
+        coarser |= has_hour;
+        if self.minute.is_none() {
+            self.minute = Some(if coarser { 0 } else { reference.minute() });
+        }
+        coarser |= has_minute;
+        // a leap second is represented by a second of 60 in `Parsed`.
+        let (second, nanosecond) = match reference.nanosecond() {
+            nano @ 1_000_000_000.. => (60, nano - 1_000_000_000),
+            nano => (reference.second(), nano),
+        };
+        if self.second.is_none() {
+            self.second = Some(if coarser { 0 } else { second });
+        }
+        coarser |= has_second;
+        if self.nanosecond.is_none() {
+            self.nanosecond = Some(if coarser { 0 } else { nanosecond });
+        }
+    }
+
+    /// Fills the fields that are missing with the local date and time and the offset of
+    /// `reference`.
+    ///
+    /// The date and time fields are filled as with [`fill_missing_from`], using the local date
+    /// and time of `reference`. The offset is filled with the offset of `reference` if it is not
+    /// set. Nothing is filled if a timestamp is set.
+    ///
+    /// [`fill_missing_from`]: Parsed::fill_missing_from
+    pub fn fill_missing_from_datetime<Tz: TimeZone>(&mut self, reference: &DateTime<Tz>) {
+        if self.timestamp.is_some() {
+            return;
+        }
+        #[cfg(feature = "std")]
+        let has_time_zone = self.time_zone.is_some();
+        #[cfg(not(feature = "std"))]
+        let has_time_zone = false;
+        if self.offset.is_none() && !has_time_zone {
+            self.offset = Some(reference.offset().fix().local_minus_utc());
+        }
+        self.fill_missing_from(reference.naive_local());
+    }
+
+    /// Returns `self` with the missing date and time fields filled from `reference`.
+    ///
+    /// See [`fill_missing_from`](Parsed::fill_missing_from) for the rules used to fill the
+    /// fields.
+    #[must_use]
+    pub fn with_defaults(mut self, reference: NaiveDateTime) -> Parsed {
+        self.fill_missing_from(reference);
+        self
+    }
+
+    /// Explains how the date fields would be resolved, and which fields conflict.
+    ///
+    /// [`to_naive_date`](Parsed::to_naive_date) and the other `to_*` methods only return an
+    /// `IMPOSSIBLE` error when fields disagree. This method reports the combination of fields that
+    /// was used to determine the date, and every pair of fields that was found to be inconsistent.
+    ///
+    /// The timestamp field is interpreted with the parsed offset, or with an offset of zero if no
+    /// offset is set, like [`to_datetime`](Parsed::to_datetime) does.
+    ///
+    /// Values that are out of range and missing fields are not reported as conflicts; they are
+    /// still returned as errors by the `to_*` methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{DateResolution, ParsedField, Parsed};
+    /// use chrono::Weekday;
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_year(2014)?;
+    /// parsed.set_month(12)?;
+    /// parsed.set_day(31)?;
+    /// parsed.set_weekday(Weekday::Thu)?; // 2014-12-31 was a Wednesday
+    /// parsed.set_ordinal(365)?;
+    ///
+    /// let diagnostics = parsed.diagnose();
+    /// assert_eq!(diagnostics.resolution(), Some(DateResolution::YearMonthDay));
+    /// assert_eq!(diagnostics.conflicts(), [(ParsedField::Weekday, ParsedField::Day)]);
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn diagnose(&self) -> ParsedDiagnostics {
+        use ParsedField as F;
+
+        let mut conflicts = Vec::new();
+
+        let year = diagnose_year(
+            (self.year, self.year_div_100, self.year_mod_100),
+            (F::Year, F::YearDiv100, F::YearMod100),
+            self.century,
+            &mut conflicts,
+        );
+        let isoyear = diagnose_year(
+            (self.isoyear, self.isoyear_div_100, self.isoyear_mod_100),
+            (F::IsoYear, F::IsoYearDiv100, F::IsoYearMod100),
+            self.century,
+            &mut conflicts,
+        );
+
+        // pick the same combination of fields as `to_naive_date`.
+        let (resolution, date) = match (year, isoyear, self) {
+            (Some(year), _, &Parsed { month: Some(month), day: Some(day), .. }) => {
+                (Some(DateResolution::YearMonthDay), NaiveDate::from_ymd_opt(year, month, day))
+            }
+            (Some(year), _, &Parsed { ordinal: Some(ordinal), .. }) => {
+                (Some(DateResolution::YearOrdinal), NaiveDate::from_yo_opt(year, ordinal))
+            }
+            (Some(year), _, &Parsed { week_from_sun: Some(week), weekday: Some(weekday), .. }) => {
+                let date = resolve_week_date(year, week, weekday, Weekday::Sun);
+                if date == Err(IMPOSSIBLE) {
+                    conflicts.push((F::Weekday, F::WeekFromSun));
+                }
+                (Some(DateResolution::YearWeekFromSun), date.ok())
+            }
+            (Some(year), _, &Parsed { week_from_mon: Some(week), weekday: Some(weekday), .. }) => {
+                let date = resolve_week_date(year, week, weekday, Weekday::Mon);
+                if date == Err(IMPOSSIBLE) {
+                    conflicts.push((F::Weekday, F::WeekFromMon));
+                }
+                (Some(DateResolution::YearWeekFromMon), date.ok())
+            }
+            (_, Some(isoyear), &Parsed { isoweek: Some(isoweek), weekday: Some(weekday), .. }) => {
+                let date = NaiveDate::from_isoywd_opt(isoyear, isoweek, weekday);
+                (Some(DateResolution::IsoYearWeekDay), date)
+            }
+            (_, _, &Parsed { julian_day: Some(julian_day), .. }) => {
+                (Some(DateResolution::JulianDay), NaiveDate::from_julian_day(julian_day))
+            }
+            (_, _, _) => (None, None),
+        };
+
+        // reconstruct the date and time from the timestamp, if any.
+        let offset = i64::from(self.offset.unwrap_or(0));
+        let mut timestamp = self
+            .timestamp
+            .and_then(|t| t.checked_add(offset))
+            .and_then(|t| DateTime::from_timestamp(t, 0))
+            .map(|dt| dt.naive_utc());
+        if let (Some(60), Some(dt)) = (self.second, timestamp.as_mut()) {
+            // a leap second may be represented by the timestamp of the next second.
+            match dt.second() {
+                59 => {}
+                0 => *dt -= TimeDelta::try_seconds(1).unwrap(),
+                _ => conflicts.push((F::Second, F::Timestamp)),
+            }
+        }
+
+        let (resolution, date) = match (resolution, date, timestamp) {
+            (None, _, Some(timestamp)) => (Some(DateResolution::Timestamp), Some(timestamp.date())),
+            (Some(resolution), Some(date), Some(timestamp)) => {
+                if date != timestamp.date() {
+                    conflicts.push((F::Timestamp, resolution.key_field()));
+                }
+                (Some(resolution), Some(date))
+            }
+            (resolution, date, _) => (resolution, date),
+        };
+
+        if let (Some(resolution), Some(date)) = (resolution, date) {
+            let mut check = |field: ParsedField, consistent: bool| {
+                if !resolution.fields().contains(&field) && !consistent {
+                    conflicts.push((field, resolution.key_field()));
+                }
+            };
+            let (year_div_100, year_mod_100) = split_year(date.year());
+            let week = date.iso_week();
+            let (isoyear_div_100, isoyear_mod_100) = split_year(week.year());
+            // the century number and two-digit year were already compared to the full year.
+            check(F::Year, self.year.map_or(true, |v| v == date.year()));
+            if self.year.is_none() {
+                check(F::YearDiv100, self.year_div_100.map_or(true, |v| Some(v) == year_div_100));
+                check(F::YearMod100, self.year_mod_100.map_or(true, |v| Some(v) == year_mod_100));
+            }
+            check(F::IsoYear, self.isoyear.map_or(true, |v| v == week.year()));
+            if self.isoyear.is_none() {
+                check(
+                    F::IsoYearDiv100,
+                    self.isoyear_div_100.map_or(true, |v| Some(v) == isoyear_div_100),
+                );
+                check(
+                    F::IsoYearMod100,
+                    self.isoyear_mod_100.map_or(true, |v| Some(v) == isoyear_mod_100),
+                );
+            }
+            check(F::Month, self.month.map_or(true, |v| v == date.month()));
+            check(F::Day, self.day.map_or(true, |v| v == date.day()));
+            check(F::Ordinal, self.ordinal.map_or(true, |v| v == date.ordinal()));
+            check(F::IsoWeek, self.isoweek.map_or(true, |v| v == week.week()));
+            check(F::Weekday, self.weekday.map_or(true, |v| v == date.weekday()));
+            let week_from_sun = date.weeks_from(Weekday::Sun);
+            check(F::WeekFromSun, self.week_from_sun.map_or(true, |v| v as i32 == week_from_sun));
+            let week_from_mon = date.weeks_from(Weekday::Mon);
+            check(F::WeekFromMon, self.week_from_mon.map_or(true, |v| v as i32 == week_from_mon));
+            check(F::JulianDay, self.julian_day.map_or(true, |v| v == date.to_julian_day()));
+        }
+
+        if let Some(timestamp) = timestamp {
+            let hour = timestamp.hour();
+            let mut check = |field: ParsedField, consistent: bool| {
+                if !consistent {
+                    conflicts.push((field, F::Timestamp));
+                }
+            };
+            check(F::HourDiv12, self.hour_div_12.map_or(true, |v| v == hour / 12));
+            check(F::HourMod12, self.hour_mod_12.map_or(true, |v| v == hour % 12));
+            check(F::Minute, self.minute.map_or(true, |v| v == timestamp.minute()));
+            if self.second != Some(60) {
+                check(F::Second, self.second.map_or(true, |v| v == timestamp.second()));
+            }
+        }
+
+        ParsedDiagnostics { resolution, conflicts }
+    }
+
     /// Get the `year` field if set.
     ///
     /// See also [`set_year()`](Parsed::set_year).
@@ -1125,6 +1642,15 @@ impl Parsed {
         self.timestamp
     }
 
+    /// Get the Julian Day Number field if set.
+    ///
+    /// See also [`set_julian_day()`](Parsed::set_julian_day).
+    #[inline]
+    #[must_use]
+    pub fn julian_day(&self) -> Option<i32> {
+        self.julian_day
+    }
+
     /// Get the `offset` field if set.
     ///
     /// See also [`set_offset()`](Parsed::set_offset).
@@ -1132,6 +1658,283 @@ impl Parsed {
     pub fn offset(&self) -> Option<i32> {
         self.offset
     }
+
+    /// Get how a two-digit year without a century number is mapped to a full year.
+    ///
+    /// See also [`set_century_resolution()`](Parsed::set_century_resolution).
+    #[inline]
+    pub fn century_resolution(&self) -> CenturyResolution {
+        self.century
+    }
+
+    /// Get the table of time zone abbreviations used to parse the `%Z` specifier.
+    ///
+    /// See also [`set_tz_abbreviations()`](Parsed::set_tz_abbreviations).
+    #[inline]
+    pub fn tz_abbreviations(&self) -> TzAbbreviations {
+        self.tz_abbreviations
+    }
+
+    /// Get the time zone, if it was parsed from a time zone identifier.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn time_zone(&self) -> Option<&TzInfo> {
+        self.time_zone.as_ref()
+    }
+
+    /// Look up a time zone identifier with the [lookup](Parsed::set_time_zone_lookup) in use.
+    #[cfg(feature = "std")]
+    pub(crate) fn lookup_time_zone(&self, name: &str) -> ParseResult<TzInfo> {
+        match self.time_zone_lookup {
+            Some(TimeZoneLookup(lookup)) => lookup(name).ok_or(INVALID),
+            None if !TzInfo::HAS_SYSTEM_ZONEINFO => Err(BAD_FORMAT),
+            None => TzInfo::from_system_zoneinfo(name).ok_or(INVALID),
+        }
+    }
+}
+
+/// The strategy [`Parsed`] uses to map a two-digit year without a century number to a full year.
+///
+/// See [`Parsed::set_century_resolution`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum CenturyResolution {
+    /// Map two-digit years to the hundred years starting at the given year.
+    ///
+    /// With `Pivot(1970)`, the default, `70` is 1970 and `69` is 2069. With `Pivot(1950)`, as
+    /// used by the `UTCTime` type of RFC 5280, `50` is 1950 and `49` is 2049.
+    Pivot(i32),
+    /// Map two-digit years to a window of a hundred years that is anchored at a reference date.
+    ///
+    /// The window starts `years_before` years before the year of `reference`, so it includes
+    /// the year of `reference` and `99 - years_before` years after it if `years_before` is less
+    /// than 100. The current date can be used as the reference to get a window that slides with
+    /// time.
+    SlidingWindow {
+        /// The date the window is anchored to.
+        reference: NaiveDate,
+        /// The number of years in the window before the year of `reference`.
+        years_before: u32,
+    },
+}
+
+impl CenturyResolution {
+    /// Returns the full year for the two-digit year `year_mod_100`, which must be in the range
+    /// `0..=99`.
+    ///
+    /// Returns `None` on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::CenturyResolution;
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(CenturyResolution::Pivot(1970).resolve(69), Some(2069));
+    /// assert_eq!(CenturyResolution::Pivot(1950).resolve(69), Some(1969));
+    ///
+    /// let reference = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+    /// let window = CenturyResolution::SlidingWindow { reference, years_before: 80 };
+    /// assert_eq!(window.resolve(44), Some(1944));
+    /// assert_eq!(window.resolve(43), Some(2043));
+    /// ```
+    #[must_use]
+    pub fn resolve(self, year_mod_100: i32) -> Option<i32> {
+        let start = match self {
+            CenturyResolution::Pivot(start) => start,
+            CenturyResolution::SlidingWindow { reference, years_before } => {
+                reference.year().checked_sub(i32::try_from(years_before).ok()?)?
+            }
+        };
+        let year = start.checked_sub(start.rem_euclid(100))?.checked_add(year_mod_100)?;
+        if year < start {
+            year.checked_add(100)
+        } else {
+            Some(year)
+        }
+    }
+}
+
+impl Default for CenturyResolution {
+    fn default() -> Self {
+        CenturyResolution::Pivot(1970)
+    }
+}
+
+/// A field of [`Parsed`], as reported by [`Parsed::diagnose`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ParsedField {
+    /// The [`year`](Parsed::year) field.
+    Year,
+    /// The [`year_div_100`](Parsed::year_div_100) field.
+    YearDiv100,
+    /// The [`year_mod_100`](Parsed::year_mod_100) field.
+    YearMod100,
+    /// The [`isoyear`](Parsed::isoyear) field.
+    IsoYear,
+    /// The [`isoyear_div_100`](Parsed::isoyear_div_100) field.
+    IsoYearDiv100,
+    /// The [`isoyear_mod_100`](Parsed::isoyear_mod_100) field.
+    IsoYearMod100,
+    /// The [`month`](Parsed::month) field.
+    Month,
+    /// The [`week_from_sun`](Parsed::week_from_sun) field.
+    WeekFromSun,
+    /// The [`week_from_mon`](Parsed::week_from_mon) field.
+    WeekFromMon,
+    /// The [`isoweek`](Parsed::isoweek) field.
+    IsoWeek,
+    /// The [`weekday`](Parsed::weekday) field.
+    Weekday,
+    /// The [`ordinal`](Parsed::ordinal) field.
+    Ordinal,
+    /// The [`day`](Parsed::day) field.
+    Day,
+    /// The [`hour_div_12`](Parsed::hour_div_12) field.
+    HourDiv12,
+    /// The [`hour_mod_12`](Parsed::hour_mod_12) field.
+    HourMod12,
+    /// The [`minute`](Parsed::minute) field.
+    Minute,
+    /// The [`second`](Parsed::second) field.
+    Second,
+    /// The [`timestamp`](Parsed::timestamp) field.
+    Timestamp,
+    /// The [`julian_day`](Parsed::julian_day) field.
+    JulianDay,
+}
+
+/// The combination of fields [`Parsed`] uses to determine a date.
+///
+/// The combinations are tried in the order of the variants below; the first one for which all
+/// fields are set is used, and all other fields are checked against the resulting date.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum DateResolution {
+    /// Year, month and day of the month.
+    YearMonthDay,
+    /// Year and day of the year.
+    YearOrdinal,
+    /// Year, week number counted from the first Sunday, and day of the week.
+    YearWeekFromSun,
+    /// Year, week number counted from the first Monday, and day of the week.
+    YearWeekFromMon,
+    /// ISO week date: ISO year, ISO week number and day of the week.
+    IsoYearWeekDay,
+    /// Julian Day Number.
+    JulianDay,
+    /// UNIX timestamp, used when none of the other combinations is complete.
+    Timestamp,
+}
+
+#[cfg(feature = "alloc")]
+impl DateResolution {
+    /// The fields that make up this combination.
+    fn fields(self) -> &'static [ParsedField] {
+        use ParsedField::*;
+        match self {
+            DateResolution::YearMonthDay => &[Year, YearDiv100, YearMod100, Month, Day],
+            DateResolution::YearOrdinal => &[Year, YearDiv100, YearMod100, Ordinal],
+            DateResolution::YearWeekFromSun => {
+                &[Year, YearDiv100, YearMod100, WeekFromSun, Weekday]
+            }
+            DateResolution::YearWeekFromMon => {
+                &[Year, YearDiv100, YearMod100, WeekFromMon, Weekday]
+            }
+            DateResolution::IsoYearWeekDay => {
+                &[IsoYear, IsoYearDiv100, IsoYearMod100, IsoWeek, Weekday]
+            }
+            DateResolution::JulianDay => &[JulianDay],
+            DateResolution::Timestamp => &[Timestamp],
+        }
+    }
+
+    /// The field of this combination that other fields are reported to conflict with.
+    fn key_field(self) -> ParsedField {
+        match self {
+            DateResolution::YearMonthDay => ParsedField::Day,
+            DateResolution::YearOrdinal => ParsedField::Ordinal,
+            DateResolution::YearWeekFromSun => ParsedField::WeekFromSun,
+            DateResolution::YearWeekFromMon => ParsedField::WeekFromMon,
+            DateResolution::IsoYearWeekDay => ParsedField::IsoWeek,
+            DateResolution::JulianDay => ParsedField::JulianDay,
+            DateResolution::Timestamp => ParsedField::Timestamp,
+        }
+    }
+}
+
+/// Diagnostics on the consistency of [`Parsed`] fields, returned by [`Parsed::diagnose`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ParsedDiagnostics {
+    resolution: Option<DateResolution>,
+    conflicts: Vec<(ParsedField, ParsedField)>,
+}
+
+#[cfg(feature = "alloc")]
+impl ParsedDiagnostics {
+    /// The combination of fields used to determine the date.
+    ///
+    /// Returns `None` if no combination is complete.
+    #[must_use]
+    pub fn resolution(&self) -> Option<DateResolution> {
+        self.resolution
+    }
+
+    /// The pairs of fields that are inconsistent with each other.
+    ///
+    /// The first field of a pair is the field that was checked, the second field is the one it
+    /// was checked against. That is usually the most specific field of the
+    /// [`resolution`](ParsedDiagnostics::resolution), such as the day for
+    /// [`DateResolution::YearMonthDay`], or the full year for the other year fields.
+    #[must_use]
+    pub fn conflicts(&self) -> &[(ParsedField, ParsedField)] {
+        &self.conflicts
+    }
+
+    /// Returns `true` if no conflicting fields were found.
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Determine the year from a full year, a century number and a two-digit year, like
+/// `to_naive_date` does, and record the fields that conflict with the full year.
+#[cfg(feature = "alloc")]
+fn diagnose_year(
+    (y, q, r): (Option<i32>, Option<i32>, Option<i32>),
+    (y_field, q_field, r_field): (ParsedField, ParsedField, ParsedField),
+    century: CenturyResolution,
+    conflicts: &mut Vec<(ParsedField, ParsedField)>,
+) -> Option<i32> {
+    match (y, q, r) {
+        (Some(y), q, r) => {
+            let (q_, r_) = split_year(y);
+            if q.is_some() && q != q_ {
+                conflicts.push((q_field, y_field));
+            }
+            if r.is_some() && r != r_ {
+                conflicts.push((r_field, y_field));
+            }
+            Some(y)
+        }
+        (None, Some(q @ 0..), Some(r @ 0..=99)) => q.checked_mul(100)?.checked_add(r),
+        (None, None, Some(r @ 0..=99)) => century.resolve(r),
+        (None, _, _) => None,
+    }
+}
+
+/// Split a year into the century number and two-digit year, which are only defined for
+/// non-negative years.
+#[cfg(feature = "alloc")]
+fn split_year(year: i32) -> (Option<i32>, Option<i32>) {
+    if year >= 0 {
+        (Some(year / 100), Some(year % 100))
+    } else {
+        (None, None)
+    }
 }
 
 /// Create a `NaiveDate` when given a year, week, weekday, and the definition at which day of the
@@ -1330,6 +2133,11 @@ mod tests {
         assert!(Parsed::new().set_timestamp(i64::MIN).is_ok());
         assert!(Parsed::new().set_timestamp(i64::MAX).is_ok());
 
+        assert_eq!(Parsed::new().set_julian_day(i32::MIN as i64 - 1), Err(OUT_OF_RANGE));
+        assert!(Parsed::new().set_julian_day(i32::MIN as i64).is_ok());
+        assert!(Parsed::new().set_julian_day(i32::MAX as i64).is_ok());
+        assert_eq!(Parsed::new().set_julian_day(i32::MAX as i64 + 1), Err(OUT_OF_RANGE));
+
         assert_eq!(Parsed::new().set_offset(i32::MIN as i64 - 1), Err(OUT_OF_RANGE));
         assert!(Parsed::new().set_offset(i32::MIN as i64).is_ok());
         assert!(Parsed::new().set_offset(i32::MAX as i64).is_ok());
@@ -1521,6 +2329,15 @@ mod tests {
         ); // ambiguous (2014-12-29, 2014-12-30, 2014-12-31)
         assert_eq!(parse!(year_div_100: 20, isoyear_mod_100: 15, ordinal: 366), Err(NOT_ENOUGH));
         // technically unique (2014-12-31) but Chrono gives up
+
+        // Julian Day Number
+        assert_eq!(parse!(julian_day: 2_457_023), ymd(2014, 12, 31));
+        assert_eq!(parse!(julian_day: -1), ymd(-4713, 11, 23));
+        assert_eq!(parse!(julian_day: 2_457_023, year: 2014, weekday: Wed), ymd(2014, 12, 31));
+        assert_eq!(parse!(julian_day: 2_457_023, isoyear: 2015, isoweek: 1), ymd(2014, 12, 31));
+        assert_eq!(parse!(julian_day: 2_457_023, weekday: Thu), Err(IMPOSSIBLE));
+        assert_eq!(parse!(julian_day: 2_457_023, year: 2014, month: 12, day: 30), Err(IMPOSSIBLE));
+        assert_eq!(parse!(julian_day: i32::MAX), Err(OUT_OF_RANGE));
     }
 
     #[test]
@@ -1863,4 +2680,228 @@ mod tests {
         parsed.year = Some(2001);
         assert_eq!(NaiveDate::from_ymd_opt(2001, 5, 28).unwrap(), parsed.to_naive_date().unwrap());
     }
+
+    #[test]
+    fn test_parsed_century_resolution() {
+        use super::CenturyResolution::{self, Pivot, SlidingWindow};
+
+        let parse = |century: CenturyResolution, year_mod_100: i32| {
+            let mut parsed = Parsed {
+                year_mod_100: Some(year_mod_100),
+                month: Some(1),
+                day: Some(2),
+                ..Parsed::new()
+            };
+            parsed.set_century_resolution(century);
+            parsed.to_naive_date().map(|d| d.year())
+        };
+
+        assert_eq!(Parsed::new().century_resolution(), Pivot(1970));
+        assert_eq!(parse(Pivot(1950), 49), Ok(2049));
+        assert_eq!(parse(Pivot(1950), 50), Ok(1950));
+        assert_eq!(parse(Pivot(1900), 0), Ok(1900));
+        assert_eq!(parse(Pivot(1900), 99), Ok(1999));
+        assert_eq!(parse(Pivot(-150), 49), Ok(-51));
+        assert_eq!(parse(Pivot(-150), 50), Ok(-150));
+        assert_eq!(parse(Pivot(i32::MAX), 0), Err(OUT_OF_RANGE));
+
+        let reference = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        assert_eq!(parse(SlidingWindow { reference, years_before: 50 }, 74), Ok(1974));
+        assert_eq!(parse(SlidingWindow { reference, years_before: 50 }, 73), Ok(2073));
+        assert_eq!(parse(SlidingWindow { reference, years_before: 0 }, 24), Ok(2024));
+        assert_eq!(parse(SlidingWindow { reference, years_before: 0 }, 23), Ok(2123));
+        assert_eq!(parse(SlidingWindow { reference, years_before: 99 }, 24), Ok(2024));
+        assert_eq!(parse(SlidingWindow { reference, years_before: 99 }, 25), Ok(1925));
+
+        // the ISO week date year is resolved the same way
+        let mut parsed = Parsed {
+            isoyear_mod_100: Some(49),
+            isoweek: Some(1),
+            weekday: Some(Mon),
+            ..Parsed::new()
+        };
+        parsed.set_century_resolution(Pivot(1950));
+        assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_isoywd_opt(2049, 1, Mon).unwrap()));
+        parsed.set_century_resolution(Pivot(1900));
+        assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_isoywd_opt(1949, 1, Mon).unwrap()));
+    }
+
+    #[test]
+    fn test_parsed_fill_missing_from() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let reference = ymd(2024, 6, 15).and_hms_nano_opt(21, 10, 11, 12).unwrap();
+
+        macro_rules! fill {
+            ($($k:ident: $v:expr),*) => ({
+                let parsed = Parsed { $($k: Some($v),)* ..Parsed::new() };
+                parsed.with_defaults(reference).to_naive_datetime_with_offset(0)
+            })
+        }
+
+        // nothing set
+        assert_eq!(fill!(), Ok(reference));
+
+        // more significant fields come from the reference, less significant fields are minimal
+        assert_eq!(fill!(year: 2023), Ok(ymd(2023, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(year_mod_100: 23), Ok(ymd(2023, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(month: 3), Ok(ymd(2024, 3, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(month: 3, day: 5), Ok(ymd(2024, 3, 5).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(day: 20), Ok(ymd(2024, 6, 20).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(
+            fill!(hour_div_12: 0, hour_mod_12: 2),
+            Ok(ymd(2024, 6, 15).and_hms_opt(2, 0, 0).unwrap())
+        );
+        assert_eq!(fill!(hour_mod_12: 2), Ok(ymd(2024, 6, 15).and_hms_opt(14, 0, 0).unwrap()));
+        assert_eq!(
+            fill!(day: 20, hour_mod_12: 2),
+            Ok(ymd(2024, 6, 20).and_hms_opt(2, 0, 0).unwrap())
+        );
+        assert_eq!(fill!(hour_div_12: 0), Ok(ymd(2024, 6, 15).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(minute: 30), Ok(ymd(2024, 6, 15).and_hms_opt(21, 30, 0).unwrap()));
+        assert_eq!(
+            fill!(nanosecond: 5),
+            Ok(ymd(2024, 6, 15).and_hms_nano_opt(21, 10, 11, 5).unwrap())
+        );
+
+        // other combinations are not overridden by the month and day
+        assert_eq!(fill!(ordinal: 32), Ok(ymd(2024, 2, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(
+            fill!(week_from_mon: 1, weekday: Mon),
+            Ok(ymd(2024, 1, 1).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            fill!(isoweek: 1, weekday: Mon),
+            Ok(ymd(2024, 1, 1).and_hms_opt(0, 0, 0).unwrap())
+        );
+
+        // a week without a weekday starts at its first day
+        assert_eq!(fill!(week_from_sun: 1), Ok(ymd(2024, 1, 7).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(week_from_mon: 1), Ok(ymd(2024, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(isoweek: 10), Ok(ymd(2024, 3, 4).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(
+            fill!(isoyear: 2021, isoweek: 1),
+            Ok(ymd(2021, 1, 4).and_hms_opt(0, 0, 0).unwrap())
+        );
+
+        // a century or an ISO week-based year on its own starts at its first year or week
+        assert_eq!(fill!(year_div_100: 19), Ok(ymd(1900, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(fill!(isoyear: 2021), Ok(ymd(2021, 1, 4).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(
+            fill!(isoyear_div_100: 20, isoyear_mod_100: 20),
+            Ok(ymd(2019, 12, 30).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(fill!(isoyear_div_100: 20), Ok(ymd(2000, 1, 3).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(
+            fill!(year: 2023, week_from_sun: 10, hour_div_12: 1),
+            Ok(ymd(2023, 3, 5).and_hms_opt(12, 0, 0).unwrap())
+        );
+
+        // a lone weekday is checked against the reference
+        assert_eq!(fill!(weekday: Sat), Ok(reference));
+        assert_eq!(fill!(weekday: Sun), Err(IMPOSSIBLE));
+        // an explicit day may not exist in the month of the reference
+        assert_eq!(fill!(month: 2, day: 30), Err(OUT_OF_RANGE));
+
+        // a Julian Day Number determines the date
+        assert_eq!(
+            fill!(julian_day: 2_457_023),
+            Ok(ymd(2014, 12, 31).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            fill!(julian_day: 2_457_023, minute: 5),
+            Ok(ymd(2014, 12, 31).and_hms_opt(0, 5, 0).unwrap())
+        );
+
+        // a timestamp determines everything
+        assert_eq!(
+            fill!(timestamp: 1_420_000_000),
+            Ok(ymd(2014, 12, 31).and_hms_opt(4, 26, 40).unwrap())
+        );
+
+        // a leap second in the reference
+        let leap = ymd(2016, 12, 31).and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        let parsed = Parsed::new().with_defaults(leap);
+        assert_eq!(parsed.second, Some(60));
+        assert_eq!(parsed.to_naive_datetime_with_offset(0), Ok(leap));
+
+        // the offset is filled from a `DateTime`
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let mut parsed = Parsed { minute: Some(30), ..Parsed::new() };
+        parsed.fill_missing_from_datetime(&tz.from_local_datetime(&reference).unwrap());
+        assert_eq!(parsed.offset, Some(3600));
+        assert_eq!(parsed.to_datetime(), Ok(tz.with_ymd_and_hms(2024, 6, 15, 21, 30, 0).unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parsed_diagnose() {
+        use super::{DateResolution, ParsedField as F};
+
+        macro_rules! parse {
+            ($($k:ident: $v:expr),*) => (
+                Parsed { $($k: Some($v),)* ..Parsed::new() }.diagnose()
+            )
+        }
+
+        // nothing to resolve
+        let d = parse!();
+        assert_eq!((d.resolution(), d.conflicts()), (None, &[][..]));
+        let d = parse!(year: 2014, month: 12);
+        assert_eq!((d.resolution(), d.is_consistent()), (None, true));
+
+        // combinations, in order of preference
+        let d = parse!(year: 2014, month: 12, day: 31, ordinal: 365, weekday: Wed);
+        assert_eq!((d.resolution(), d.conflicts()), (Some(DateResolution::YearMonthDay), &[][..]));
+        let d = parse!(year: 2014, ordinal: 365, week_from_sun: 52, weekday: Wed);
+        assert_eq!((d.resolution(), d.conflicts()), (Some(DateResolution::YearOrdinal), &[][..]));
+        let d = parse!(year: 2014, week_from_sun: 52, week_from_mon: 52, weekday: Wed);
+        assert_eq!(d.resolution(), Some(DateResolution::YearWeekFromSun));
+        assert!(d.is_consistent());
+        let d = parse!(year_mod_100: 14, week_from_mon: 52, weekday: Wed);
+        assert_eq!(d.resolution(), Some(DateResolution::YearWeekFromMon));
+        assert!(d.is_consistent());
+        let d = parse!(isoyear: 2015, isoweek: 1, weekday: Wed, year: 2014, month: 12);
+        assert_eq!(
+            (d.resolution(), d.conflicts()),
+            (Some(DateResolution::IsoYearWeekDay), &[][..])
+        );
+        let d = parse!(julian_day: 2_457_023, year: 2014, ordinal: 365, timestamp: 1_420_000_000);
+        assert_eq!((d.resolution(), d.conflicts()), (Some(DateResolution::YearOrdinal), &[][..]));
+        let d = parse!(julian_day: 2_457_023, year: 2014, month: 12, timestamp: 1_420_000_000);
+        assert_eq!((d.resolution(), d.conflicts()), (Some(DateResolution::JulianDay), &[][..]));
+        let d = parse!(timestamp: 1_420_000_000, year: 2014, month: 12, hour_mod_12: 4);
+        assert_eq!((d.resolution(), d.conflicts()), (Some(DateResolution::Timestamp), &[][..]));
+
+        // conflicting fields
+        let d = parse!(year: 2014, month: 12, day: 31, weekday: Thu, ordinal: 364);
+        assert_eq!(d.resolution(), Some(DateResolution::YearMonthDay));
+        assert_eq!(d.conflicts(), [(F::Ordinal, F::Day), (F::Weekday, F::Day)]);
+        assert!(!d.is_consistent());
+        let d = parse!(year: 2014, ordinal: 365, month: 11);
+        assert_eq!(d.conflicts(), [(F::Month, F::Ordinal)]);
+        let d = parse!(year: 2014, year_div_100: 19, year_mod_100: 14, month: 1, day: 1);
+        assert_eq!(d.conflicts(), [(F::YearDiv100, F::Year)]);
+        let d = parse!(year: 2014, week_from_sun: 0, weekday: Mon);
+        assert_eq!(d.conflicts(), [(F::Weekday, F::WeekFromSun)]);
+        let d = parse!(isoyear: 2015, isoweek: 1, weekday: Wed, year: 2015);
+        assert_eq!(d.conflicts(), [(F::Year, F::IsoWeek)]);
+        let d = parse!(julian_day: 2_457_024, year: 2014, month: 12, day: 31);
+        assert_eq!(d.conflicts(), [(F::JulianDay, F::Day)]);
+        let d = parse!(julian_day: 2_457_024, timestamp: 1_420_000_000);
+        assert_eq!(d.conflicts(), [(F::Timestamp, F::JulianDay)]);
+
+        // conflicts with the timestamp
+        let d = parse!(year: 2014, month: 12, day: 30, timestamp: 1_420_000_000);
+        assert_eq!(d.resolution(), Some(DateResolution::YearMonthDay));
+        assert_eq!(d.conflicts(), [(F::Timestamp, F::Day)]);
+        let d = parse!(timestamp: 1_420_000_000, year: 2015, hour_div_12: 1, minute: 26);
+        assert_eq!(d.resolution(), Some(DateResolution::Timestamp));
+        assert_eq!(d.conflicts(), [(F::Year, F::Timestamp), (F::HourDiv12, F::Timestamp)]);
+        let d = parse!(timestamp: 1_420_000_000, offset: 86_400, day: 31);
+        assert_eq!(d.conflicts(), [(F::Day, F::Timestamp)]);
+        let d = parse!(timestamp: 1_420_000_000, second: 60);
+        assert_eq!(d.conflicts(), [(F::Second, F::Timestamp)]);
+        let d = parse!(timestamp: 1_341_100_800, second: 60, minute: 59);
+        assert!(d.is_consistent());
+    }
 }
@@ -5,6 +5,8 @@
  * Various scanning routines for the parser.
  */
 
+#[cfg(feature = "unstable-locales")]
+use super::locales::{self, Locale};
 use super::{ParseResult, INVALID, OUT_OF_RANGE, TOO_SHORT};
 use crate::Weekday;
 
@@ -159,6 +161,157 @@ pub(super) fn short_or_long_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
     Ok((s, weekday))
 }
 
+/// Tries to parse `AM` or `PM`, ignoring case. Returns `true` for `PM`.
+pub(super) fn ampm(s: &str) -> ParseResult<(&str, bool)> {
+    if s.len() < 2 {
+        return Err(TOO_SHORT);
+    }
+    let pm = match (s.as_bytes()[0] | 32, s.as_bytes()[1] | 32) {
+        (b'a', b'm') => false,
+        (b'p', b'm') => true,
+        _ => return Err(INVALID),
+    };
+    Ok((&s[2..], pm))
+}
+
+/// Tries to parse the month index (0 through 11) with the month names of `locale`.
+/// Long month names are only accepted if `long` is set, and preferred over short month names.
+#[cfg(feature = "unstable-locales")]
+pub(super) fn localized_month0(s: &str, locale: Locale, long: bool) -> ParseResult<(&str, u8)> {
+    let short = locales::short_months(locale);
+    let (s, month0) = if long {
+        localized_name(s, &[locales::long_months(locale), short])?
+    } else {
+        localized_name(s, &[short])?
+    };
+    Ok((s, month0 as u8))
+}
+
+/// Tries to parse the weekday with the weekday names of `locale`.
+/// Long weekday names are only accepted if `long` is set, and preferred over short weekday names.
+#[cfg(feature = "unstable-locales")]
+pub(super) fn localized_weekday(
+    s: &str,
+    locale: Locale,
+    long: bool,
+) -> ParseResult<(&str, Weekday)> {
+    // the locale tables start at Sunday
+    const WEEKDAYS: [Weekday; 7] = [
+        Weekday::Sun,
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+    ];
+
+    let short = locales::short_weekdays(locale);
+    let (s, index) = if long {
+        localized_name(s, &[locales::long_weekdays(locale), short])?
+    } else {
+        localized_name(s, &[short])?
+    };
+    Ok((s, WEEKDAYS[index]))
+}
+
+/// Tries to parse the AM/PM designator of `locale`. Returns `true` for PM.
+///
+/// Locales without a 12-hour clock have no designators, `AM` and `PM` are accepted for them.
+#[cfg(feature = "unstable-locales")]
+pub(super) fn localized_ampm(s: &str, locale: Locale) -> ParseResult<(&str, bool)> {
+    let am_pm = locales::am_pm(locale);
+    if am_pm.iter().all(|name| name.is_empty()) {
+        return ampm(s);
+    }
+    let (s, index) = localized_name(s, &[am_pm])?;
+    Ok((s, index == 1))
+}
+
+/// Tries to parse the longest of the names in `tables`, ignoring case and accents.
+/// Returns the index of the name within its table.
+#[cfg(feature = "unstable-locales")]
+fn localized_name<'a>(s: &'a str, tables: &[&[&str]]) -> ParseResult<(&'a str, usize)> {
+    let mut longest: Option<(usize, usize)> = None;
+    let mut err = INVALID;
+    for table in tables {
+        for (index, name) in table.iter().enumerate().filter(|(_, name)| !name.is_empty()) {
+            match folded_prefix_len(s, name) {
+                Ok(len) if longest.map_or(true, |(longest, _)| len > longest) => {
+                    longest = Some((len, index));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    if e == TOO_SHORT {
+                        err = TOO_SHORT;
+                    }
+                }
+            }
+        }
+    }
+    let (len, index) = longest.ok_or(err)?;
+    Ok((&s[len..], index))
+}
+
+/// Returns the length in bytes of the prefix of `s` that matches `name`, ignoring case and
+/// accents.
+///
+/// Some locales pad names with a leading space (such as `" 1월"`), which may already have been
+/// consumed by a preceding space item. Any amount of leading whitespace matches such names.
+#[cfg(feature = "unstable-locales")]
+fn folded_prefix_len(s: &str, name: &str) -> ParseResult<usize> {
+    let is_combining_mark = |c: char| matches!(c, '\u{300}'..='\u{36f}');
+
+    let (s, name, skipped) = if name.starts_with(char::is_whitespace) {
+        let trimmed = s.trim_start();
+        (trimmed, name.trim_start(), s.len() - trimmed.len())
+    } else {
+        (s, name, 0)
+    };
+
+    let mut input = s.char_indices().filter(|&(_, c)| !is_combining_mark(c));
+    let mut len = 0;
+    for expected in name.chars().filter(|&c| !is_combining_mark(c)) {
+        match input.next() {
+            Some((i, c)) if fold_char(c) == fold_char(expected) => len = i + c.len_utf8(),
+            Some(_) => return Err(INVALID),
+            None => return Err(TOO_SHORT),
+        }
+    }
+    // accents that follow the last character as combining marks are part of the match
+    len +=
+        s[len..].chars().take_while(|&c| is_combining_mark(c)).map(char::len_utf8).sum::<usize>();
+    Ok(skipped + len)
+}
+
+/// Lowercases `c` and removes the accent from the Latin letters in the Latin-1 Supplement and
+/// Latin Extended-A blocks.
+#[cfg(feature = "unstable-locales")]
+fn fold_char(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => 's',
+        'ţ' | 'ť' | 'ŧ' | 'ț' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
 /// Tries to consume exactly one given character.
 pub(super) fn char(s: &str, c1: u8) -> ParseResult<&str> {
     match s.as_bytes().first() {
@@ -0,0 +1,363 @@
+//! Serialization/deserialization with a custom `strftime`-like format string.
+//!
+//! The [`strftime!`](crate::serde::strftime) macro generates a module for use with serde's `with`
+//! attribute. The format string is parsed at compile time, and the generated functions forward to
+//! the implementations of [`StrftimeSerde`] in here.
+
+use core::fmt;
+use core::marker::PhantomData;
+use serde::{de, ser};
+
+use super::{parse, Item, ParseResult, Parsed};
+use crate::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+/// A value that can be serialized to and deserialized from a string with a custom format.
+///
+/// This trait is implemented for [`DateTime`], [`NaiveDateTime`], [`NaiveDate`] and
+/// [`NaiveTime`], and for an `Option` of any of them. It is used by the modules generated with
+/// [`strftime!`](crate::serde::strftime), and is not meant to be implemented outside of chrono.
+///
+/// Deserializing a `DateTime<Tz>` requires the format string to contain an offset, and is only
+/// possible for a `DateTime<Tz>` with a `From<DateTime<FixedOffset>>` implementation.
+pub trait StrftimeSerde: Sized {
+    /// Serialize `self` by formatting it with `items`.
+    fn serialize_with_items<S>(&self, items: &[Item<'_>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer;
+
+    /// Deserialize a value by parsing a string with `items`.
+    fn deserialize_with_items<'de, D>(
+        items: &[Item<'_>],
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>;
+}
+
+impl<Tz> StrftimeSerde for DateTime<Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: fmt::Display,
+    DateTime<Tz>: From<DateTime<FixedOffset>>,
+{
+    fn serialize_with_items<S>(&self, items: &[Item<'_>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&self.format_with_items(items.iter()))
+    }
+
+    fn deserialize_with_items<'de, D>(items: &[Item<'_>], deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let visitor = StrftimeVisitor { items, to_value: |parsed| parsed.to_datetime() };
+        deserializer.deserialize_str(visitor).map(DateTime::from)
+    }
+}
+
+impl StrftimeSerde for NaiveDateTime {
+    fn serialize_with_items<S>(&self, items: &[Item<'_>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&self.format_with_items(items.iter()))
+    }
+
+    fn deserialize_with_items<'de, D>(items: &[Item<'_>], deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let visitor =
+            StrftimeVisitor { items, to_value: |parsed| parsed.to_naive_datetime_with_offset(0) };
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+impl StrftimeSerde for NaiveDate {
+    fn serialize_with_items<S>(&self, items: &[Item<'_>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&self.format_with_items(items.iter()))
+    }
+
+    fn deserialize_with_items<'de, D>(items: &[Item<'_>], deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let visitor = StrftimeVisitor { items, to_value: |parsed| parsed.to_naive_date() };
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+impl StrftimeSerde for NaiveTime {
+    fn serialize_with_items<S>(&self, items: &[Item<'_>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&self.format_with_items(items.iter()))
+    }
+
+    fn deserialize_with_items<'de, D>(items: &[Item<'_>], deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let visitor = StrftimeVisitor { items, to_value: |parsed| parsed.to_naive_time() };
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+impl<T: StrftimeSerde> StrftimeSerde for Option<T> {
+    fn serialize_with_items<S>(&self, items: &[Item<'_>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match *self {
+            Some(ref value) => serializer.serialize_some(&WithItems { value, items }),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_with_items<'de, D>(items: &[Item<'_>], deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionStrftimeVisitor { items, value: PhantomData })
+    }
+}
+
+struct WithItems<'a, 'b, T> {
+    value: &'a T,
+    items: &'a [Item<'b>],
+}
+
+impl<T: StrftimeSerde> ser::Serialize for WithItems<'_, '_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.value.serialize_with_items(self.items, serializer)
+    }
+}
+
+struct StrftimeVisitor<'a, 'b, T> {
+    items: &'a [Item<'b>],
+    to_value: fn(&Parsed) -> ParseResult<T>,
+}
+
+impl<'de, T> de::Visitor<'de> for StrftimeVisitor<'_, '_, T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a formatted date and time string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, value, self.items.iter()).map_err(E::custom)?;
+        (self.to_value)(&parsed).map_err(E::custom)
+    }
+}
+
+struct OptionStrftimeVisitor<'a, 'b, T> {
+    items: &'a [Item<'b>],
+    value: PhantomData<T>,
+}
+
+impl<'de, T: StrftimeSerde> de::Visitor<'de> for OptionStrftimeVisitor<'_, '_, T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a formatted date and time string or none")
+    }
+
+    fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        T::deserialize_with_items(self.items, d).map(Some)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+}
+
+/// Generate a module to serialize and deserialize with a custom `strftime`-like format string.
+///
+/// The generated module is intended for use with serde's [`with` attribute][1]. It works for
+/// [`DateTime`], [`NaiveDateTime`], [`NaiveDate`] and [`NaiveTime`], and for an `Option` of any
+/// of them. See the [`format::strftime` module](crate::format::strftime) for the supported
+/// format specifiers.
+///
+/// The format string is parsed at compile time with
+/// [`strftime_items!`](crate::format::strftime_items), so an invalid format string is a compile
+/// error. The module also has a `FORMAT` constant with the format string.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveDateTime};
+/// use serde_derive::{Deserialize, Serialize};
+///
+/// chrono::serde::strftime!(mod ymd_hm = "%Y-%m-%d %H:%M");
+/// chrono::serde::strftime!(mod day_month = "%d/%m/%Y");
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Meeting {
+///     #[serde(with = "ymd_hm")]
+///     start: NaiveDateTime,
+///     #[serde(with = "day_month")]
+///     cancelled: Option<NaiveDate>,
+/// }
+///
+/// let meeting = Meeting {
+///     start: NaiveDate::from_ymd_opt(2023, 9, 1).unwrap().and_hms_opt(14, 30, 0).unwrap(),
+///     cancelled: None,
+/// };
+/// let as_string = serde_json::to_string(&meeting)?;
+/// assert_eq!(as_string, r#"{"start":"2023-09-01 14:30","cancelled":null}"#);
+/// assert_eq!(serde_json::from_str::<Meeting>(&as_string)?, meeting);
+/// assert_eq!(ymd_hm::FORMAT, "%Y-%m-%d %H:%M");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [1]: https://serde.rs/field-attrs.html#with
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serde_strftime {
+    ($(#[$attr:meta])* $vis:vis mod $name:ident = $fmt:expr) => {
+        $(#[$attr])*
+        $vis mod $name {
+            /// The format string used to serialize and deserialize.
+            pub const FORMAT: &str = $fmt;
+
+            const ITEMS: &[$crate::format::Item<'static>] = $crate::format::strftime_items!(FORMAT);
+
+            /// Serialize a value with [`FORMAT`].
+            pub fn serialize<S, T>(value: &T, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::__private::Serializer,
+                T: $crate::serde::StrftimeSerde,
+            {
+                $crate::serde::StrftimeSerde::serialize_with_items(value, ITEMS, serializer)
+            }
+
+            /// Deserialize a value with [`FORMAT`].
+            pub fn deserialize<'de, D, T>(deserializer: D) -> ::core::result::Result<T, D::Error>
+            where
+                D: $crate::serde::__private::Deserializer<'de>,
+                T: $crate::serde::StrftimeSerde,
+            {
+                $crate::serde::StrftimeSerde::deserialize_with_items(ITEMS, deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+    use serde_derive::{Deserialize, Serialize};
+
+    crate::serde::strftime!(#[allow(unreachable_pub)] mod ymd = "%Y-%m-%d");
+    crate::serde::strftime!(#[allow(unreachable_pub)] mod hms = "%H:%M:%S%.f");
+    crate::serde::strftime!(#[allow(unreachable_pub)] mod ymd_hm = "%Y-%m-%d %H:%M");
+    crate::serde::strftime!(#[allow(unreachable_pub)] mod with_offset = "%Y-%m-%d %H:%M:%S %z");
+
+    #[test]
+    fn test_serde_strftime() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "ymd")]
+            date: NaiveDate,
+            #[serde(with = "hms")]
+            time: NaiveTime,
+            #[serde(with = "ymd_hm")]
+            naive: NaiveDateTime,
+            #[serde(with = "with_offset")]
+            fixed: DateTime<FixedOffset>,
+            #[serde(with = "with_offset")]
+            utc: DateTime<Utc>,
+        }
+
+        let date = NaiveDate::from_ymd_opt(2023, 9, 1).unwrap();
+        let time = NaiveTime::from_hms_milli_opt(14, 30, 5, 250).unwrap();
+        let naive = date.and_hms_opt(14, 30, 0).unwrap();
+        let fixed = FixedOffset::east_opt(2 * 3600).unwrap().from_local_datetime(&naive).unwrap();
+        let s = S { date, time, naive, fixed, utc: fixed.with_timezone(&Utc) };
+
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(
+            json,
+            r#"{"date":"2023-09-01","time":"14:30:05.250","naive":"2023-09-01 14:30","fixed":"2023-09-01 14:30:00 +0200","utc":"2023-09-01 12:30:00 +0000"}"#
+        );
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), s);
+
+        let bytes = bincode::serialize(&s).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&bytes).unwrap(), s);
+    }
+
+    #[test]
+    fn test_serde_strftime_option() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "ymd")]
+            date: Option<NaiveDate>,
+            #[serde(with = "with_offset")]
+            dt: Option<DateTime<Utc>>,
+        }
+
+        let s = S {
+            date: NaiveDate::from_ymd_opt(2023, 9, 1),
+            dt: Some(Utc.with_ymd_and_hms(2023, 9, 1, 12, 0, 0).unwrap()),
+        };
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, r#"{"date":"2023-09-01","dt":"2023-09-01 12:00:00 +0000"}"#);
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), s);
+        let bytes = bincode::serialize(&s).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&bytes).unwrap(), s);
+
+        let none = S { date: None, dt: None };
+        let json = serde_json::to_string(&none).unwrap();
+        assert_eq!(json, r#"{"date":null,"dt":null}"#);
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), none);
+        let bytes = bincode::serialize(&none).unwrap();
+        assert_eq!(bincode::deserialize::<S>(&bytes).unwrap(), none);
+    }
+
+    #[test]
+    fn test_serde_strftime_errors() {
+        #[derive(Debug, Deserialize, Serialize)]
+        struct Date {
+            #[serde(with = "ymd")]
+            date: NaiveDate,
+        }
+        #[derive(Debug, Deserialize)]
+        struct NoOffset {
+            #[serde(with = "ymd_hm")]
+            dt: DateTime<Utc>,
+        }
+
+        assert!(serde_json::from_str::<Date>(r#"{"date":"2023-09-01 12:00"}"#).is_err());
+        assert!(serde_json::from_str::<Date>(r#"{"date":"2023-02-30"}"#).is_err());
+        assert!(serde_json::from_str::<Date>(r#"{"date":20230901}"#).is_err());
+        assert!(serde_json::from_str::<NoOffset>(r#"{"dt":"2023-09-01 12:00"}"#)
+            .map(|s| s.dt)
+            .is_err());
+    }
+}
@@ -36,6 +36,7 @@ The following specifiers are available both to formatting and parsing.
 | `%V`  | `27`     | Same as `%U` but uses the week number in ISO 8601 week date (01--53). [^3] |
 |       |          |                                                                            |
 | `%j`  | `189`    | Day of the year (001--366), zero-padded to 3 digits.                       |
+| `%J`  | `2452099`| Julian Day Number of the date. [^9]                                        |
 |       |          |                                                                            |
 | `%D`  | `07/08/01`    | Month-day-year format. Same as `%m/%d/%y`.                            |
 | `%x`  | `07/08/01`    | Locale's date representation (e.g., 12/31/99).                        |
@@ -68,7 +69,8 @@ The following specifiers are available both to formatting and parsing.
 | `%r`  | `12:34:60 AM` | Locale's 12 hour clock time. (e.g., 11:11:04 PM). Falls back to `%X` if the locale does not have a 12 hour clock format. |
 |       |          |                                                                            |
 |       |          | **TIME ZONE SPECIFIERS:**                                                  |
-| `%Z`  | `ACST`   | Local time zone name. Skips all non-whitespace characters during parsing. Identical to `%:z` when formatting. [^8] |
+| `%Z`  | `ACST`   | Local time zone name. Sets the offset for known abbreviations during parsing. Identical to `%:z` when formatting. [^8] |
+| `%:Z` | `Australia/Darwin` | IANA time zone identifier, used to resolve the offset when parsing. Identical to `%Z` when formatting an offset without one. [^8] |
 | `%z`  | `+0930`  | Offset from the local time to UTC (with UTC being `+0000`).                |
 | `%:z` | `+09:30` | Same as `%z` but with a colon.                                             |
 |`%::z`|`+09:30:00`| Offset from the local time to UTC with seconds.                            |
@@ -146,27 +148,40 @@ Notes:
    for more information.
    <br>
    <br>
-   Offset will not be populated from the parsed data, nor will it be validated.
-   Timezone is completely ignored. Similar to the glibc `strptime` treatment of
-   this format code.
+   When parsing, all non-whitespace characters are consumed and looked up in the
+   [`TzAbbreviations`](crate::format::TzAbbreviations) table of [`Parsed`](crate::format::Parsed),
+   which sets the offset for known abbreviations. Unknown abbreviations are skipped.
+   It is not possible to reliably convert from an abbreviation to an offset,
+   for example CST can mean either Central Standard Time (North America) or
+   China Standard Time. Such ambiguous abbreviations are skipped by default,
+   see [`AbbreviationAmbiguity`](crate::format::AbbreviationAmbiguity).
    <br>
    <br>
-   It is not possible to reliably convert from an abbreviation to an offset,
-   for example CDT can mean either Central Daylight Time (North America) or
-   China Daylight Time.
+   `%:Z` parses a time zone identifier like `Europe/Prague` and loads it from the
+   system's zoneinfo directories, or with the lookup set by
+   [`Parsed::set_time_zone_lookup`](crate::format::Parsed::set_time_zone_lookup),
+   so the offset is resolved from the parsed local date and time. This requires the
+   `std` feature; without it, or on platforms without a zoneinfo database and
+   without a custom lookup, parsing `%:Z` fails.
+   When formatting, `%:Z` writes the name of a [`TzInfo`](crate::TzInfo) time zone.
+
+[^9]: `%J`:
+   The number of days since November 24, 4714 BCE in the proleptic Gregorian calendar,
+   see [`NaiveDate::from_julian_day`](crate::NaiveDate::from_julian_day).
+   This is not padded and can be negative.
 */
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-use super::{fixed, internal_fixed, num, num0, nums};
 #[cfg(feature = "unstable-locales")]
 use super::{locales, Locale};
-use super::{Fixed, InternalInternal, Item, Numeric, Pad};
+use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad};
 #[cfg(any(feature = "alloc", feature = "std"))]
 use super::{ParseError, BAD_FORMAT};
 #[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
 use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
 
 /// Parsing iterator for `strftime`-like format strings.
 ///
@@ -180,6 +195,10 @@ use alloc::vec::Vec;
 /// [`parse_from_str`] and [`format`] on types such as [`DateTime`](crate::DateTime) are easier to
 /// use.
 ///
+/// For a format string that is known at compile time, [`strftime_items!`] parses it during
+/// compilation into a `&'static [Item<'static>]`, and rejects invalid formatting specifiers.
+///
+/// [`strftime_items!`]: crate::format::strftime_items
 /// [`format`]: crate::DateTime::format
 /// [`format_with_items`]: crate::DateTime::format
 /// [`parse_from_str`]: crate::DateTime::parse_from_str
@@ -241,6 +260,7 @@ impl<'a> StrftimeItems<'a> {
     /// Note: `StrftimeItems::new_with_locale` only localizes the *format*. You usually want to
     /// combine it with other locale-aware methods such as
     /// [`DateTime::format_localized_with_items`] to get things like localized month or day names.
+    /// For parsing, use it with [`parse_localized`] to accept the localized names.
     ///
     /// The `%x` formatting specifier will use the local date format, `%X` the local time format,
     ///  and `%c` the local format for date and time.
@@ -251,6 +271,7 @@ impl<'a> StrftimeItems<'a> {
     /// specifiers.
     ///
     ///  [`DateTime::format_localized_with_items`]: crate::DateTime::format_localized_with_items
+    ///  [`parse_localized`]: crate::format::parse_localized
     ///
     /// # Errors
     ///
@@ -383,8 +404,6 @@ impl<'a> StrftimeItems<'a> {
     }
 }
 
-const HAVE_ALTERNATES: &str = "z";
-
 impl<'a> Iterator for StrftimeItems<'a> {
     type Item = Item<'a>;
 
@@ -412,38 +431,7 @@ impl<'a> Iterator for StrftimeItems<'a> {
 
 impl<'a> StrftimeItems<'a> {
     fn parse_next_item(&mut self, mut remainder: &'a str) -> Option<(&'a str, Item<'a>)> {
-        use InternalInternal::*;
         use Item::{Literal, Space};
-        use Numeric::*;
-
-        static D_FMT: &[Item<'static>] =
-            &[num0(Month), Literal("/"), num0(Day), Literal("/"), num0(YearMod100)];
-        static D_T_FMT: &[Item<'static>] = &[
-            fixed(Fixed::ShortWeekdayName),
-            Space(" "),
-            fixed(Fixed::ShortMonthName),
-            Space(" "),
-            nums(Day),
-            Space(" "),
-            num0(Hour),
-            Literal(":"),
-            num0(Minute),
-            Literal(":"),
-            num0(Second),
-            Space(" "),
-            num0(Year),
-        ];
-        static T_FMT: &[Item<'static>] =
-            &[num0(Hour), Literal(":"), num0(Minute), Literal(":"), num0(Second)];
-        static T_FMT_AMPM: &[Item<'static>] = &[
-            num0(Hour12),
-            Literal(":"),
-            num0(Minute),
-            Literal(":"),
-            num0(Second),
-            Space(" "),
-            fixed(Fixed::UpperAmPm),
-        ];
 
         match remainder.chars().next() {
             // we are done
@@ -451,189 +439,19 @@ impl<'a> StrftimeItems<'a> {
 
             // the next item is a specifier
             Some('%') => {
-                remainder = &remainder[1..];
-
-                macro_rules! next {
-                    () => {
-                        match remainder.chars().next() {
-                            Some(x) => {
-                                remainder = &remainder[x.len_utf8()..];
-                                x
-                            }
-                            None => return Some((remainder, Item::Error)), // premature end of string
-                        }
-                    };
-                }
-
-                let spec = next!();
-                let pad_override = match spec {
-                    '-' => Some(Pad::None),
-                    '0' => Some(Pad::Zero),
-                    '_' => Some(Pad::Space),
-                    _ => None,
-                };
-                let is_alternate = spec == '#';
-                let spec = if pad_override.is_some() || is_alternate { next!() } else { spec };
-                if is_alternate && !HAVE_ALTERNATES.contains(spec) {
-                    return Some((remainder, Item::Error));
-                }
-
-                macro_rules! queue {
-                    [$head:expr, $($tail:expr),+ $(,)*] => ({
-                        const QUEUE: &'static [Item<'static>] = &[$($tail),+];
-                        self.queue = QUEUE;
-                        $head
-                    })
-                }
-                #[cfg(not(feature = "unstable-locales"))]
-                macro_rules! queue_from_slice {
-                    ($slice:expr) => {{
-                        self.queue = &$slice[1..];
-                        $slice[0].clone()
-                    }};
-                }
-
-                let item = match spec {
-                    'A' => fixed(Fixed::LongWeekdayName),
-                    'B' => fixed(Fixed::LongMonthName),
-                    'C' => num0(YearDiv100),
-                    'D' => {
-                        queue![num0(Month), Literal("/"), num0(Day), Literal("/"), num0(YearMod100)]
-                    }
-                    'F' => queue![num0(Year), Literal("-"), num0(Month), Literal("-"), num0(Day)],
-                    'G' => num0(IsoYear),
-                    'H' => num0(Hour),
-                    'I' => num0(Hour12),
-                    'M' => num0(Minute),
-                    'P' => fixed(Fixed::LowerAmPm),
-                    'R' => queue![num0(Hour), Literal(":"), num0(Minute)],
-                    'S' => num0(Second),
-                    'T' => {
-                        queue![num0(Hour), Literal(":"), num0(Minute), Literal(":"), num0(Second)]
-                    }
-                    'U' => num0(WeekFromSun),
-                    'V' => num0(IsoWeek),
-                    'W' => num0(WeekFromMon),
-                    #[cfg(not(feature = "unstable-locales"))]
-                    'X' => queue_from_slice!(T_FMT),
-                    #[cfg(feature = "unstable-locales")]
-                    'X' => self.switch_to_locale_str(locales::t_fmt, T_FMT),
-                    'Y' => num0(Year),
-                    'Z' => fixed(Fixed::TimezoneName),
-                    'a' => fixed(Fixed::ShortWeekdayName),
-                    'b' | 'h' => fixed(Fixed::ShortMonthName),
-                    #[cfg(not(feature = "unstable-locales"))]
-                    'c' => queue_from_slice!(D_T_FMT),
-                    #[cfg(feature = "unstable-locales")]
-                    'c' => self.switch_to_locale_str(locales::d_t_fmt, D_T_FMT),
-                    'd' => num0(Day),
-                    'e' => nums(Day),
-                    'f' => num0(Nanosecond),
-                    'g' => num0(IsoYearMod100),
-                    'j' => num0(Ordinal),
-                    'k' => nums(Hour),
-                    'l' => nums(Hour12),
-                    'm' => num0(Month),
-                    'n' => Space("\n"),
-                    'p' => fixed(Fixed::UpperAmPm),
-                    #[cfg(not(feature = "unstable-locales"))]
-                    'r' => queue_from_slice!(T_FMT_AMPM),
-                    #[cfg(feature = "unstable-locales")]
-                    'r' => {
-                        if self.locale.is_some()
-                            && locales::t_fmt_ampm(self.locale.unwrap()).is_empty()
-                        {
-                            // 12-hour clock not supported by this locale. Switch to 24-hour format.
-                            self.switch_to_locale_str(locales::t_fmt, T_FMT)
-                        } else {
-                            self.switch_to_locale_str(locales::t_fmt_ampm, T_FMT_AMPM)
-                        }
-                    }
-                    's' => num(Timestamp),
-                    't' => Space("\t"),
-                    'u' => num(WeekdayFromMon),
-                    'v' => {
-                        queue![
-                            nums(Day),
-                            Literal("-"),
-                            fixed(Fixed::ShortMonthName),
-                            Literal("-"),
-                            num0(Year)
-                        ]
-                    }
-                    'w' => num(NumDaysFromSun),
-                    #[cfg(not(feature = "unstable-locales"))]
-                    'x' => queue_from_slice!(D_FMT),
-                    #[cfg(feature = "unstable-locales")]
-                    'x' => self.switch_to_locale_str(locales::d_fmt, D_FMT),
-                    'y' => num0(YearMod100),
-                    'z' => {
-                        if is_alternate {
-                            internal_fixed(TimezoneOffsetPermissive)
-                        } else {
-                            fixed(Fixed::TimezoneOffset)
-                        }
+                let (rest, item) = match parse_specifier(&remainder.as_bytes()[1..]) {
+                    Ok(parsed) => parsed,
+                    // `parse_specifier` only consumes whole characters.
+                    Err((rest, _)) => {
+                        return Some((&remainder[remainder.len() - rest.len()..], Item::Error))
                     }
-                    '+' => fixed(Fixed::RFC3339),
-                    ':' => {
-                        if remainder.starts_with("::z") {
-                            remainder = &remainder[3..];
-                            fixed(Fixed::TimezoneOffsetTripleColon)
-                        } else if remainder.starts_with(":z") {
-                            remainder = &remainder[2..];
-                            fixed(Fixed::TimezoneOffsetDoubleColon)
-                        } else if remainder.starts_with('z') {
-                            remainder = &remainder[1..];
-                            fixed(Fixed::TimezoneOffsetColon)
-                        } else {
-                            Item::Error
-                        }
-                    }
-                    '.' => match next!() {
-                        '3' => match next!() {
-                            'f' => fixed(Fixed::Nanosecond3),
-                            _ => Item::Error,
-                        },
-                        '6' => match next!() {
-                            'f' => fixed(Fixed::Nanosecond6),
-                            _ => Item::Error,
-                        },
-                        '9' => match next!() {
-                            'f' => fixed(Fixed::Nanosecond9),
-                            _ => Item::Error,
-                        },
-                        'f' => fixed(Fixed::Nanosecond),
-                        _ => Item::Error,
-                    },
-                    '3' => match next!() {
-                        'f' => internal_fixed(Nanosecond3NoDot),
-                        _ => Item::Error,
-                    },
-                    '6' => match next!() {
-                        'f' => internal_fixed(Nanosecond6NoDot),
-                        _ => Item::Error,
-                    },
-                    '9' => match next!() {
-                        'f' => internal_fixed(Nanosecond9NoDot),
-                        _ => Item::Error,
-                    },
-                    '%' => Literal("%"),
-                    _ => Item::Error, // no such specifier
                 };
-
-                // Adjust `item` if we have any padding modifier.
-                // Not allowed on non-numeric items or on specifiers composed out of multiple
-                // formatting items.
-                if let Some(new_pad) = pad_override {
-                    match item {
-                        Item::Numeric(ref kind, _pad) if self.queue.is_empty() => {
-                            Some((remainder, Item::Numeric(kind.clone(), new_pad)))
-                        }
-                        _ => Some((remainder, Item::Error)),
-                    }
-                } else {
-                    Some((remainder, item))
-                }
+                remainder = &remainder[remainder.len() - rest.len()..];
+                let item = match item {
+                    ConstItem::Expand(composed) => self.expand(composed),
+                    item => item.into_item(),
+                };
+                Some((remainder, item))
             }
 
             // the next item is space
@@ -660,6 +478,38 @@ impl<'a> StrftimeItems<'a> {
         }
     }
 
+    /// Returns the first item of a composed specifier, and queues the others.
+    #[cfg(not(feature = "unstable-locales"))]
+    fn expand(&mut self, composed: Composed) -> Item<'a> {
+        let items = composed.items();
+        self.queue = &items[1..];
+        items[0].clone()
+    }
+
+    /// Returns the first item of a composed specifier, and queues the others or switches to the
+    /// localized format string.
+    #[cfg(feature = "unstable-locales")]
+    fn expand(&mut self, composed: Composed) -> Item<'a> {
+        let items = composed.items();
+        match composed {
+            Composed::LocaleDate => self.switch_to_locale_str(locales::d_fmt, items),
+            Composed::LocaleTime => self.switch_to_locale_str(locales::t_fmt, items),
+            Composed::LocaleDateTime => self.switch_to_locale_str(locales::d_t_fmt, items),
+            Composed::LocaleTime12 => {
+                if self.locale.is_some() && locales::t_fmt_ampm(self.locale.unwrap()).is_empty() {
+                    // 12-hour clock not supported by this locale. Switch to 24-hour format.
+                    self.switch_to_locale_str(locales::t_fmt, Composed::LocaleTime.items())
+                } else {
+                    self.switch_to_locale_str(locales::t_fmt_ampm, items)
+                }
+            }
+            _ => {
+                self.queue = &items[1..];
+                items[0].clone()
+            }
+        }
+    }
+
     #[cfg(feature = "unstable-locales")]
     fn switch_to_locale_str(
         &mut self,
@@ -678,6 +528,501 @@ impl<'a> StrftimeItems<'a> {
     }
 }
 
+/// Creates a `&'static [Item<'static>]` from a `strftime`-like format string at compile time.
+///
+/// The format string must be a constant expression, such as a string literal. It is parsed during
+/// compilation, so an invalid or unrecognized formatting specifier fails the build instead of
+/// returning [`Item::Error`] while formatting or parsing. There is no parsing left to do at
+/// runtime.
+///
+/// The result can be used with the `format_with_items` methods and with [`format::parse()`].
+/// Localized formatting specifiers such as `%x` use their non-localized form, like
+/// [`StrftimeItems::new`].
+///
+/// See the [`format::strftime` module](crate::format::strftime) for supported formatting
+/// specifiers.
+///
+/// [`format::parse()`]: crate::format::parse()
+///
+/// # Example
+///
+#[cfg_attr(not(any(feature = "alloc", feature = "std")), doc = "```ignore")]
+#[cfg_attr(any(feature = "alloc", feature = "std"), doc = "```rust")]
+/// use chrono::format::{parse, strftime_items, Item, Parsed, StrftimeItems};
+/// use chrono::NaiveDate;
+///
+/// const ITEMS: &[Item<'static>] = strftime_items!("%e %b %Y %k.%M");
+/// assert!(StrftimeItems::new("%e %b %Y %k.%M").eq(ITEMS.iter().cloned()));
+///
+/// let datetime = NaiveDate::from_ymd_opt(2023, 7, 11).unwrap().and_hms_opt(9, 0, 0).unwrap();
+/// assert_eq!(datetime.format_with_items(ITEMS.iter()).to_string(), "11 Jul 2023  9.00");
+///
+/// let mut parsed = Parsed::new();
+/// parse(&mut parsed, "11 Jul 2023  9.00", ITEMS.iter())?;
+/// assert_eq!(parsed.to_naive_datetime_with_offset(0)?, datetime);
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+///
+/// A typo in the format string is a compile error:
+///
+/// ```compile_fail
+/// const ITEMS: &[chrono::format::Item<'static>] = chrono::format::strftime_items!("%Y-%Q");
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strftime_items {
+    ($fmt:expr) => {{
+        const ITEMS: &[$crate::format::Item<'static>] = &$crate::format::strftime::__const_items::<
+            { $crate::format::strftime::__const_items_len($fmt) },
+        >($fmt);
+        ITEMS
+    }};
+}
+
+/// Returns the number of items in a format string, used by [`strftime_items!`].
+///
+/// # Panics
+///
+/// Panics if the format string contains an invalid or unrecognized formatting specifier.
+#[doc(hidden)]
+pub const fn __const_items_len(s: &'static str) -> usize {
+    let (mut remainder, mut expansion) = (s.as_bytes(), &[] as &'static [u8]);
+    let mut len = 0;
+    while let Some((r, e, _)) = const_next_item(remainder, expansion) {
+        remainder = r;
+        expansion = e;
+        len += 1;
+    }
+    len
+}
+
+/// Parses a format string into `N` items, used by [`strftime_items!`].
+///
+/// # Panics
+///
+/// Panics if the format string contains an invalid or unrecognized formatting specifier, or does
+/// not have `N` items.
+#[doc(hidden)]
+pub const fn __const_items<const N: usize>(s: &'static str) -> [Item<'static>; N] {
+    // `Item` may need to be dropped, which is not possible in a `const fn`: assigning to an
+    // element of a `[Item; N]` would drop the old value, and `mem::replace` is not `const` in our
+    // MSRV. So we fill an array of `ManuallyDrop<Item>` instead and convert it at the end.
+    const ERROR: ManuallyDrop<Item<'static>> = ManuallyDrop::new(Item::Error);
+    let mut items = [ERROR; N];
+    let (mut remainder, mut expansion) = (s.as_bytes(), &[] as &'static [u8]);
+    let mut i = 0;
+    while let Some((r, e, item)) = const_next_item(remainder, expansion) {
+        if i == N {
+            panic!("wrong number of items in format string");
+        }
+        items[i] = ManuallyDrop::new(item.into_item());
+        remainder = r;
+        expansion = e;
+        i += 1;
+    }
+    if i != N {
+        panic!("wrong number of items in format string");
+    }
+    // SAFETY: `ManuallyDrop<T>` is `repr(transparent)`, so `[ManuallyDrop<Item>; N]` and
+    // `[Item; N]` have the same size, alignment and validity, and every element was initialized
+    // from a valid `Item` above. Nothing is dropped twice: the `from` array is never dropped, and
+    // its items are only owned by the returned array.
+    ManuallyDrop::into_inner(unsafe { ItemsTransmute { from: ManuallyDrop::new(items) }.to })
+}
+
+/// Converts `[ManuallyDrop<Item>; N]` into `[Item; N]` in a `const fn`.
+///
+/// `mem::transmute` can't be used because the size of the arrays depends on `N`, and
+/// `MaybeUninit::array_assume_init` is unstable.
+union ItemsTransmute<const N: usize> {
+    from: ManuallyDrop<[ManuallyDrop<Item<'static>>; N]>,
+    to: ManuallyDrop<[Item<'static>; N]>,
+}
+
+/// A formatting item that is known during constant evaluation.
+///
+/// Unlike `Item` this never needs to be dropped, so it can be freely moved around in a
+/// `const fn`.
+enum ConstItem {
+    Literal(&'static str),
+    Space(&'static str),
+    Numeric(Numeric, Pad),
+    Fixed(Fixed),
+    /// A specifier composed of multiple items.
+    Expand(Composed),
+}
+
+impl ConstItem {
+    const fn into_item(self) -> Item<'static> {
+        match self {
+            ConstItem::Literal(s) => Item::Literal(s),
+            ConstItem::Space(s) => Item::Space(s),
+            ConstItem::Numeric(numeric, pad) => Item::Numeric(numeric, pad),
+            ConstItem::Fixed(fixed) => Item::Fixed(fixed),
+            ConstItem::Expand(_) => Item::Error,
+        }
+    }
+}
+
+/// Returns the remainder of the format string, the remainder of the expansion of a composed
+/// specifier and the next item, or `None` at the end of the format string.
+const fn const_next_item(
+    remainder: &'static [u8],
+    expansion: &'static [u8],
+) -> Option<(&'static [u8], &'static [u8], ConstItem)> {
+    if !expansion.is_empty() {
+        // Expansions don't contain composed specifiers themselves.
+        let (expansion, item) = const_parse_next_item(expansion);
+        return Some((remainder, expansion, item));
+    }
+    if remainder.is_empty() {
+        return None;
+    }
+    let (remainder, item) = const_parse_next_item(remainder);
+    match item {
+        ConstItem::Expand(composed) => {
+            let (expansion, item) = const_parse_next_item(composed.format_str().as_bytes());
+            Some((remainder, expansion, item))
+        }
+        item => Some((remainder, &[], item)),
+    }
+}
+
+/// A `const` version of `StrftimeItems::parse_next_item`, without support for locales.
+///
+/// `s` must not be empty.
+const fn const_parse_next_item(s: &'static [u8]) -> (&'static [u8], ConstItem) {
+    if let [b'%', spec @ ..] = s {
+        return const_parse_specifier(spec);
+    }
+
+    // A run of whitespace, or a literal up to the next whitespace or specifier.
+    let is_space = whitespace_len(s, 0) > 0;
+    let mut i = 0;
+    while i < s.len() {
+        let len = whitespace_len(s, i);
+        if (len > 0) != is_space || (!is_space && s[i] == b'%') {
+            break;
+        }
+        i += if len > 0 { len } else { utf8_len(s[i]) };
+    }
+    let (head, tail) = split_bytes(s, i);
+    // SAFETY: `s` is valid UTF-8 and `i` is at a character boundary.
+    let head = unsafe { core::str::from_utf8_unchecked(head) };
+    (tail, if is_space { ConstItem::Space(head) } else { ConstItem::Literal(head) })
+}
+
+/// `parse_specifier` for [`strftime_items!`](crate::format::strftime_items), which fails the
+/// build on an invalid specifier.
+const fn const_parse_specifier(s: &'static [u8]) -> (&'static [u8], ConstItem) {
+    match parse_specifier(s) {
+        Ok(parsed) => parsed,
+        Err((_, err)) => err.panic(),
+    }
+}
+
+/// Parses the formatting specifier at the start of `s`, which follows a `%`.
+///
+/// This is the table of specifiers shared by [`StrftimeItems`] and [`strftime_items!`]. Returns
+/// the remainder of `s` and the item, or the remainder and why the specifier is invalid. Only
+/// whole characters are consumed.
+///
+/// [`strftime_items!`]: crate::format::strftime_items
+const fn parse_specifier(s: &[u8]) -> SpecifierResult<'_> {
+    use ConstItem::{Expand, Fixed as F, Literal, Numeric as N, Space};
+    use InternalInternal::*;
+    use Numeric::*;
+
+    let (spec, mut s) = match next_char(s) {
+        Some(next) => next,
+        None => return Err((s, SpecifierError::PrematureEnd)),
+    };
+    let pad_override = match spec {
+        b'-' => Some(Pad::None),
+        b'0' => Some(Pad::Zero),
+        b'_' => Some(Pad::Space),
+        _ => None,
+    };
+    let is_alternate = spec == b'#';
+    let spec = if pad_override.is_some() || is_alternate {
+        match next_char(s) {
+            Some((spec, rest)) => {
+                s = rest;
+                spec
+            }
+            None => return Err((s, SpecifierError::PrematureEnd)),
+        }
+    } else {
+        spec
+    };
+    if is_alternate && spec != b'z' {
+        return Err((s, SpecifierError::UnsupportedAlternate));
+    }
+
+    let item = match spec {
+        b'A' => F(Fixed::LongWeekdayName),
+        b'B' => F(Fixed::LongMonthName),
+        b'C' => N(YearDiv100, Pad::Zero),
+        b'D' => Expand(Composed::Date),
+        b'F' => Expand(Composed::IsoDate),
+        b'G' => N(IsoYear, Pad::Zero),
+        b'H' => N(Hour, Pad::Zero),
+        b'I' => N(Hour12, Pad::Zero),
+        b'J' => N(JulianDay, Pad::None),
+        b'M' => N(Minute, Pad::Zero),
+        b'P' => F(Fixed::LowerAmPm),
+        b'R' => Expand(Composed::HourMinute),
+        b'S' => N(Second, Pad::Zero),
+        b'T' => Expand(Composed::Time),
+        b'U' => N(WeekFromSun, Pad::Zero),
+        b'V' => N(IsoWeek, Pad::Zero),
+        b'W' => N(WeekFromMon, Pad::Zero),
+        b'X' => Expand(Composed::LocaleTime),
+        b'Y' => N(Year, Pad::Zero),
+        b'Z' => F(Fixed::TimezoneName),
+        b'a' => F(Fixed::ShortWeekdayName),
+        b'b' | b'h' => F(Fixed::ShortMonthName),
+        b'c' => Expand(Composed::LocaleDateTime),
+        b'd' => N(Day, Pad::Zero),
+        b'e' => N(Day, Pad::Space),
+        b'f' => N(Nanosecond, Pad::Zero),
+        b'g' => N(IsoYearMod100, Pad::Zero),
+        b'j' => N(Ordinal, Pad::Zero),
+        b'k' => N(Hour, Pad::Space),
+        b'l' => N(Hour12, Pad::Space),
+        b'm' => N(Month, Pad::Zero),
+        b'n' => Space("\n"),
+        b'p' => F(Fixed::UpperAmPm),
+        b'r' => Expand(Composed::LocaleTime12),
+        b's' => N(Timestamp, Pad::None),
+        b't' => Space("\t"),
+        b'u' => N(WeekdayFromMon, Pad::None),
+        b'v' => Expand(Composed::DayMonthYear),
+        b'w' => N(NumDaysFromSun, Pad::None),
+        b'x' => Expand(Composed::LocaleDate),
+        b'y' => N(YearMod100, Pad::Zero),
+        b'z' if is_alternate => F(Fixed::Internal(InternalFixed { val: TimezoneOffsetPermissive })),
+        b'z' => F(Fixed::TimezoneOffset),
+        b'+' => F(Fixed::RFC3339),
+        b':' => match s {
+            [b':', b':', b'z', rest @ ..] => {
+                s = rest;
+                F(Fixed::TimezoneOffsetTripleColon)
+            }
+            [b':', b'z', rest @ ..] => {
+                s = rest;
+                F(Fixed::TimezoneOffsetDoubleColon)
+            }
+            [b'z', rest @ ..] => {
+                s = rest;
+                F(Fixed::TimezoneOffsetColon)
+            }
+            [b'Z', rest @ ..] => {
+                s = rest;
+                F(Fixed::TimezoneId)
+            }
+            _ => return Err((s, SpecifierError::Unrecognized)),
+        },
+        b'.' => match s {
+            [b'3', b'f', rest @ ..] => {
+                s = rest;
+                F(Fixed::Nanosecond3)
+            }
+            [b'6', b'f', rest @ ..] => {
+                s = rest;
+                F(Fixed::Nanosecond6)
+            }
+            [b'9', b'f', rest @ ..] => {
+                s = rest;
+                F(Fixed::Nanosecond9)
+            }
+            [b'f', rest @ ..] => {
+                s = rest;
+                F(Fixed::Nanosecond)
+            }
+            _ => return Err((s, SpecifierError::Unrecognized)),
+        },
+        b'3' | b'6' | b'9' => match s {
+            [b'f', rest @ ..] => {
+                s = rest;
+                let val = match spec {
+                    b'3' => Nanosecond3NoDot,
+                    b'6' => Nanosecond6NoDot,
+                    _ => Nanosecond9NoDot,
+                };
+                F(Fixed::Internal(InternalFixed { val }))
+            }
+            _ => return Err((s, SpecifierError::Unrecognized)),
+        },
+        b'%' => Literal("%"),
+        _ => return Err((s, SpecifierError::Unrecognized)),
+    };
+
+    // Padding modifiers are only allowed on numeric items.
+    match (pad_override, item) {
+        (None, item) => Ok((s, item)),
+        (Some(pad), N(numeric, _)) => Ok((s, N(numeric, pad))),
+        (Some(_), _) => Err((s, SpecifierError::PaddingOnNonNumeric)),
+    }
+}
+
+/// The remainder of the format string, with the item or why the specifier is invalid.
+type SpecifierResult<'a> = Result<(&'a [u8], ConstItem), (&'a [u8], SpecifierError)>;
+
+/// Returns the first byte of `s` and the remainder after the character it starts.
+const fn next_char(s: &[u8]) -> Option<(u8, &[u8])> {
+    let (first, mut rest) = match s {
+        [first, rest @ ..] => (*first, rest),
+        [] => return None,
+    };
+    // Skip the continuation bytes of a multi-byte character.
+    while let [b, tail @ ..] = rest {
+        if *b & 0xc0 != 0x80 {
+            break;
+        }
+        rest = tail;
+    }
+    Some((first, rest))
+}
+
+/// Why [`parse_specifier`] rejected a formatting specifier.
+#[derive(Clone, Copy)]
+enum SpecifierError {
+    PrematureEnd,
+    UnsupportedAlternate,
+    Unrecognized,
+    PaddingOnNonNumeric,
+}
+
+impl SpecifierError {
+    /// Fails the constant evaluation of [`strftime_items!`](crate::format::strftime_items).
+    const fn panic(self) -> ! {
+        match self {
+            SpecifierError::PrematureEnd => panic!("premature end of format string"),
+            SpecifierError::UnsupportedAlternate => {
+                panic!("unsupported alternate form of formatting specifier")
+            }
+            SpecifierError::Unrecognized => {
+                panic!("unrecognized formatting specifier in format string")
+            }
+            SpecifierError::PaddingOnNonNumeric => {
+                panic!("padding modifier on a non-numeric formatting specifier")
+            }
+        }
+    }
+}
+
+/// A specifier composed of multiple formatting items.
+#[derive(Clone, Copy)]
+enum Composed {
+    /// `%D`
+    Date,
+    /// `%F`
+    IsoDate,
+    /// `%R`
+    HourMinute,
+    /// `%T`
+    Time,
+    /// `%v`
+    DayMonthYear,
+    /// `%x`, the date in the format of the locale
+    LocaleDate,
+    /// `%X`, the time in the format of the locale
+    LocaleTime,
+    /// `%c`, the date and time in the format of the locale
+    LocaleDateTime,
+    /// `%r`, the 12-hour clock time in the format of the locale
+    LocaleTime12,
+}
+
+impl Composed {
+    /// The equivalent format string, the non-localized form for the locale specifiers.
+    const fn format_str(self) -> &'static str {
+        match self {
+            Composed::Date | Composed::LocaleDate => "%m/%d/%y",
+            Composed::IsoDate => "%Y-%m-%d",
+            Composed::HourMinute => "%H:%M",
+            Composed::Time | Composed::LocaleTime => "%H:%M:%S",
+            Composed::DayMonthYear => "%e-%b-%Y",
+            Composed::LocaleDateTime => "%a %b %e %H:%M:%S %Y",
+            Composed::LocaleTime12 => "%I:%M:%S %p",
+        }
+    }
+
+    /// The formatting items of [`format_str()`](Composed::format_str), parsed at compile time.
+    fn items(self) -> &'static [Item<'static>] {
+        macro_rules! items {
+            ($composed:expr) => {{
+                const FMT: &str = $composed.format_str();
+                const ITEMS: &[Item<'static>] = &__const_items::<{ __const_items_len(FMT) }>(FMT);
+                ITEMS
+            }};
+        }
+        match self {
+            Composed::Date | Composed::LocaleDate => items!(Composed::Date),
+            Composed::IsoDate => items!(Composed::IsoDate),
+            Composed::HourMinute => items!(Composed::HourMinute),
+            Composed::Time | Composed::LocaleTime => items!(Composed::Time),
+            Composed::DayMonthYear => items!(Composed::DayMonthYear),
+            Composed::LocaleDateTime => items!(Composed::LocaleDateTime),
+            Composed::LocaleTime12 => items!(Composed::LocaleTime12),
+        }
+    }
+}
+
+/// Returns the length of the character at `s[i]` if it is whitespace, or 0 otherwise.
+///
+/// Matches the characters of `char::is_whitespace`.
+const fn whitespace_len(s: &[u8], i: usize) -> usize {
+    let len = utf8_len(s[i]);
+    let c = match len {
+        1 => s[i] as u32,
+        2 => (s[i] as u32 & 0x1f) << 6 | (s[i + 1] as u32 & 0x3f),
+        3 => (s[i] as u32 & 0x0f) << 12 | (s[i + 1] as u32 & 0x3f) << 6 | (s[i + 2] as u32 & 0x3f),
+        _ => return 0, // all whitespace is in the Basic Multilingual Plane
+    };
+    match c {
+        0x09..=0x0d
+        | 0x20
+        | 0x85
+        | 0xa0
+        | 0x1680
+        | 0x2000..=0x200a
+        | 0x2028
+        | 0x2029
+        | 0x202f
+        | 0x205f
+        | 0x3000 => len,
+        _ => 0,
+    }
+}
+
+/// Returns the length of the UTF-8 encoded character starting with byte `b`.
+const fn utf8_len(b: u8) -> usize {
+    match b {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+/// `<[u8]>::split_at`, which is not yet `const` in our MSRV.
+const fn split_bytes(s: &'static [u8], mid: usize) -> (&'static [u8], &'static [u8]) {
+    let (mut head, mut tail) = (s, s);
+    while head.len() > mid {
+        if let [rest @ .., _] = head {
+            head = rest;
+        }
+    }
+    while tail.len() > s.len() - mid {
+        if let [_, rest @ ..] = tail {
+            tail = rest;
+        }
+    }
+    (head, tail)
+}
+
 #[cfg(test)]
 mod tests {
     use super::StrftimeItems;
@@ -821,7 +1166,7 @@ mod tests {
         assert_eq!(parse_and_collect("bar%42"), [Item::Error]);
         assert_eq!(parse_and_collect("quux% +"), [Item::Error]);
         assert_eq!(parse_and_collect("%.Z"), [Item::Error]);
-        assert_eq!(parse_and_collect("%:Z"), [Item::Error]);
+        assert_eq!(parse_and_collect("%:Y"), [Item::Error]);
         assert_eq!(parse_and_collect("%-Z"), [Item::Error]);
         assert_eq!(parse_and_collect("%0Z"), [Item::Error]);
         assert_eq!(parse_and_collect("%_Z"), [Item::Error]);
@@ -838,6 +1183,7 @@ mod tests {
         assert_eq!(parse_and_collect("%z"), [fixed(Fixed::TimezoneOffset)]);
         assert_eq!(parse_and_collect("%:z"), [fixed(Fixed::TimezoneOffsetColon)]);
         assert_eq!(parse_and_collect("%Z"), [fixed(Fixed::TimezoneName)]);
+        assert_eq!(parse_and_collect("%:Z"), [fixed(Fixed::TimezoneId)]);
         assert_eq!(parse_and_collect("%ZZZZ"), [fixed(Fixed::TimezoneName), Literal("ZZZ")]);
         assert_eq!(parse_and_collect("%Z😽"), [fixed(Fixed::TimezoneName), Literal("😽")]);
         assert_eq!(
@@ -881,6 +1227,7 @@ mod tests {
         assert_eq!(dt.format("%g").to_string(), "01");
         assert_eq!(dt.format("%V").to_string(), "27");
         assert_eq!(dt.format("%j").to_string(), "189");
+        assert_eq!(dt.format("%J").to_string(), "2452099");
         assert_eq!(dt.format("%D").to_string(), "07/08/01");
         assert_eq!(dt.format("%x").to_string(), "07/08/01");
         assert_eq!(dt.format("%F").to_string(), "2001-07-08");
@@ -1132,4 +1479,85 @@ mod tests {
         let dt = Utc.with_ymd_and_hms(2014, 5, 7, 12, 34, 56).unwrap();
         assert_eq!(&dt.format_with_items(fmt_items.iter()).to_string(), "2014-05-07T12:34:56+0000");
     }
+
+    #[test]
+    fn test_strftime_items_macro() {
+        macro_rules! check {
+            ($fmt:literal) => {
+                assert!(
+                    StrftimeItems::new($fmt)
+                        .eq(crate::format::strftime_items!($fmt).iter().cloned()),
+                    "{:?}",
+                    $fmt
+                );
+            };
+        }
+
+        check!("");
+        check!("%Y-%m-%dT%H:%M:%S%.f%:z");
+        check!("%A %a %B %b %h %C %G %g %U %V %W %j %u %w %s %Z %P %p %e %k %l %I %f %J");
+        check!("%D|%F|%R|%T|%c|%r|%x|%X|%v|%+");
+        check!("%-d %0e %_m %-H %_Y");
+        check!("%z %:z %::z %:::z %#z %:Z");
+        check!("%.f %.3f %.6f %.9f %3f %6f %9f");
+        check!("%t%n%%");
+        check!("  \t %Y\u{a0}\u{3000}年 %m月%d日 \u{2028}");
+        check!("Ünïcödé %H:%M 😀");
+    }
+
+    #[test]
+    fn test_strftime_items_macro_parity() {
+        use super::const_next_item;
+        use std::panic::catch_unwind;
+
+        // The items `strftime_items!` generates, or `None` if it fails the build.
+        fn const_items(fmt: &'static str) -> Option<Vec<Item<'static>>> {
+            catch_unwind(|| {
+                let (mut remainder, mut expansion) = (fmt.as_bytes(), &[] as &'static [u8]);
+                let mut items = Vec::new();
+                while let Some((r, e, item)) = const_next_item(remainder, expansion) {
+                    items.push(item.into_item());
+                    remainder = r;
+                    expansion = e;
+                }
+                items
+            })
+            .ok()
+        }
+        fn runtime_items(fmt: &str) -> Option<Vec<Item<'_>>> {
+            StrftimeItems::new(fmt).map(|item| Some(item).filter(|i| *i != Item::Error)).collect()
+        }
+
+        // every specifier with every modifier, and the characters that can follow `%:`, `%.` or
+        // `%3` and the like
+        for spec in (b' '..=b'~').map(char::from).chain(['ü']) {
+            for modifier in ["", "-", "0", "_", "#"] {
+                for suffix in ["", "f", "z", "Z", ":z", "::z", "3f", "6f", "9f"] {
+                    let fmt = format!("%{}{}{}", modifier, spec, suffix);
+                    let fmt: &'static str = Box::leak(fmt.into_boxed_str());
+                    assert_eq!(const_items(fmt), runtime_items(fmt), "{:?}", fmt);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strftime_items_macro_formatting() {
+        use crate::format::{parse, Parsed};
+
+        const ITEMS: &[Item<'static>] =
+            crate::format::strftime_items!("%a %d %b %Y %-H:%M:%S%.3f %:z");
+        let dt = FixedOffset::east_opt(34200)
+            .unwrap()
+            .with_ymd_and_hms(2001, 7, 8, 0, 34, 59)
+            .unwrap()
+            .with_nanosecond(26_490_708)
+            .unwrap();
+        let formatted = dt.format_with_items(ITEMS.iter()).to_string();
+        assert_eq!(formatted, "Sun 08 Jul 2001 0:34:59.026 +09:30");
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, &formatted, ITEMS.iter()).unwrap();
+        assert_eq!(parsed.to_datetime().unwrap(), dt.with_nanosecond(26_000_000).unwrap());
+    }
 }
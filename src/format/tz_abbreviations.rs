@@ -0,0 +1,242 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Time zone abbreviations, used to parse the `%Z` specifier.
+
+use super::{ParseResult, NOT_ENOUGH};
+
+/// How [`TzAbbreviations`] handles an abbreviation that is listed with different offsets.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum AbbreviationAmbiguity {
+    /// Use the offset of the first matching entry in the table.
+    First,
+    /// Ignore the abbreviation, as if it was not in the table.
+    ///
+    /// The offset then has to come from another field, such as `%z`.
+    Ignore,
+    /// Fail with a [`ParseErrorKind::NotEnough`](super::ParseErrorKind::NotEnough) error.
+    Reject,
+}
+
+/// A table of time zone abbreviations and their offsets from UTC, used to parse `%Z`.
+///
+/// Abbreviations are matched case-insensitively. An abbreviation that is not in the table is
+/// skipped without setting an offset. Many abbreviations are ambiguous, for example `CST` is
+/// used for both Central Standard Time (North America) and China Standard Time. An abbreviation
+/// can be listed multiple times with different offsets, and the [`AbbreviationAmbiguity`] of the
+/// table decides what happens when such an abbreviation is parsed.
+///
+/// The table is set on [`Parsed`](super::Parsed) with
+/// [`set_tz_abbreviations()`](super::Parsed::set_tz_abbreviations). The default is
+/// [`TzAbbreviations::COMMON`].
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse, AbbreviationAmbiguity, Parsed, StrftimeItems, TzAbbreviations};
+///
+/// let parse_offset = |s, abbreviations| {
+///     let mut parsed = Parsed::new();
+///     parsed.set_tz_abbreviations(abbreviations);
+///     parse(&mut parsed, s, StrftimeItems::new("%H:%M %Z")).map(|_| parsed.offset())
+/// };
+///
+/// let common = TzAbbreviations::COMMON;
+/// assert_eq!(parse_offset("10:00 EST", common), Ok(Some(-5 * 3600)));
+/// assert_eq!(parse_offset("10:00 cest", common), Ok(Some(2 * 3600)));
+/// assert_eq!(parse_offset("10:00 XYZ", common), Ok(None));
+///
+/// // `CST` is ambiguous
+/// assert_eq!(parse_offset("10:00 CST", common), Ok(None));
+/// let first = common.with_ambiguity(AbbreviationAmbiguity::First);
+/// assert_eq!(parse_offset("10:00 CST", first), Ok(Some(-6 * 3600)));
+///
+/// // a custom table
+/// static CHINA: &[(&str, i32)] = &[("CST", 8 * 3600), ("HKT", 8 * 3600)];
+/// assert_eq!(parse_offset("10:00 CST", TzAbbreviations::new(CHINA)), Ok(Some(8 * 3600)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct TzAbbreviations {
+    entries: &'static [(&'static str, i32)],
+    ambiguity: AbbreviationAmbiguity,
+}
+
+impl TzAbbreviations {
+    /// A table with common abbreviations in North America, Europe, Asia, Oceania, Africa and
+    /// South America.
+    ///
+    /// The ambiguous abbreviations `AST`, `BST`, `CST` and `IST` are listed with the offset of
+    /// their most common meaning first. They are ignored with the default
+    /// [`AbbreviationAmbiguity::Ignore`] policy.
+    pub const COMMON: TzAbbreviations = TzAbbreviations::new(COMMON_ABBREVIATIONS);
+
+    /// An empty table, all abbreviations are skipped without setting an offset.
+    pub const NONE: TzAbbreviations = TzAbbreviations::new(&[]);
+
+    /// Makes a new table from a list of abbreviations and offsets from UTC in seconds.
+    ///
+    /// Ambiguous abbreviations are ignored, see [`with_ambiguity()`] to change this.
+    ///
+    /// [`with_ambiguity()`]: TzAbbreviations::with_ambiguity
+    #[must_use]
+    pub const fn new(entries: &'static [(&'static str, i32)]) -> TzAbbreviations {
+        TzAbbreviations { entries, ambiguity: AbbreviationAmbiguity::Ignore }
+    }
+
+    /// Returns the table with a different policy for ambiguous abbreviations.
+    #[must_use]
+    pub const fn with_ambiguity(self, ambiguity: AbbreviationAmbiguity) -> TzAbbreviations {
+        TzAbbreviations { ambiguity, ..self }
+    }
+
+    /// Returns the abbreviations and their offsets from UTC in seconds.
+    #[must_use]
+    pub const fn entries(&self) -> &'static [(&'static str, i32)] {
+        self.entries
+    }
+
+    /// Returns the policy for ambiguous abbreviations.
+    #[must_use]
+    pub const fn ambiguity(&self) -> AbbreviationAmbiguity {
+        self.ambiguity
+    }
+
+    /// Returns the offset from UTC in seconds for `abbreviation`, ignoring case.
+    ///
+    /// Returns `Ok(None)` if the abbreviation is not in the table, or if it is ambiguous and
+    /// ambiguous abbreviations are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NOT_ENOUGH` if the abbreviation is ambiguous and ambiguous abbreviations are
+    /// rejected.
+    pub fn offset(&self, abbreviation: &str) -> ParseResult<Option<i32>> {
+        let mut offsets = self
+            .entries
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(abbreviation))
+            .map(|&(_, offset)| offset);
+        let first = match offsets.next() {
+            Some(offset) => offset,
+            None => return Ok(None),
+        };
+        if offsets.all(|offset| offset == first) {
+            return Ok(Some(first));
+        }
+        match self.ambiguity {
+            AbbreviationAmbiguity::First => Ok(Some(first)),
+            AbbreviationAmbiguity::Ignore => Ok(None),
+            AbbreviationAmbiguity::Reject => Err(NOT_ENOUGH),
+        }
+    }
+}
+
+impl Default for TzAbbreviations {
+    fn default() -> Self {
+        TzAbbreviations::COMMON
+    }
+}
+
+const HOUR: i32 = 3600;
+const MINUTE: i32 = 60;
+
+const COMMON_ABBREVIATIONS: &[(&str, i32)] = &[
+    ("UTC", 0),
+    ("UT", 0),
+    ("GMT", 0),
+    // North America
+    ("EST", -5 * HOUR),
+    ("EDT", -4 * HOUR),
+    ("CST", -6 * HOUR),
+    ("CDT", -5 * HOUR),
+    ("MST", -7 * HOUR),
+    ("MDT", -6 * HOUR),
+    ("PST", -8 * HOUR),
+    ("PDT", -7 * HOUR),
+    ("AKST", -9 * HOUR),
+    ("AKDT", -8 * HOUR),
+    ("HST", -10 * HOUR),
+    ("AST", -4 * HOUR),
+    ("ADT", -3 * HOUR),
+    ("NST", -3 * HOUR - 30 * MINUTE),
+    ("NDT", -2 * HOUR - 30 * MINUTE),
+    // Europe
+    ("WET", 0),
+    ("WEST", HOUR),
+    ("BST", HOUR),
+    ("CET", HOUR),
+    ("CEST", 2 * HOUR),
+    ("MET", HOUR),
+    ("MEST", 2 * HOUR),
+    ("EET", 2 * HOUR),
+    ("EEST", 3 * HOUR),
+    ("MSK", 3 * HOUR),
+    // Asia
+    ("IST", 5 * HOUR + 30 * MINUTE),
+    ("PKT", 5 * HOUR),
+    ("WIB", 7 * HOUR),
+    ("HKT", 8 * HOUR),
+    ("SGT", 8 * HOUR),
+    ("JST", 9 * HOUR),
+    ("KST", 9 * HOUR),
+    ("IDT", 3 * HOUR),
+    // Oceania
+    ("AWST", 8 * HOUR),
+    ("ACST", 9 * HOUR + 30 * MINUTE),
+    ("ACDT", 10 * HOUR + 30 * MINUTE),
+    ("AEST", 10 * HOUR),
+    ("AEDT", 11 * HOUR),
+    ("NZST", 12 * HOUR),
+    ("NZDT", 13 * HOUR),
+    // Africa
+    ("WAT", HOUR),
+    ("CAT", 2 * HOUR),
+    ("EAT", 3 * HOUR),
+    ("SAST", 2 * HOUR),
+    // South America
+    ("BRT", -3 * HOUR),
+    ("ART", -3 * HOUR),
+    // less common meanings of ambiguous abbreviations
+    ("AST", 3 * HOUR), // Arabia Standard Time
+    ("BST", 6 * HOUR), // Bangladesh Standard Time
+    ("CST", 8 * HOUR), // China Standard Time
+    ("IST", HOUR),     // Irish Standard Time
+    ("IST", 2 * HOUR), // Israel Standard Time
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{AbbreviationAmbiguity, TzAbbreviations};
+    use crate::format::NOT_ENOUGH;
+
+    #[test]
+    fn test_tz_abbreviations_offset() {
+        let common = TzAbbreviations::default();
+        assert_eq!(common, TzAbbreviations::COMMON);
+        assert_eq!(common.offset("UTC"), Ok(Some(0)));
+        assert_eq!(common.offset("Jst"), Ok(Some(9 * 3600)));
+        assert_eq!(common.offset("NST"), Ok(Some(-(3 * 3600 + 1800))));
+        assert_eq!(common.offset("ACST"), Ok(Some(9 * 3600 + 1800)));
+        assert_eq!(common.offset("CES"), Ok(None));
+        assert_eq!(common.offset(""), Ok(None));
+        assert_eq!(TzAbbreviations::NONE.offset("UTC"), Ok(None));
+
+        for abbreviation in ["AST", "BST", "CST", "IST"] {
+            assert_eq!(common.offset(abbreviation), Ok(None));
+            let reject = common.with_ambiguity(AbbreviationAmbiguity::Reject);
+            assert_eq!(reject.offset(abbreviation), Err(NOT_ENOUGH));
+        }
+        let first = common.with_ambiguity(AbbreviationAmbiguity::First);
+        assert_eq!(first.ambiguity(), AbbreviationAmbiguity::First);
+        assert_eq!(first.offset("CST"), Ok(Some(-6 * 3600)));
+        assert_eq!(first.offset("IST"), Ok(Some(5 * 3600 + 1800)));
+
+        // duplicate entries with the same offset are not ambiguous
+        static DUPLICATES: &[(&str, i32)] = &[("Z", 0), ("z", 0)];
+        let duplicates =
+            TzAbbreviations::new(DUPLICATES).with_ambiguity(AbbreviationAmbiguity::Reject);
+        assert_eq!(duplicates.offset("Z"), Ok(Some(0)));
+        assert_eq!(duplicates.entries().len(), 2);
+    }
+}
@@ -0,0 +1,730 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Time intervals and repeating time intervals as described by ISO 8601.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use crate::format::iso8601::{format_iso8601, parse_iso8601, Iso8601Format};
+use crate::format::{ParseError, ParseErrorKind, ParseResult};
+use crate::format::{IMPOSSIBLE, INVALID, NOT_ENOUGH, OUT_OF_RANGE, TOO_SHORT};
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{FixedOffset, LocalResult, TimeZone};
+use crate::{CalendarArithmetic, DateTime, DateTimeRange, Disambiguation, Period, TimeDelta};
+
+/// How the ISO 8601 representation of a [`DateTimeInterval`] describes the interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalForm {
+    /// A start and an end, such as `2024-01-01T00:00Z/2024-01-02T00:00Z`.
+    StartEnd,
+    /// A start and a duration, such as `2024-01-01T00:00Z/P1D`.
+    StartDuration(Period),
+    /// A duration and an end, such as `P1D/2024-01-02T00:00Z`.
+    DurationEnd(Period),
+}
+
+/// A time interval from a start up to an end instant in a time zone.
+///
+/// The interval is half-open: it contains its start, but not its end. The end is never before the
+/// start.
+///
+/// An interval can be given by its start and end, or by one of them and a [`Period`]. The
+/// `Period` is applied to the local date and time as with `DateTime + Period`, so `P1D` is one
+/// calendar day even across a daylight saving time transition. The interval is a
+/// [`DateTimeRange`] that remembers its [`IntervalForm`], which is used to format it again.
+///
+/// # Parsing and formatting
+///
+/// Intervals are written in ISO 8601 as two parts separated by `/` (or `--`), where each part is
+/// either a date and time as accepted by [`parse_iso8601`] or a duration as accepted by
+/// `Period::from_str`. The `Display` implementation writes the same form with extended date and
+/// times. A duration on its own, such as `P1D`, is also an interval in ISO 8601, but it has no
+/// position on the time line and is not accepted here; parse it as a [`Period`] instead.
+///
+/// An end point without an offset from UTC, or a date without a time (which means the start of the
+/// day), can be parsed with [`DateTimeInterval::parse_with_timezone`] to interpret it in a time
+/// zone. `FromStr` for `DateTimeInterval<FixedOffset>` uses the offset of the other end point
+/// instead.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DateTimeInterval, FixedOffset, TimeZone, Utc};
+///
+/// let interval: DateTimeInterval<FixedOffset> = "2024-01-01T00:00Z/P1D".parse()?;
+/// assert_eq!(interval.end(), &Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap());
+/// assert!(interval.contains(&Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+/// assert!(!interval.contains(interval.end()));
+/// assert_eq!(interval.to_string(), "2024-01-01T00:00:00+00:00/P1D");
+///
+/// let interval = DateTimeInterval::parse_with_timezone("P1W/2024-02-01", &Utc)?;
+/// assert_eq!(interval.start(), &Utc.with_ymd_and_hms(2024, 1, 25, 0, 0, 0).unwrap());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+///
+/// [`parse_iso8601`]: crate::format::iso8601::parse_iso8601
+#[derive(Clone)]
+pub struct DateTimeInterval<Tz: TimeZone> {
+    range: DateTimeRange<Tz>,
+    form: IntervalForm,
+}
+
+impl<Tz: TimeZone> DateTimeInterval<Tz> {
+    /// Makes a new interval from `start` up to `end`.
+    ///
+    /// Returns `None` if `end` is before `start`.
+    #[must_use]
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Option<DateTimeInterval<Tz>> {
+        let range = DateTimeRange::new(start, end)?;
+        Some(DateTimeInterval { range, form: IntervalForm::StartEnd })
+    }
+
+    /// Makes a new interval from `start` with the given duration.
+    ///
+    /// Returns `None` if the end would be out of range, or before `start`.
+    #[must_use]
+    pub fn from_start(start: DateTime<Tz>, duration: Period) -> Option<DateTimeInterval<Tz>> {
+        let end = start.clone().checked_add_span(duration, WALL_CLOCK)?;
+        let range = DateTimeRange::new(start, end)?;
+        Some(DateTimeInterval { range, form: IntervalForm::StartDuration(duration) })
+    }
+
+    /// Makes a new interval with the given duration up to `end`.
+    ///
+    /// Returns `None` if the start would be out of range, or after `end`.
+    #[must_use]
+    pub fn from_end(duration: Period, end: DateTime<Tz>) -> Option<DateTimeInterval<Tz>> {
+        let start = end.clone().checked_sub_span(duration, WALL_CLOCK)?;
+        let range = DateTimeRange::new(start, end)?;
+        Some(DateTimeInterval { range, form: IntervalForm::DurationEnd(duration) })
+    }
+
+    /// Parses an ISO 8601 interval, interpreting end points without an offset in `tz`.
+    ///
+    /// End points with an offset from UTC are converted to `tz`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid interval, if the end is before the start, or
+    /// if a local end point does not exist (`IMPOSSIBLE`) or is ambiguous (`NOT_ENOUGH`) in `tz`.
+    pub fn parse_with_timezone(s: &str, tz: &Tz) -> ParseResult<DateTimeInterval<Tz>> {
+        resolve_interval(split_interval(s)?, |endpoint| match endpoint {
+            Endpoint::Instant(dt) => Ok(dt.with_timezone(tz)),
+            Endpoint::Local(local) => match tz.from_local_datetime(&local) {
+                LocalResult::Single(dt) => Ok(dt),
+                LocalResult::Ambiguous(..) => Err(NOT_ENOUGH),
+                LocalResult::None => Err(IMPOSSIBLE),
+            },
+        })
+    }
+
+    /// Returns the start of the interval, which is contained in the interval.
+    #[must_use]
+    pub fn start(&self) -> &DateTime<Tz> {
+        self.range.start()
+    }
+
+    /// Returns the end of the interval, which is not contained in the interval.
+    #[must_use]
+    pub fn end(&self) -> &DateTime<Tz> {
+        self.range.end()
+    }
+
+    /// Returns how the interval was given.
+    #[must_use]
+    pub fn form(&self) -> IntervalForm {
+        self.form
+    }
+
+    /// Returns the range of instants covered by the interval.
+    ///
+    /// The [`DateTimeRange`] has the set operations, such as
+    /// [`intersection`](DateTimeRange::intersection), and iteration with
+    /// [`step_by`](DateTimeRange::step_by).
+    #[must_use]
+    pub fn range(&self) -> &DateTimeRange<Tz> {
+        &self.range
+    }
+
+    /// Returns the exact amount of time from the start to the end.
+    #[must_use]
+    pub fn duration(&self) -> TimeDelta {
+        self.range.duration()
+    }
+
+    /// Returns `true` if the start and the end are the same instant.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns `true` if `dt` is at or after the start, and before the end.
+    #[must_use]
+    pub fn contains<Tz2: TimeZone>(&self, dt: &DateTime<Tz2>) -> bool {
+        self.range.contains(dt)
+    }
+
+    /// Returns `true` if `other` lies completely within this interval.
+    ///
+    /// Like [`DateTimeRange::contains_range`] on the [`range`](DateTimeInterval::range) of both
+    /// intervals.
+    #[must_use]
+    pub fn contains_interval<Tz2: TimeZone>(&self, other: &DateTimeInterval<Tz2>) -> bool {
+        self.range.contains_range(&other.range)
+    }
+
+    /// Returns `true` if this interval and `other` have an instant in common.
+    ///
+    /// Intervals that only touch, where one ends at the start of the other, do not overlap. An
+    /// empty interval contains no instant, so it overlaps nothing.
+    #[must_use]
+    pub fn overlaps<Tz2: TimeZone>(&self, other: &DateTimeInterval<Tz2>) -> bool {
+        self.range.overlaps(&other.range)
+    }
+
+    /// Converts the interval to another time zone, keeping its form.
+    #[must_use]
+    pub fn with_timezone<Tz2: TimeZone>(&self, tz: &Tz2) -> DateTimeInterval<Tz2> {
+        DateTimeInterval { range: self.range.with_timezone(tz), form: self.form }
+    }
+}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<DateTimeInterval<Tz2>> for DateTimeInterval<Tz> {
+    /// Intervals are equal if they have the same start and end instants and the same form.
+    fn eq(&self, other: &DateTimeInterval<Tz2>) -> bool {
+        self.range == other.range && self.form == other.form
+    }
+}
+
+impl<Tz: TimeZone> Eq for DateTimeInterval<Tz> {}
+
+impl<Tz: TimeZone> fmt::Debug for DateTimeInterval<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DateTimeInterval")
+            .field("start", self.start())
+            .field("end", self.end())
+            .field("form", &self.form)
+            .finish()
+    }
+}
+
+impl<Tz: TimeZone> fmt::Display for DateTimeInterval<Tz> {
+    /// Formats the interval in ISO 8601 in the form it was given, for example
+    /// `2024-01-01T00:00:00+01:00/P1D`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let instant =
+            |dt: &DateTime<Tz>| format_iso8601(dt.fixed_offset(), Iso8601Format::EXTENDED);
+        match self.form {
+            IntervalForm::StartEnd => {
+                write!(f, "{}/{}", instant(self.start()), instant(self.end()))
+            }
+            IntervalForm::StartDuration(duration) => {
+                write!(f, "{}/{}", instant(self.start()), duration)
+            }
+            IntervalForm::DurationEnd(duration) => {
+                write!(f, "{}/{}", duration, instant(self.end()))
+            }
+        }
+    }
+}
+
+/// Parsing a `str` into a `DateTimeInterval<FixedOffset>` accepts the ISO 8601 interval forms
+/// `start/end`, `start/duration` and `duration/end`.
+///
+/// An end point without an offset from UTC takes the offset of the other end point. Use
+/// [`DateTimeInterval::parse_with_timezone`] to parse intervals without any offset.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DateTimeInterval, FixedOffset, IntervalForm, Period};
+///
+/// let interval: DateTimeInterval<FixedOffset> =
+///     "2024-01-31T09:00+01:00/2024-01-31T17:30".parse()?;
+/// assert_eq!(interval.end().to_rfc3339(), "2024-01-31T17:30:00+01:00");
+/// assert_eq!(interval.duration().num_minutes(), 510);
+///
+/// let interval: DateTimeInterval<FixedOffset> = "P1M/2024-03-01T00:00:00Z".parse()?;
+/// assert_eq!(interval.start().to_rfc3339(), "2024-02-01T00:00:00+00:00");
+/// assert_eq!(interval.form(), IntervalForm::DurationEnd(Period::months(1)));
+///
+/// assert!("2024-01-31T09:00/P1D".parse::<DateTimeInterval<FixedOffset>>().is_err());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+impl FromStr for DateTimeInterval<FixedOffset> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<DateTimeInterval<FixedOffset>> {
+        let parts = split_interval(s)?;
+        let offset = [&parts.0, &parts.1].iter().find_map(|part| match part {
+            Part::Endpoint(Endpoint::Instant(dt)) => Some(*dt.offset()),
+            _ => None,
+        });
+        resolve_interval(parts, |endpoint| match endpoint {
+            Endpoint::Instant(dt) => Ok(dt),
+            Endpoint::Local(local) => {
+                let offset = offset.ok_or(NOT_ENOUGH)?;
+                local.and_local_timezone(offset).single().ok_or(OUT_OF_RANGE)
+            }
+        })
+    }
+}
+
+/// A time interval that repeats a number of times, or without end.
+///
+/// The `n`-th recurrence (counting from zero) of an interval with a start and a duration starts at
+/// `start + n × duration`, where the duration is applied as a [`Period`] to the local date and
+/// time. The recurrences of an interval with a duration and an end go back in time, the `n`-th one
+/// ends at `end - n × duration`. An interval with a start and an end repeats with its exact
+/// [`duration`](DateTimeInterval::duration).
+///
+/// In ISO 8601 a repeating interval is written as `R`, the number of recurrences (or nothing for
+/// an unbounded number), `/` and the interval.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, RepeatingInterval};
+///
+/// let hourly: RepeatingInterval<FixedOffset> = "R5/2024-01-01T00:00Z/PT1H".parse()?;
+/// assert_eq!(hourly.repetitions(), Some(5));
+/// let starts: Vec<String> = hourly.iter().map(|i| i.start().format("%H:%M").to_string()).collect();
+/// assert_eq!(starts, ["00:00", "01:00", "02:00", "03:00", "04:00"]);
+///
+/// let monthly: RepeatingInterval<FixedOffset> = "R/2024-01-31T00:00Z/P1M".parse()?;
+/// let starts: Vec<String> = monthly.iter().take(3).map(|i| i.start().format("%m-%d").to_string()).collect();
+/// assert_eq!(starts, ["01-31", "02-29", "03-31"]);
+/// assert_eq!(monthly.to_string(), "R/2024-01-31T00:00:00+00:00/P1M");
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone)]
+pub struct RepeatingInterval<Tz: TimeZone> {
+    repetitions: Option<u32>,
+    interval: DateTimeInterval<Tz>,
+}
+
+impl<Tz: TimeZone> RepeatingInterval<Tz> {
+    /// Makes a new repeating interval, which repeats `repetitions` times or without end if
+    /// `repetitions` is `None`.
+    #[must_use]
+    pub fn new(repetitions: Option<u32>, interval: DateTimeInterval<Tz>) -> RepeatingInterval<Tz> {
+        RepeatingInterval { repetitions, interval }
+    }
+
+    /// Parses an ISO 8601 repeating interval, interpreting end points without an offset in `tz`.
+    ///
+    /// See [`DateTimeInterval::parse_with_timezone`].
+    pub fn parse_with_timezone(s: &str, tz: &Tz) -> ParseResult<RepeatingInterval<Tz>> {
+        let (repetitions, rest, offset) = split_repetitions(s)?;
+        let interval =
+            DateTimeInterval::parse_with_timezone(rest, tz).map_err(|e| e.shift(offset))?;
+        Ok(RepeatingInterval { repetitions, interval })
+    }
+
+    /// Returns the number of recurrences, or `None` if the interval repeats without end.
+    #[must_use]
+    pub fn repetitions(&self) -> Option<u32> {
+        self.repetitions
+    }
+
+    /// Returns the first interval.
+    #[must_use]
+    pub fn interval(&self) -> &DateTimeInterval<Tz> {
+        &self.interval
+    }
+
+    /// Returns an iterator over the recurrences of the interval.
+    ///
+    /// The iterator stops early if a recurrence would be out of range.
+    #[must_use]
+    pub fn iter(&self) -> Recurrences<Tz> {
+        Recurrences { interval: self.interval.clone(), index: 0, remaining: self.repetitions }
+    }
+}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<RepeatingInterval<Tz2>> for RepeatingInterval<Tz> {
+    fn eq(&self, other: &RepeatingInterval<Tz2>) -> bool {
+        self.repetitions == other.repetitions && self.interval == other.interval
+    }
+}
+
+impl<Tz: TimeZone> Eq for RepeatingInterval<Tz> {}
+
+impl<Tz: TimeZone> fmt::Debug for RepeatingInterval<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RepeatingInterval")
+            .field("repetitions", &self.repetitions)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl<Tz: TimeZone> fmt::Display for RepeatingInterval<Tz> {
+    /// Formats the repeating interval in ISO 8601, for example `R5/2024-01-01T00:00:00+00:00/PT1H`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("R")?;
+        if let Some(repetitions) = self.repetitions {
+            write!(f, "{}", repetitions)?;
+        }
+        write!(f, "/{}", self.interval)
+    }
+}
+
+/// Parsing a `str` into a `RepeatingInterval<FixedOffset>` accepts ISO 8601 repeating intervals
+/// such as `R5/2024-01-01T00:00Z/PT1H`.
+///
+/// The interval is parsed as with `FromStr` for [`DateTimeInterval<FixedOffset>`].
+impl FromStr for RepeatingInterval<FixedOffset> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<RepeatingInterval<FixedOffset>> {
+        let (repetitions, rest, offset) = split_repetitions(s)?;
+        let interval = rest.parse().map_err(|e: ParseError| e.shift(offset))?;
+        Ok(RepeatingInterval { repetitions, interval })
+    }
+}
+
+impl<Tz: TimeZone> IntoIterator for &RepeatingInterval<Tz> {
+    type Item = DateTimeInterval<Tz>;
+    type IntoIter = Recurrences<Tz>;
+
+    fn into_iter(self) -> Recurrences<Tz> {
+        self.iter()
+    }
+}
+
+/// Iterator over the recurrences of a [`RepeatingInterval`].
+///
+/// Created by [`RepeatingInterval::iter`].
+#[derive(Clone)]
+pub struct Recurrences<Tz: TimeZone> {
+    interval: DateTimeInterval<Tz>,
+    /// The number of the next recurrence.
+    index: i32,
+    /// The number of recurrences left, or `None` without end.
+    remaining: Option<u32>,
+}
+
+impl<Tz: TimeZone> Recurrences<Tz> {
+    /// Returns recurrence number `index`, or `None` if it is out of range.
+    fn recurrence(&self, index: i32) -> Option<DateTimeInterval<Tz>> {
+        let (start, end) = (self.interval.start(), self.interval.end());
+        match self.interval.form {
+            IntervalForm::StartEnd => {
+                let duration = self.interval.duration();
+                let start = start.clone().checked_add_signed(duration.checked_mul(index)?)?;
+                let end = start.clone().checked_add_signed(duration)?;
+                DateTimeInterval::new(start, end)
+            }
+            IntervalForm::StartDuration(duration) => {
+                let offset = duration.checked_mul(index)?;
+                DateTimeInterval::from_start(
+                    start.clone().checked_add_span(offset, WALL_CLOCK)?,
+                    duration,
+                )
+            }
+            IntervalForm::DurationEnd(duration) => {
+                let offset = duration.checked_mul(index)?;
+                DateTimeInterval::from_end(
+                    duration,
+                    end.clone().checked_sub_span(offset, WALL_CLOCK)?,
+                )
+            }
+        }
+    }
+}
+
+impl<Tz: TimeZone> Iterator for Recurrences<Tz> {
+    type Item = DateTimeInterval<Tz>;
+
+    fn next(&mut self) -> Option<DateTimeInterval<Tz>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let recurrence = self.index.checked_add(1).and_then(|_| self.recurrence(self.index));
+        match recurrence {
+            Some(_) => {
+                self.index += 1;
+                self.remaining = self.remaining.map(|n| n - 1);
+            }
+            None => self.remaining = Some(0),
+        }
+        recurrence
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.remaining.map(|n| n as usize))
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Recurrences<Tz> {}
+
+impl<Tz: TimeZone> fmt::Debug for Recurrences<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Recurrences")
+            .field("interval", &self.interval)
+            .field("index", &self.index)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+/// Durations are added to the local time, like `DateTime + Period`.
+const WALL_CLOCK: CalendarArithmetic = CalendarArithmetic::WallClock(Disambiguation::Compatible);
+
+/// A date and time in an ISO 8601 interval, with or without an offset from UTC.
+enum Endpoint {
+    Instant(DateTime<FixedOffset>),
+    Local(NaiveDateTime),
+}
+
+/// One of the two parts of an ISO 8601 interval.
+enum Part {
+    Endpoint(Endpoint),
+    Duration(Period),
+}
+
+/// Splits an interval at the `/` or `--` separator and parses both parts.
+fn split_interval(s: &str) -> ParseResult<(Part, Part)> {
+    let (first, second, second_start) = match s.find('/') {
+        Some(i) => (&s[..i], &s[i + 1..], i + 1),
+        None => match s.find("--") {
+            Some(i) => (&s[..i], &s[i + 2..], i + 2),
+            None => return Err(TOO_SHORT),
+        },
+    };
+    Ok((parse_part(first)?, parse_part(second).map_err(|e| e.shift(second_start))?))
+}
+
+fn parse_part(s: &str) -> ParseResult<Part> {
+    if s.starts_with('P') {
+        return s.parse().map(Part::Duration);
+    }
+    if !s.contains('T') {
+        let date = parse_iso8601::<NaiveDate>(s)?;
+        return Ok(Part::Endpoint(Endpoint::Local(date.and_time(NaiveTime::MIN))));
+    }
+    match parse_iso8601::<DateTime<FixedOffset>>(s) {
+        // there is no offset, parse a local date and time
+        Err(e) if e.kind() == ParseErrorKind::TooShort && e.position() == Some(s.len()) => {
+            parse_iso8601::<NaiveDateTime>(s).map(|dt| Part::Endpoint(Endpoint::Local(dt)))
+        }
+        result => result.map(|dt| Part::Endpoint(Endpoint::Instant(dt))),
+    }
+}
+
+/// Makes an interval from its parts, converting the end points with `to_tz`.
+fn resolve_interval<Tz: TimeZone>(
+    parts: (Part, Part),
+    to_tz: impl Fn(Endpoint) -> ParseResult<DateTime<Tz>>,
+) -> ParseResult<DateTimeInterval<Tz>> {
+    let (start, end, form) = match parts {
+        (Part::Endpoint(start), Part::Endpoint(end)) => {
+            (to_tz(start)?, to_tz(end)?, IntervalForm::StartEnd)
+        }
+        (Part::Endpoint(start), Part::Duration(duration)) => {
+            let start = to_tz(start)?;
+            let end = start.clone().checked_add_span(duration, WALL_CLOCK).ok_or(OUT_OF_RANGE)?;
+            (start, end, IntervalForm::StartDuration(duration))
+        }
+        (Part::Duration(duration), Part::Endpoint(end)) => {
+            let end = to_tz(end)?;
+            let start = end.clone().checked_sub_span(duration, WALL_CLOCK).ok_or(OUT_OF_RANGE)?;
+            (start, end, IntervalForm::DurationEnd(duration))
+        }
+        (Part::Duration(_), Part::Duration(_)) => return Err(INVALID),
+    };
+    let range = DateTimeRange::new(start, end).ok_or(IMPOSSIBLE)?;
+    Ok(DateTimeInterval { range, form })
+}
+
+/// Splits the `R[n]/` prefix of a repeating interval, returning the number of recurrences, the
+/// interval and its byte offset.
+fn split_repetitions(s: &str) -> ParseResult<(Option<u32>, &str, usize)> {
+    let rest = match s.as_bytes().first() {
+        Some(b'R') => &s[1..],
+        Some(_) => return Err(INVALID),
+        None => return Err(TOO_SHORT),
+    };
+    let (count, rest) = rest.split_at(rest.find('/').ok_or(TOO_SHORT)?);
+    let repetitions = match count {
+        "" => None,
+        _ if !count.bytes().all(|c| c.is_ascii_digit()) => return Err(INVALID),
+        _ => Some(count.parse().map_err(|_| OUT_OF_RANGE)?),
+    };
+    Ok((repetitions, &rest[1..], count.len() + 2))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::RepeatingInterval;
+    use super::{DateTimeInterval, IntervalForm};
+    use crate::format::ParseErrorKind;
+    use crate::offset::{FixedOffset, TimeZone, Utc};
+    use crate::{Period, TimeDelta};
+
+    fn parse(s: &str) -> Result<DateTimeInterval<FixedOffset>, ParseErrorKind> {
+        s.parse().map_err(|e: crate::ParseError| e.kind())
+    }
+
+    #[test]
+    fn test_interval_parse() {
+        let utc = |y, m, d, h, n| Utc.with_ymd_and_hms(y, m, d, h, n, 0).unwrap();
+
+        let interval = parse("2024-01-01T00:00Z/2024-01-02T12:00Z").unwrap();
+        assert_eq!(interval.start(), &utc(2024, 1, 1, 0, 0));
+        assert_eq!(interval.end(), &utc(2024, 1, 2, 12, 0));
+        assert_eq!(interval.form(), IntervalForm::StartEnd);
+        assert_eq!(interval.duration(), TimeDelta::try_hours(36).unwrap());
+        assert_eq!(parse("20240101T0000Z--20240102T1200Z"), Ok(interval.clone()));
+        // the end takes the offset of the start
+        assert_eq!(parse("2024-01-01T00:00Z/2024-01-02T12:00"), Ok(interval));
+
+        let interval = parse("2024-01-01T00:00Z/P1D").unwrap();
+        assert_eq!(interval.end(), &utc(2024, 1, 2, 0, 0));
+        assert_eq!(interval.form(), IntervalForm::StartDuration(Period::days(1)));
+        let interval = parse("P1W/2024-02-01T00:00+01:00").unwrap();
+        assert_eq!(interval.start(), &utc(2024, 1, 24, 23, 0));
+        assert_eq!(interval.form(), IntervalForm::DurationEnd(Period::days(7)));
+
+        assert_eq!(parse("2024-01-01T00:00Z"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("P1D"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("P1D/P2D"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2024-01-01T00:00/P1D"), Err(ParseErrorKind::NotEnough));
+        assert_eq!(parse("2024-01-02T00:00Z/2024-01-01T00:00Z"), Err(ParseErrorKind::Impossible));
+        assert_eq!(parse("2024-01-01T00:00Z/-P1D"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("2024-01-01T00:00Z/P1X"), Err(ParseErrorKind::Invalid));
+
+        let err = "2024-01-01T00:00Z/2024-13-01T00:00Z".parse::<DateTimeInterval<FixedOffset>>();
+        assert_eq!(err.unwrap_err().position(), Some(23));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_interval_parse_with_timezone() {
+        let utc = |y, m, d, h, n| Utc.with_ymd_and_hms(y, m, d, h, n, 0).unwrap();
+
+        let prague = crate::TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        // one calendar day across the start of daylight saving time is 23 hours
+        let interval = DateTimeInterval::parse_with_timezone("2024-03-31/P1D", &prague).unwrap();
+        assert_eq!(interval.start(), &utc(2024, 3, 30, 23, 0));
+        assert_eq!(interval.duration(), TimeDelta::try_hours(23).unwrap());
+        let interval =
+            DateTimeInterval::parse_with_timezone("2024-03-31T01:00Z/PT1H", &prague).unwrap();
+        assert_eq!(interval.start().offset().to_string(), "CEST");
+
+        let parse = |s| DateTimeInterval::parse_with_timezone(s, &prague).map_err(|e| e.kind());
+        assert_eq!(parse("2024-03-31T02:30/PT1H"), Err(ParseErrorKind::Impossible));
+        assert_eq!(parse("2024-10-27T02:30/PT1H"), Err(ParseErrorKind::NotEnough));
+    }
+
+    #[test]
+    fn test_interval_queries() {
+        let utc = |y, m, d, h, n| Utc.with_ymd_and_hms(y, m, d, h, n, 0).unwrap();
+
+        let interval = DateTimeInterval::new(utc(2024, 1, 1, 0, 0), utc(2024, 1, 2, 0, 0)).unwrap();
+        assert!(DateTimeInterval::new(utc(2024, 1, 2, 0, 0), utc(2024, 1, 1, 0, 0)).is_none());
+        assert!(interval.contains(&utc(2024, 1, 1, 0, 0)));
+        assert!(interval.contains(&utc(2024, 1, 1, 23, 59)));
+        assert!(!interval.contains(&utc(2024, 1, 2, 0, 0)));
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        assert!(interval.contains(&cet.with_ymd_and_hms(2024, 1, 2, 0, 30, 0).unwrap()));
+        assert!(!interval.is_empty());
+
+        let morning = DateTimeInterval::from_start(
+            utc(2024, 1, 1, 6, 0),
+            Period::time(TimeDelta::try_hours(6).unwrap()),
+        )
+        .unwrap();
+        let next_day = DateTimeInterval::from_end(Period::days(1), utc(2024, 1, 3, 0, 0)).unwrap();
+        assert!(interval.contains_interval(&morning));
+        assert!(!morning.contains_interval(&interval));
+        assert!(interval.overlaps(&morning) && morning.overlaps(&interval));
+        assert!(!interval.overlaps(&next_day) && !next_day.overlaps(&interval));
+        assert!(interval.contains_interval(&interval));
+
+        // an empty interval overlaps nothing, not even an interval around it
+        let empty = DateTimeInterval::new(utc(2024, 1, 1, 12, 0), utc(2024, 1, 1, 12, 0)).unwrap();
+        assert!(empty.is_empty());
+        assert!(!interval.overlaps(&empty) && !empty.overlaps(&interval));
+        assert!(!empty.overlaps(&empty));
+        assert!(interval.contains_interval(&empty));
+        assert!(DateTimeInterval::from_start(utc(2024, 1, 1, 0, 0), Period::days(-1)).is_none());
+
+        let converted = morning.with_timezone(&cet);
+        assert_eq!(converted, morning);
+        #[cfg(feature = "alloc")]
+        assert_eq!(converted.start().to_rfc3339(), "2024-01-01T07:00:00+01:00");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_interval_display() {
+        for s in [
+            "2024-01-01T00:00:00+00:00/2024-01-02T12:00:00+00:00",
+            "2024-01-01T00:00:00+01:00/P1Y2M3DT4H",
+            "PT1.5S/2024-01-01T00:00:00-05:00",
+            "R/2024-01-01T00:00:00+00:00/PT1H",
+            "R0/PT1H/2024-01-01T00:00:00+00:00",
+        ] {
+            if s.starts_with('R') {
+                assert_eq!(s.parse::<RepeatingInterval<FixedOffset>>().unwrap().to_string(), s);
+            } else {
+                assert_eq!(s.parse::<DateTimeInterval<FixedOffset>>().unwrap().to_string(), s);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_repeating_interval() {
+        let utc = |y, m, d, h, n| Utc.with_ymd_and_hms(y, m, d, h, n, 0).unwrap();
+
+        let parse = |s: &str| s.parse::<RepeatingInterval<FixedOffset>>().map_err(|e| e.kind());
+        let hourly = parse("R3/2024-01-01T00:00Z/PT1H").unwrap();
+        let starts: Vec<_> = hourly.iter().map(|i| *i.start()).collect();
+        assert_eq!(starts, [utc(2024, 1, 1, 0, 0), utc(2024, 1, 1, 1, 0), utc(2024, 1, 1, 2, 0)]);
+        assert_eq!(hourly.iter().size_hint(), (0, Some(3)));
+
+        // start/end repeats with the exact duration
+        let shifts = parse("R2/2024-01-01T22:00Z/2024-01-02T06:00Z").unwrap();
+        let ends: Vec<_> = (&shifts).into_iter().map(|i| *i.end()).collect();
+        assert_eq!(ends, [utc(2024, 1, 2, 6, 0), utc(2024, 1, 2, 14, 0)]);
+
+        // recurrences are computed from the first one, so months do not drift
+        let monthly = parse("R/2024-01-31T00:00Z/P1M").unwrap();
+        assert_eq!(monthly.repetitions(), None);
+        let starts: Vec<_> = monthly.iter().take(4).map(|i| *i.start()).collect();
+        assert_eq!(
+            starts,
+            [
+                utc(2024, 1, 31, 0, 0),
+                utc(2024, 2, 29, 0, 0),
+                utc(2024, 3, 31, 0, 0),
+                utc(2024, 4, 30, 0, 0)
+            ]
+        );
+
+        // duration/end goes back in time
+        let backwards = parse("R2/P1D/2024-01-10T00:00Z").unwrap();
+        let ends: Vec<_> = backwards.iter().map(|i| *i.end()).collect();
+        assert_eq!(ends, [utc(2024, 1, 10, 0, 0), utc(2024, 1, 9, 0, 0)]);
+
+        // stops at the end of the supported range
+        let yearly = parse("R/+262000-01-01T00:00Z/P100Y").unwrap();
+        assert_eq!(yearly.iter().count(), 1);
+        assert_eq!(parse("R0/2024-01-01T00:00Z/PT1H").unwrap().iter().next(), None);
+
+        assert_eq!(parse(""), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("R"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("X/2024-01-01T00:00Z/PT1H"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("R-1/2024-01-01T00:00Z/PT1H"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("R99999999999/2024-01-01T00:00Z/PT1H"), Err(ParseErrorKind::OutOfRange));
+        let err = "R5/2024-01-01T00:00Z/PT1H/".parse::<RepeatingInterval<FixedOffset>>();
+        assert_eq!(err.unwrap_err().kind(), ParseErrorKind::Invalid);
+    }
+}
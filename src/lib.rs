@@ -570,7 +570,7 @@ pub use date::{MAX_DATE, MIN_DATE};
 mod datetime;
 #[cfg(feature = "rustc-serialize")]
 pub use datetime::rustc_serialize::TsSeconds;
-pub use datetime::DateTime;
+pub use datetime::{CalendarArithmetic, CalendarSpan, DateTime};
 #[allow(deprecated)]
 #[doc(no_inline)]
 pub use datetime::{MAX_DATETIME, MIN_DATETIME};
@@ -590,9 +590,12 @@ pub mod offset;
 #[cfg(feature = "clock")]
 #[doc(inline)]
 pub use offset::Local;
-pub use offset::LocalResult;
+pub use offset::{Disambiguation, LocalResult};
 #[doc(inline)]
 pub use offset::{FixedOffset, Offset, TimeZone, Utc};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use offset::{TzInfo, TzInfoOffset};
 
 pub mod round;
 pub use round::{DurationRound, RoundingError, SubsecRound};
@@ -602,6 +605,31 @@ mod weekday;
 pub use weekday::ParseWeekdayError;
 pub use weekday::Weekday;
 
+mod period;
+pub use period::Period;
+
+mod interval;
+pub use interval::{DateTimeInterval, IntervalForm, Recurrences, RepeatingInterval};
+
+mod range;
+pub use range::{DateTimeRange, DateTimeRangeIter, NaiveDateRange, NaiveDateRangeIter};
+
+pub mod calendar;
+
+mod day_count;
+pub use day_count::DayCount;
+
+#[cfg(feature = "alloc")]
+mod business_calendar;
+#[cfg(feature = "alloc")]
+pub use business_calendar::{BusinessCalendar, HolidayRule, RollConvention};
+
+#[cfg(feature = "alloc")]
+pub mod rrule;
+#[cfg(feature = "alloc")]
+#[doc(no_inline)]
+pub use rrule::RRule;
+
 mod month;
 #[doc(no_inline)]
 pub use month::ParseMonthError;
@@ -619,6 +647,16 @@ pub use naive::__BenchYearFlags;
 /// This module provides default implementations for `DateTime` using the [RFC 3339][1] format and various
 /// alternatives for use with serde's [`with` annotation][2].
 ///
+/// The integer `ts_*` modules work with a `DateTime<Utc>`; the matching `ts_*_tz` modules and the
+/// `ts_seconds_f64` modules work with a `DateTime` in any time zone. Serializing only writes the
+/// timestamp, so deserializing can only give a `DateTime<Tz>` for which there is a
+/// `From<DateTime<Utc>>` implementation, such as `DateTime<Utc>`, `DateTime<FixedOffset>` and
+/// `DateTime<Local>`.
+///
+/// The `rfc2822` and `rfc3339_lenient` modules serialize a `DateTime` as a string in another
+/// format, and the [`strftime!`](crate::serde::strftime) macro generates a module for a custom
+/// format string that works with `DateTime`, `NaiveDateTime`, `NaiveDate` and `NaiveTime`.
+///
 /// *Available on crate feature 'serde' only.*
 ///
 /// [1]: https://tools.ietf.org/html/rfc3339
@@ -629,6 +667,30 @@ pub mod serde {
     use serde::de;
 
     pub use super::datetime::serde::*;
+    #[cfg(feature = "alloc")]
+    pub use super::format::serde::StrftimeSerde;
+
+    #[cfg(feature = "alloc")]
+    #[doc(inline)]
+    pub use crate::__serde_strftime as strftime;
+
+    #[cfg(feature = "alloc")]
+    #[doc(hidden)]
+    pub mod __private {
+        pub use serde::{Deserializer, Serializer};
+    }
+
+    /// Serialization/Deserialization of [`TimeDelta`](crate::TimeDelta) in alternate formats.
+    ///
+    /// By default a `TimeDelta` is serialized as an [ISO 8601 duration][1] string. The modules in
+    /// here are intended to be used with serde's [`with` annotation][2] to serialize it as an
+    /// integer instead.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    /// [2]: https://serde.rs/field-attrs.html#with
+    pub mod time_delta {
+        pub use crate::time_delta::serde::*;
+    }
 
     /// Create a custom `de::Error` with `SerdeError::InvalidTimestamp`.
     pub(crate) fn invalid_ts<E, T>(value: T) -> E
@@ -192,7 +192,6 @@ impl num_traits::FromPrimitive for Month {
     /// `Month::from_i64(n: i64)`: | `1`                  | `2`                   | ... | `12`
     /// ---------------------------| -------------------- | --------------------- | ... | -----
     /// ``:                        | Some(Month::January) | Some(Month::February) | ... | Some(Month::December)
-
     #[inline]
     fn from_u64(n: u64) -> Option<Month> {
         Self::from_u32(n as u32)
@@ -264,7 +263,7 @@ impl fmt::Debug for ParseMonthError {
 
 #[cfg(feature = "serde")]
 mod month_serde {
-    use super::Month;
+    use super::{Month, Months};
     use serde::{de, ser};
 
     use core::fmt;
@@ -303,6 +302,26 @@ mod month_serde {
             deserializer.deserialize_str(MonthVisitor)
         }
     }
+
+    /// Serialize into the number of months as an integer.
+    impl ser::Serialize for Months {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_u32(self.0)
+        }
+    }
+
+    /// Deserialize from the number of months as an integer.
+    impl<'de> de::Deserialize<'de> for Months {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            u32::deserialize(deserializer).map(Months)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -433,6 +452,15 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_months() {
+        assert_eq!(serde_json::to_string(&Months::new(14)).unwrap(), "14");
+        assert_eq!(serde_json::from_str::<Months>("14").unwrap(), Months::new(14));
+        assert!(serde_json::from_str::<Months>("-1").is_err());
+        assert!(serde_json::from_str::<Months>("\"P1M\"").is_err());
+    }
+
     #[test]
     #[cfg(feature = "rkyv-validation")]
     fn test_rkyv_validation() {
@@ -24,9 +24,11 @@ use core::{fmt, str};
 use rkyv::{Archive, Deserialize, Serialize};
 
 /// L10n locales.
-#[cfg(all(feature = "unstable-locales", feature = "alloc"))]
+#[cfg(feature = "unstable-locales")]
 use pure_rust_locales::Locale;
 
+#[cfg(feature = "unstable-locales")]
+use crate::format::parse_localized;
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{
@@ -36,7 +38,7 @@ use crate::format::{
 use crate::month::Months;
 use crate::naive::{Days, IsoWeek, NaiveDateTime, NaiveTime, NaiveWeek};
 use crate::{expect, try_opt};
-use crate::{Datelike, TimeDelta, Weekday};
+use crate::{Datelike, Period, TimeDelta, Weekday};
 
 use super::internals::{Mdf, YearFlags};
 
@@ -384,6 +386,47 @@ impl NaiveDate {
         NaiveDate::from_ordinal_and_flags(year_div_400 * 400 + year_mod_400 as i32, ordinal, flags)
     }
 
+    /// Makes a new `NaiveDate` from a Julian Day Number (JDN).
+    ///
+    /// Julian days are counted from noon UTC on November 24, 4714 BCE in the proleptic Gregorian
+    /// calendar, which starts day 0. The Julian Day Number of a date is the number of the Julian
+    /// day that starts at noon of that date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range for `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_julian_day(2_451_545), NaiveDate::from_ymd_opt(2000, 1, 1));
+    /// assert_eq!(NaiveDate::from_julian_day(0), NaiveDate::from_ymd_opt(-4713, 11, 24));
+    /// assert_eq!(NaiveDate::from_julian_day(-100_000_000), None);
+    /// ```
+    #[must_use]
+    pub const fn from_julian_day(day: i32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(day.checked_sub(JULIAN_DAY_OF_CE_DAY_0)))
+    }
+
+    /// Returns the Julian Day Number (JDN) of the date.
+    ///
+    /// See [`NaiveDate::from_julian_day`] for the definition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// assert_eq!(date.to_julian_day(), 2_460_371);
+    /// ```
+    #[must_use]
+    pub const fn to_julian_day(&self) -> i32 {
+        self.num_days_from_ce() + JULIAN_DAY_OF_CE_DAY_0
+    }
+
     /// Makes a new `NaiveDate` by counting the number of occurrences of a particular day-of-week
     /// since the beginning of the given month. For instance, if you want the 2nd Friday of March
     /// 2017, you would use `NaiveDate::from_weekday_of_month(2017, 3, Weekday::Fri, 2)`.
@@ -443,6 +486,75 @@ impl NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day)
     }
 
+    /// Makes a new `NaiveDate` for Western Easter Sunday of the given year, computed with the
+    /// Gregorian rules.
+    ///
+    /// Other movable feasts follow from Easter, for example Good Friday is two days before it,
+    /// Ascension is 39 days after it and Pentecost is 49 days after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `year` is out of range for `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Days, NaiveDate};
+    ///
+    /// let easter = NaiveDate::easter(2024).unwrap();
+    /// assert_eq!(easter, NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+    /// assert_eq!(easter.checked_sub_days(Days::new(2)), NaiveDate::from_ymd_opt(2024, 3, 29));
+    /// assert_eq!(easter.checked_add_days(Days::new(49)), NaiveDate::from_ymd_opt(2024, 5, 19));
+    /// ```
+    #[must_use]
+    pub const fn easter(year: i32) -> Option<NaiveDate> {
+        // the anonymous Gregorian algorithm (Meeus/Jones/Butcher)
+        let a = year.rem_euclid(19);
+        let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+        let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+        let f = (b + 8).div_euclid(25);
+        let g = (b - f + 1).div_euclid(3);
+        let h = (19 * a + b - d - g + 15).rem_euclid(30);
+        let (i, k) = (c / 4, c % 4);
+        let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l - 7 * m + 114;
+        NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+    }
+
+    /// Makes a new `NaiveDate` for Orthodox Easter Sunday of the given year.
+    ///
+    /// Orthodox Easter is computed with the rules of the Julian calendar, and the result is
+    /// converted to the (proleptic) Gregorian calendar. Because the calendars drift apart, this
+    /// date falls in a later Gregorian year for years far in the future.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date is out of range for `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::orthodox_easter(2024), NaiveDate::from_ymd_opt(2024, 5, 5));
+    /// assert_eq!(NaiveDate::orthodox_easter(2025), NaiveDate::easter(2025));
+    /// ```
+    #[must_use]
+    pub const fn orthodox_easter(year: i32) -> Option<NaiveDate> {
+        // Meeus' Julian algorithm
+        let a = year.rem_euclid(4);
+        let b = year.rem_euclid(7);
+        let c = year.rem_euclid(19);
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+        let n = d + e + 114;
+        let julian = try_opt!(NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32));
+        // The difference between the calendars in March and April of `year`.
+        let difference = year.div_euclid(100) - year.div_euclid(400) - 2;
+        julian.add_days(difference)
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](crate::format::strftime)
     /// on the supported escape sequences.
@@ -497,6 +609,65 @@ impl NaiveDate {
         parsed.to_naive_date()
     }
 
+    /// Parses a string with the specified format string and locale into a new `NaiveDate`.
+    ///
+    /// Month and weekday names are parsed with the names of `locale`, ignoring case and accents.
+    /// This can parse the output of [`format_localized`](#method.format_localized). See
+    /// [`format::parse_localized`](crate::format::parse_localized) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Locale, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2001, 8, 17).unwrap();
+    /// let formatted = date.format_localized("%A %e %B %Y", Locale::de_DE).to_string();
+    /// assert_eq!(formatted, "Freitag 17 August 2001");
+    /// assert_eq!(
+    ///     NaiveDate::parse_from_str_localized(&formatted, "%A %e %B %Y", Locale::de_DE),
+    ///     Ok(date)
+    /// );
+    /// assert_eq!(
+    ///     NaiveDate::parse_from_str_localized("MÄRZ 5 2001", "%B %d %Y", Locale::de_DE),
+    ///     Ok(NaiveDate::from_ymd_opt(2001, 3, 5).unwrap())
+    /// );
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(s: &str, fmt: &str, locale: Locale) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_naive_date()
+    }
+
+    /// Parses a string with the specified format string, taking missing fields from `default`.
+    ///
+    /// The year is taken from `default` if it is missing, the month if neither the year nor the
+    /// month is present, and so on. Fields less significant than a parsed field are set to their
+    /// minimum. See [`Parsed::fill_missing_from`] for the exact rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let default = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+    /// let parse = |s, fmt| NaiveDate::parse_from_str_with_default(s, fmt, default);
+    ///
+    /// assert_eq!(parse("March 5", "%B %d"), Ok(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()));
+    /// assert_eq!(parse("20", "%d"), Ok(NaiveDate::from_ymd_opt(2024, 6, 20).unwrap()));
+    /// assert_eq!(parse("2023-07", "%Y-%m"), Ok(NaiveDate::from_ymd_opt(2023, 7, 1).unwrap()));
+    /// ```
+    pub fn parse_from_str_with_default(
+        s: &str,
+        fmt: &str,
+        default: NaiveDate,
+    ) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.fill_missing_from(default.and_time(NaiveTime::MIN));
+        parsed.to_naive_date()
+    }
+
     /// Parses a string from a user-specified format into a new `NaiveDate` value, and a slice with
     /// the remaining portion of the string.
     /// See the [`format::strftime` module](crate::format::strftime)
@@ -546,7 +717,7 @@ impl NaiveDate {
             return Some(self);
         }
 
-        match months.0 <= core::i32::MAX as u32 {
+        match months.0 <= i32::MAX as u32 {
             true => self.diff_months(months.0 as i32),
             false => None,
         }
@@ -572,7 +743,7 @@ impl NaiveDate {
     /// assert_eq!(
     ///     NaiveDate::from_ymd_opt(2014, 1, 1)
     ///         .unwrap()
-    ///         .checked_sub_months(Months::new(core::i32::MAX as u32 + 1)),
+    ///         .checked_sub_months(Months::new(i32::MAX as u32 + 1)),
     ///     None
     /// );
     /// ```
@@ -589,7 +760,7 @@ impl NaiveDate {
         }
     }
 
-    const fn diff_months(self, months: i32) -> Option<Self> {
+    pub(crate) const fn diff_months(self, months: i32) -> Option<Self> {
         let (years, left) = ((months / 12), (months % 12));
 
         // Determine new year (without taking months into account for now
@@ -693,6 +864,60 @@ impl NaiveDate {
         }
     }
 
+    /// Add a [`Period`] to the date.
+    ///
+    /// The years and months are added first, using the last day of the month if the day does not
+    /// exist in the resulting month. Then the days are added, followed by the number of whole days
+    /// in the time span of the `Period`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Period, TimeDelta};
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2022, 1, 31)
+    ///         .unwrap()
+    ///         .checked_add_period(Period::new(0, 1, 1, TimeDelta::zero())),
+    ///     Some(NaiveDate::from_ymd_opt(2022, 3, 1).unwrap())
+    /// );
+    /// assert_eq!(NaiveDate::MAX.checked_add_period(Period::days(1)), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_add_period(self, period: Period) -> Option<Self> {
+        let date = try_opt!(self.diff_months(try_opt!(period.total_months())));
+        let date = try_opt!(date.add_days(period.num_days()));
+        date.checked_add_signed(period.time_delta())
+    }
+
+    /// Subtract a [`Period`] from the date.
+    ///
+    /// The negated components are applied in the same order as in
+    /// [`checked_add_period`](#method.checked_add_period).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Period, TimeDelta};
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd_opt(2022, 3, 31)
+    ///         .unwrap()
+    ///         .checked_sub_period(Period::new(0, 1, 1, TimeDelta::zero())),
+    ///     Some(NaiveDate::from_ymd_opt(2022, 2, 27).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn checked_sub_period(self, period: Period) -> Option<Self> {
+        self.checked_add_period(try_opt!(period.checked_neg()))
+    }
+
     /// Add a duration of `i32` days to the date.
     pub(crate) const fn add_days(self, days: i32) -> Option<Self> {
         // Fast path if the result is within the same year.
@@ -2040,6 +2265,38 @@ impl Sub<Days> for NaiveDate {
     }
 }
 
+/// Add a `Period` to `NaiveDate`.
+///
+/// See [`NaiveDate::checked_add_period`] for the order in which the components are applied.
+///
+/// # Panics
+///
+/// Panics if the resulting date would be out of range.
+/// Consider using `checked_add_period` to get an `Option` instead.
+impl Add<Period> for NaiveDate {
+    type Output = NaiveDate;
+
+    fn add(self, period: Period) -> Self::Output {
+        self.checked_add_period(period).expect("`NaiveDate + Period` out of range")
+    }
+}
+
+/// Subtract a `Period` from `NaiveDate`.
+///
+/// See [`NaiveDate::checked_sub_period`] for the order in which the components are applied.
+///
+/// # Panics
+///
+/// Panics if the resulting date would be out of range.
+/// Consider using `checked_sub_period` to get an `Option` instead.
+impl Sub<Period> for NaiveDate {
+    type Output = NaiveDate;
+
+    fn sub(self, period: Period) -> Self::Output {
+        self.checked_sub_period(period).expect("`NaiveDate - Period` out of range")
+    }
+}
+
 /// Subtract `TimeDelta` from `NaiveDate`.
 ///
 /// This discards the fractional days in `TimeDelta`, rounding to the closest integral number of
@@ -2366,12 +2623,12 @@ const fn div_mod_floor(val: i32, div: i32) -> (i32, i32) {
 /// MAX_YEAR is one year less than the type is capable of representing. Internally we may sometimes
 /// use the headroom, notably to handle cases where the offset of a `DateTime` constructed with
 /// `NaiveDate::MAX` pushes it beyond the valid, representable range.
-pub(super) const MAX_YEAR: i32 = (i32::MAX >> 13) - 1;
+pub(crate) const MAX_YEAR: i32 = (i32::MAX >> 13) - 1;
 
 /// MIN_YEAR is one year more than the type is capable of representing. Internally we may sometimes
 /// use the headroom, notably to handle cases where the offset of a `DateTime` constructed with
 /// `NaiveDate::MIN` pushes it beyond the valid, representable range.
-pub(super) const MIN_YEAR: i32 = (i32::MIN >> 13) + 1;
+pub(crate) const MIN_YEAR: i32 = (i32::MIN >> 13) + 1;
 
 const ORDINAL_MASK: i32 = 0b1_1111_1111_0000;
 
@@ -2390,6 +2647,9 @@ const WEEKDAY_FLAGS_MASK: i32 = 0b111;
 
 const YEAR_FLAGS_MASK: i32 = LEAP_YEAR_MASK | WEEKDAY_FLAGS_MASK;
 
+/// The Julian Day Number of December 31, 1 BCE, which is day 0 of `num_days_from_ce`.
+const JULIAN_DAY_OF_CE_DAY_0: i32 = 1_721_425;
+
 const YEAR_DELTAS: &[u8; 401] = &[
     0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 7, 8, 8, 8,
     8, 9, 9, 9, 9, 10, 10, 10, 10, 11, 11, 11, 11, 12, 12, 12, 12, 13, 13, 13, 13, 14, 14, 14, 14,
@@ -2440,8 +2700,6 @@ where
     F: Fn(&str) -> Result<NaiveDate, E>,
     E: ::std::fmt::Debug,
 {
-    use std::{i32, i64};
-
     assert_eq!(
         from_str(r#""2016-07-08""#).ok(),
         Some(NaiveDate::from_ymd_opt(2016, 7, 8).unwrap())
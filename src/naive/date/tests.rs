@@ -320,6 +320,81 @@ fn test_date_from_weekday_of_month_opt() {
     assert_eq!(ymwd(2018, 8, Weekday::Sat, 5), None);
 }
 
+#[test]
+fn test_date_easter() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    for (year, month, day) in
+        [(1583, 4, 10), (1818, 3, 22), (1943, 4, 25), (2000, 4, 23), (2024, 3, 31), (2285, 3, 22)]
+    {
+        assert_eq!(NaiveDate::easter(year), ymd(year, month, day));
+    }
+    for (year, month, day) in
+        [(1900, 4, 22), (2008, 4, 27), (2010, 4, 4), (2021, 5, 2), (2023, 4, 16), (2024, 5, 5)]
+    {
+        assert_eq!(NaiveDate::orthodox_easter(year), ymd(year, month, day));
+    }
+
+    for year in (MIN_YEAR..=MAX_YEAR).step_by(97) {
+        let easter = NaiveDate::easter(year).unwrap();
+        assert_eq!(easter.weekday(), Weekday::Sun);
+        assert!(easter >= ymd(year, 3, 22).unwrap() && easter <= ymd(year, 4, 25).unwrap());
+        if let Some(easter) = NaiveDate::orthodox_easter(year) {
+            assert_eq!(easter.weekday(), Weekday::Sun);
+        }
+    }
+
+    assert_eq!(NaiveDate::easter(MIN_YEAR - 1), None);
+    assert_eq!(NaiveDate::easter(MAX_YEAR + 1), None);
+    assert!(NaiveDate::easter(MIN_YEAR).is_some());
+    assert!(NaiveDate::easter(MAX_YEAR).is_some());
+    // far from year 0 the Julian calendar is years ahead or behind the Gregorian calendar
+    assert_eq!(NaiveDate::orthodox_easter(MIN_YEAR), None);
+    assert_eq!(NaiveDate::orthodox_easter(MAX_YEAR), None);
+    assert_eq!(NaiveDate::orthodox_easter(MAX_YEAR - 6).map(|d| d.year()), Some(MAX_YEAR - 1));
+    assert_eq!(NaiveDate::orthodox_easter(i32::MIN), None);
+    assert_eq!(NaiveDate::easter(i32::MAX), None);
+}
+
+#[test]
+fn test_date_julian_day() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    for (date, julian_day) in [
+        (ymd(-4713, 11, 24), 0),
+        (ymd(-4713, 11, 23), -1),
+        (ymd(0, 12, 31), 1_721_425),
+        (ymd(1, 1, 1), 1_721_426),
+        (ymd(1858, 11, 17), 2_400_001),
+        (ymd(1970, 1, 1), 2_440_588),
+        (ymd(2000, 1, 1), 2_451_545),
+        (ymd(2024, 3, 1), 2_460_371),
+    ] {
+        assert_eq!(date.to_julian_day(), julian_day);
+        assert_eq!(NaiveDate::from_julian_day(julian_day), Some(date));
+    }
+
+    for date in [NaiveDate::MIN, NaiveDate::MAX] {
+        assert_eq!(NaiveDate::from_julian_day(date.to_julian_day()), Some(date));
+    }
+    assert_eq!(NaiveDate::from_julian_day(NaiveDate::MIN.to_julian_day() - 1), None);
+    assert_eq!(NaiveDate::from_julian_day(NaiveDate::MAX.to_julian_day() + 1), None);
+    assert_eq!(NaiveDate::from_julian_day(i32::MIN), None);
+    assert_eq!(NaiveDate::from_julian_day(i32::MAX), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_date_julian_day_format() {
+    for date in [NaiveDate::MIN, NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(), NaiveDate::MAX] {
+        let formatted = date.format("%J").to_string();
+        assert_eq!(formatted, date.to_julian_day().to_string());
+        assert_eq!(NaiveDate::parse_from_str(&formatted, "%J"), Ok(date));
+    }
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(NaiveDate::parse_from_str("-1", "%J"), Ok(ymd(-4713, 11, 23)));
+    assert_eq!(NaiveDate::parse_from_str("2451545 2000-01-01", "%J %F"), Ok(ymd(2000, 1, 1)));
+    assert!(NaiveDate::parse_from_str("2451545 2000-01-02", "%J %F").is_err());
+}
+
 #[test]
 fn test_date_fields() {
     fn check(year: i32, month: u32, day: u32, ordinal: u32) {
@@ -16,13 +16,15 @@ use rkyv::{Archive, Deserialize, Serialize};
 #[cfg(feature = "alloc")]
 use crate::format::DelayedFormat;
 use crate::format::{parse, parse_and_remainder, ParseError, ParseResult, Parsed, StrftimeItems};
+#[cfg(feature = "unstable-locales")]
+use crate::format::{parse_localized, Locale};
 use crate::format::{Fixed, Item, Numeric, Pad};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveTime};
 use crate::offset::Utc;
 use crate::time_delta::NANOS_PER_SEC;
 use crate::{
-    expect, try_opt, DateTime, Datelike, FixedOffset, LocalResult, Months, TimeDelta, TimeZone,
-    Timelike, Weekday,
+    expect, try_opt, DateTime, Datelike, Disambiguation, FixedOffset, LocalResult, Months, Period,
+    TimeDelta, TimeZone, Timelike, Weekday,
 };
 #[cfg(feature = "rustc-serialize")]
 pub(super) mod rustc_serialize;
@@ -41,6 +43,36 @@ pub const MIN_DATETIME: NaiveDateTime = NaiveDateTime::MIN;
 #[deprecated(since = "0.4.20", note = "Use NaiveDateTime::MAX instead")]
 pub const MAX_DATETIME: NaiveDateTime = NaiveDateTime::MAX;
 
+/// The Julian Day Number of the UNIX epoch, 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+/// The Modified Julian Date of the UNIX epoch.
+const UNIX_EPOCH_MJD: i64 = 40_587;
+/// The Julian Day Number of day 0 of the Modified Julian Date, 1858-11-17.
+const MJD_JULIAN_DAY: i32 = 2_400_001;
+
+const HALF_DAY: TimeDelta = expect!(TimeDelta::try_hours(12), "");
+const FULL_DAY: TimeDelta = expect!(TimeDelta::try_days(1), "");
+
+/// Converts a non-negative `TimeDelta` shorter than a few days to a fractional number of days.
+fn days_f64(delta: TimeDelta) -> f64 {
+    (delta.num_seconds() as f64 + f64::from(delta.subsec_nanos()) / 1e9) / 86_400.0
+}
+
+/// Splits a number of days into whole days and the remaining fraction, rounded to nanoseconds.
+fn split_days_f64(days: f64) -> Option<(i32, TimeDelta)> {
+    // this is also false for NaN
+    if !(days >= f64::from(i32::MIN) && days < f64::from(i32::MAX)) {
+        return None;
+    }
+    let mut whole = days as i32; // rounds towards zero
+    if f64::from(whole) > days {
+        whole -= 1;
+    }
+    // the fraction is not negative, so this rounds to the nearest nanosecond
+    let nanos = ((days - f64::from(whole)) * 86_400e9 + 0.5) as i64;
+    Some((whole, TimeDelta::nanoseconds(nanos)))
+}
+
 /// ISO 8601 combined date and time without timezone.
 ///
 /// # Example
@@ -290,6 +322,76 @@ impl NaiveDateTime {
         parsed.to_naive_datetime_with_offset(0) // no offset adjustment
     }
 
+    /// Parses a string with the specified format string and locale into a new `NaiveDateTime`.
+    ///
+    /// Month and weekday names and AM/PM are parsed with the names of `locale`, ignoring case
+    /// and accents. See [`format::parse_localized`](crate::format::parse_localized) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Locale, NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 4).unwrap();
+    /// assert_eq!(
+    ///     NaiveDateTime::parse_from_str_localized(
+    ///         "sábado, 5 de septiembre de 2015 23:56:04",
+    ///         "%A, %e de %B de %Y %H:%M:%S",
+    ///         Locale::es_ES
+    ///     ),
+    ///     Ok(dt)
+    /// );
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(
+        s: &str,
+        fmt: &str,
+        locale: Locale,
+    ) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+
+    /// Parses a string with the specified format string, taking missing fields from `default`.
+    ///
+    /// Unlike [`parse_from_str`](#method.parse_from_str), the input does not have to contain a
+    /// complete date and time. Fields more significant than the most significant parsed field
+    /// are taken from `default`, less significant fields are set to their minimum. See
+    /// [`Parsed::fill_missing_from`] for the exact rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let default = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap().and_hms_opt(9, 10, 11).unwrap();
+    /// let parse = |s, fmt| NaiveDateTime::parse_from_str_with_default(s, fmt, default);
+    ///
+    /// assert_eq!(
+    ///     parse("14:30", "%H:%M"),
+    ///     Ok(NaiveDate::from_ymd_opt(2024, 6, 15).unwrap().and_hms_opt(14, 30, 0).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     parse("March 5", "%B %d"),
+    ///     Ok(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     parse("2023", "%Y"),
+    ///     Ok(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    /// );
+    /// ```
+    pub fn parse_from_str_with_default(
+        s: &str,
+        fmt: &str,
+        default: NaiveDateTime,
+    ) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.fill_missing_from(default);
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDateTime`, and a
     /// slice with the remaining portion of the string.
     /// See the [`format::strftime` module](crate::format::strftime)
@@ -451,6 +553,200 @@ impl NaiveDateTime {
         self.and_utc().timestamp_subsec_nanos()
     }
 
+    /// Makes a new `NaiveDateTime` from a Julian date, given as the Julian Day Number and the
+    /// time since noon of that Julian day.
+    ///
+    /// A Julian day starts at noon, so `day` is the Julian Day Number of the
+    /// [`NaiveDate`](NaiveDate::from_julian_day) on which it starts. `fraction` may be negative
+    /// or longer than a day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+    ///
+    /// let dt = NaiveDateTime::from_julian_date(2_451_545, TimeDelta::try_hours(13).unwrap());
+    /// assert_eq!(dt, NaiveDate::from_ymd_opt(2000, 1, 2).unwrap().and_hms_opt(1, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn from_julian_date(day: i32, fraction: TimeDelta) -> Option<NaiveDateTime> {
+        let days = TimeDelta::try_days(i64::from(day) - UNIX_EPOCH_JULIAN_DAY)?;
+        let since_epoch = days.checked_add(&HALF_DAY)?.checked_add(&fraction)?;
+        NaiveDateTime::UNIX_EPOCH.checked_add_signed(since_epoch)
+    }
+
+    /// Returns the Julian date, as the Julian Day Number and the time since noon of that Julian
+    /// day.
+    ///
+    /// The time since noon is at least zero and less than a day. This representation is exact,
+    /// unlike [`to_julian_date_f64`](NaiveDateTime::to_julian_date_f64).
+    ///
+    /// A [leap second](./struct.NaiveTime.html#leap-second-handling) has the same Julian date as
+    /// the second that follows it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, TimeDelta};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(6, 0, 0).unwrap();
+    /// assert_eq!(dt.to_julian_date(), (2_451_544, TimeDelta::try_hours(18).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn to_julian_date(&self) -> (i32, TimeDelta) {
+        let day = self.date.to_julian_day();
+        let since_midnight = self.time.signed_duration_since(NaiveTime::MIN);
+        if since_midnight >= HALF_DAY {
+            (day, since_midnight - HALF_DAY)
+        } else {
+            (day - 1, since_midnight + HALF_DAY)
+        }
+    }
+
+    /// Makes a new `NaiveDateTime` from a Julian date in days, as a floating point number.
+    ///
+    /// The time is rounded to the nearest nanosecond, but the precision of an `f64` is only
+    /// about 40 microseconds for current dates. Use
+    /// [`from_julian_date`](NaiveDateTime::from_julian_date) for an exact conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `julian_date` is not finite, or if the resulting date would be out of
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(18, 0, 0).unwrap();
+    /// assert_eq!(NaiveDateTime::from_julian_date_f64(2_451_545.25), Some(dt));
+    /// assert_eq!(NaiveDateTime::from_julian_date_f64(f64::NAN), None);
+    /// ```
+    #[must_use]
+    pub fn from_julian_date_f64(julian_date: f64) -> Option<NaiveDateTime> {
+        let (day, fraction) = split_days_f64(julian_date)?;
+        NaiveDateTime::from_julian_date(day, fraction)
+    }
+
+    /// Returns the Julian date in days, as a floating point number.
+    ///
+    /// The precision of an `f64` is only about 40 microseconds for current dates. Use
+    /// [`to_julian_date`](NaiveDateTime::to_julian_date) for an exact conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(18, 0, 0).unwrap();
+    /// assert_eq!(dt.to_julian_date_f64(), 2_451_545.25);
+    /// ```
+    #[must_use]
+    pub fn to_julian_date_f64(&self) -> f64 {
+        let (day, fraction) = self.to_julian_date();
+        f64::from(day) + days_f64(fraction)
+    }
+
+    /// Makes a new `NaiveDateTime` from a Modified Julian Date (MJD), given as the day number and
+    /// the time since midnight.
+    ///
+    /// The Modified Julian Date is the Julian date minus 2,400,000.5. Its days start at midnight,
+    /// and day 0 is November 17, 1858. `fraction` may be negative or longer than a day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+    ///
+    /// let dt = NaiveDateTime::from_mjd(60_000, TimeDelta::try_hours(6).unwrap());
+    /// assert_eq!(dt, NaiveDate::from_ymd_opt(2023, 2, 25).unwrap().and_hms_opt(6, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn from_mjd(day: i32, fraction: TimeDelta) -> Option<NaiveDateTime> {
+        let days = TimeDelta::try_days(i64::from(day) - UNIX_EPOCH_MJD)?;
+        NaiveDateTime::UNIX_EPOCH.checked_add_signed(days.checked_add(&fraction)?)
+    }
+
+    /// Returns the Modified Julian Date (MJD), as the day number and the time since midnight.
+    ///
+    /// The time since midnight is at least zero and less than a day. This representation is
+    /// exact, unlike [`to_mjd_f64`](NaiveDateTime::to_mjd_f64).
+    ///
+    /// A [leap second](./struct.NaiveTime.html#leap-second-handling) has the same Modified Julian
+    /// Date as the second that follows it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, TimeDelta};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(1858, 11, 17).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(dt.to_mjd(), (0, TimeDelta::try_hours(12).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn to_mjd(&self) -> (i32, TimeDelta) {
+        let day = self.date.to_julian_day() - MJD_JULIAN_DAY;
+        let since_midnight = self.time.signed_duration_since(NaiveTime::MIN);
+        if since_midnight >= FULL_DAY {
+            (day + 1, since_midnight - FULL_DAY)
+        } else {
+            (day, since_midnight)
+        }
+    }
+
+    /// Makes a new `NaiveDateTime` from a Modified Julian Date (MJD) in days, as a floating point
+    /// number.
+    ///
+    /// The time is rounded to the nearest nanosecond, but the precision of an `f64` is only
+    /// about 1 microsecond for current dates. Use [`from_mjd`](NaiveDateTime::from_mjd) for an
+    /// exact conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `mjd` is not finite, or if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2023, 2, 25).unwrap().and_hms_opt(6, 0, 0).unwrap();
+    /// assert_eq!(NaiveDateTime::from_mjd_f64(60_000.25), Some(dt));
+    /// ```
+    #[must_use]
+    pub fn from_mjd_f64(mjd: f64) -> Option<NaiveDateTime> {
+        let (day, fraction) = split_days_f64(mjd)?;
+        NaiveDateTime::from_mjd(day, fraction)
+    }
+
+    /// Returns the Modified Julian Date (MJD) in days, as a floating point number.
+    ///
+    /// The precision of an `f64` is only about 1 microsecond for current dates. Use
+    /// [`to_mjd`](NaiveDateTime::to_mjd) for an exact conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2023, 2, 25).unwrap().and_hms_opt(6, 0, 0).unwrap();
+    /// assert_eq!(dt.to_mjd_f64(), 60_000.25);
+    /// ```
+    #[must_use]
+    pub fn to_mjd_f64(&self) -> f64 {
+        let (day, fraction) = self.to_mjd();
+        f64::from(day) + days_f64(fraction)
+    }
+
     /// Adds given `TimeDelta` to the current date and time.
     ///
     /// As a part of Chrono's [leap second handling](./struct.NaiveTime.html#leap-second-handling),
@@ -767,6 +1063,43 @@ impl NaiveDateTime {
         Some(Self { date: try_opt!(self.date.checked_sub_days(days)), ..self })
     }
 
+    /// Add a [`Period`] to the `NaiveDateTime`.
+    ///
+    /// The years and months are added first, using the last day of the month if the day does not
+    /// exist in the resulting month. Then the days are added, followed by the time span of the
+    /// `Period`.
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Period, TimeDelta};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2022, 1, 31).unwrap().and_hms_opt(23, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_add_period(Period::new(0, 1, 1, TimeDelta::try_hours(2).unwrap())),
+    ///     Some(NaiveDate::from_ymd_opt(2022, 3, 2).unwrap().and_hms_opt(1, 0, 0).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn checked_add_period(self, period: Period) -> Option<Self> {
+        let date = try_opt!(self.date.diff_months(try_opt!(period.total_months())));
+        let date = try_opt!(date.add_days(period.num_days()));
+        NaiveDateTime { date, ..self }.checked_add_signed(period.time_delta())
+    }
+
+    /// Subtract a [`Period`] from the `NaiveDateTime`.
+    ///
+    /// The negated components are applied in the same order as in
+    /// [`checked_add_period`](#method.checked_add_period).
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub const fn checked_sub_period(self, period: Period) -> Option<Self> {
+        self.checked_add_period(try_opt!(period.checked_neg()))
+    }
+
     /// Subtracts another `NaiveDateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     ///
@@ -933,6 +1266,34 @@ impl NaiveDateTime {
         tz.from_local_datetime(self)
     }
 
+    /// Converts the `NaiveDateTime` into a timezone-aware `DateTime<Tz>` with the provided
+    /// timezone, resolving a local time that is ambiguous or does not exist in the timezone
+    /// with the given [`Disambiguation`] policy.
+    ///
+    /// See [`TimeZone::from_local_datetime_with`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Disambiguation, FixedOffset, NaiveDate};
+    /// let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2015, 9, 5)
+    ///     .unwrap()
+    ///     .and_hms_opt(23, 56, 4)
+    ///     .unwrap()
+    ///     .and_local_timezone_with(tz, Disambiguation::Compatible)
+    ///     .unwrap();
+    /// assert_eq!(dt.timezone(), tz);
+    /// ```
+    #[must_use]
+    pub fn and_local_timezone_with<Tz: TimeZone>(
+        &self,
+        tz: Tz,
+        policy: Disambiguation,
+    ) -> Option<DateTime<Tz>> {
+        tz.from_local_datetime_with(self, policy)
+    }
+
     /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Utc>`.
     ///
     /// # Example
@@ -2027,6 +2388,38 @@ impl Sub<Days> for NaiveDateTime {
     }
 }
 
+/// Add a `Period` to `NaiveDateTime`.
+///
+/// See [`NaiveDateTime::checked_add_period`] for the order in which the components are applied.
+///
+/// # Panics
+///
+/// Panics if the resulting date would be out of range.
+/// Consider using `checked_add_period` to get an `Option` instead.
+impl Add<Period> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    fn add(self, period: Period) -> Self::Output {
+        self.checked_add_period(period).expect("`NaiveDateTime + Period` out of range")
+    }
+}
+
+/// Subtract a `Period` from `NaiveDateTime`.
+///
+/// See [`NaiveDateTime::checked_sub_period`] for the order in which the components are applied.
+///
+/// # Panics
+///
+/// Panics if the resulting date would be out of range.
+/// Consider using `checked_sub_period` to get an `Option` instead.
+impl Sub<Period> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    fn sub(self, period: Period) -> Self::Output {
+        self.checked_sub_period(period).expect("`NaiveDateTime - Period` out of range")
+    }
+}
+
 /// The `Debug` output of the naive date and time `dt` is the same as
 /// [`dt.format("%Y-%m-%dT%H:%M:%S%.f")`](crate::format::strftime).
 ///
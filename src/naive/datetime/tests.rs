@@ -407,3 +407,93 @@ fn test_rkyv_validation() {
     let bytes = rkyv::to_bytes::<_, 12>(&dt_max).unwrap();
     assert_eq!(rkyv::from_bytes::<NaiveDateTime>(&bytes).unwrap(), dt_max);
 }
+
+#[test]
+fn test_datetime_julian_date() {
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let hours = |h| TimeDelta::try_hours(h).unwrap();
+
+    for (dt, julian_date, mjd) in [
+        (ymdhms(-4713, 11, 24, 12, 0, 0), (0, hours(0)), (-2_400_001, hours(12))),
+        (ymdhms(1858, 11, 17, 0, 0, 0), (2_400_000, hours(12)), (0, hours(0))),
+        (ymdhms(1970, 1, 1, 0, 0, 0), (2_440_587, hours(12)), (40_587, hours(0))),
+        (ymdhms(2000, 1, 1, 12, 0, 0), (2_451_545, hours(0)), (51_544, hours(12))),
+        (ymdhms(2023, 2, 25, 6, 0, 0), (2_460_000, hours(18)), (60_000, hours(6))),
+    ] {
+        assert_eq!(dt.to_julian_date(), julian_date);
+        assert_eq!(NaiveDateTime::from_julian_date(julian_date.0, julian_date.1), Some(dt));
+        assert_eq!(dt.to_mjd(), mjd);
+        assert_eq!(NaiveDateTime::from_mjd(mjd.0, mjd.1), Some(dt));
+    }
+
+    // the fraction may be negative or longer than a day
+    let dt = ymdhms(2000, 1, 1, 12, 0, 0);
+    assert_eq!(NaiveDateTime::from_julian_date(2_451_546, hours(-24)), Some(dt));
+    assert_eq!(NaiveDateTime::from_julian_date(2_451_543, hours(48)), Some(dt));
+    assert_eq!(NaiveDateTime::from_mjd(51_545, hours(-12)), Some(dt));
+    assert_eq!(NaiveDateTime::from_mjd(51_542, hours(60)), Some(dt));
+
+    // a leap second has the same representation as the following second
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500);
+    let after = NaiveDate::from_ymd_opt(2017, 1, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500);
+    assert_eq!(leap.unwrap().to_julian_date(), after.unwrap().to_julian_date());
+    assert_eq!(leap.unwrap().to_mjd(), after.unwrap().to_mjd());
+
+    for dt in [NaiveDateTime::MIN, NaiveDateTime::MAX] {
+        let (day, fraction) = dt.to_julian_date();
+        assert_eq!(NaiveDateTime::from_julian_date(day, fraction), Some(dt));
+        let (day, fraction) = dt.to_mjd();
+        assert_eq!(NaiveDateTime::from_mjd(day, fraction), Some(dt));
+    }
+    let (day, fraction) = NaiveDateTime::MAX.to_julian_date();
+    assert_eq!(NaiveDateTime::from_julian_date(day, fraction + TimeDelta::nanoseconds(1)), None);
+    let (day, fraction) = NaiveDateTime::MIN.to_mjd();
+    assert_eq!(NaiveDateTime::from_mjd(day, fraction - TimeDelta::nanoseconds(1)), None);
+    assert_eq!(NaiveDateTime::from_julian_date(i32::MAX, TimeDelta::zero()), None);
+    assert_eq!(NaiveDateTime::from_mjd(i32::MIN, TimeDelta::zero()), None);
+}
+
+#[test]
+fn test_datetime_julian_date_f64() {
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+
+    for (dt, julian_date, mjd) in [
+        (ymdhms(-4713, 11, 24, 12, 0, 0), 0.0, -2_400_000.5),
+        (ymdhms(1858, 11, 17, 0, 0, 0), 2_400_000.5, 0.0),
+        (ymdhms(2000, 1, 1, 18, 0, 0), 2_451_545.25, 51_544.75),
+        (ymdhms(2023, 2, 25, 6, 0, 0), 2_460_000.75, 60_000.25),
+        (ymdhms(-100, 3, 1, 3, 0, 0), 1_684_594.625, -715_405.875),
+    ] {
+        assert_eq!(dt.to_julian_date_f64(), julian_date);
+        assert_eq!(NaiveDateTime::from_julian_date_f64(julian_date), Some(dt));
+        assert_eq!(dt.to_mjd_f64(), mjd);
+        assert_eq!(NaiveDateTime::from_mjd_f64(mjd), Some(dt));
+    }
+
+    // rounded to the nearest nanosecond
+    let dt = NaiveDateTime::from_mjd_f64(0.1).unwrap();
+    assert_eq!(dt, ymdhms(1858, 11, 17, 2, 24, 0));
+
+    // precision is lost for large values, but it should stay close
+    let dt = ymdhms(2024, 3, 1, 13, 14, 15) + TimeDelta::try_milliseconds(678).unwrap();
+    let diff = NaiveDateTime::from_julian_date_f64(dt.to_julian_date_f64()).unwrap() - dt;
+    assert!(diff.abs() < TimeDelta::microseconds(50));
+    let diff = NaiveDateTime::from_mjd_f64(dt.to_mjd_f64()).unwrap() - dt;
+    assert!(diff.abs() < TimeDelta::microseconds(5));
+
+    let min = NaiveDate::MIN.and_hms_opt(12, 0, 0).unwrap();
+    let max = NaiveDate::MAX.and_hms_opt(12, 0, 0).unwrap();
+    for dt in [min, max] {
+        assert_eq!(NaiveDateTime::from_julian_date_f64(dt.to_julian_date_f64()), Some(dt));
+        assert_eq!(NaiveDateTime::from_mjd_f64(dt.to_mjd_f64()), Some(dt));
+    }
+    assert_eq!(NaiveDateTime::from_julian_date_f64(min.to_julian_date_f64() - 1.0), None);
+    assert_eq!(NaiveDateTime::from_mjd_f64(max.to_mjd_f64() + 1.0), None);
+
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e10, -1e10] {
+        assert_eq!(NaiveDateTime::from_julian_date_f64(value), None);
+        assert_eq!(NaiveDateTime::from_mjd_f64(value), None);
+    }
+}
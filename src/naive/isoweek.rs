@@ -160,6 +160,124 @@ impl fmt::Debug for IsoWeek {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde {
+    use super::IsoWeek;
+    use crate::naive::date::{NaiveDate, MAX_YEAR, MIN_YEAR};
+    use crate::{Datelike, Weekday};
+    use core::fmt;
+    use serde::{de, ser};
+
+    /// Serialize into an ISO 8601 week string, such as `2015-W36`.
+    impl ser::Serialize for IsoWeek {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            struct FormatWrapped<'a, D: 'a> {
+                inner: &'a D,
+            }
+
+            impl<'a, D: fmt::Debug> fmt::Display for FormatWrapped<'a, D> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.inner.fmt(f)
+                }
+            }
+
+            serializer.collect_str(&FormatWrapped { inner: &self })
+        }
+    }
+
+    struct IsoWeekVisitor;
+
+    impl<'de> de::Visitor<'de> for IsoWeekVisitor {
+        type Value = IsoWeek;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an ISO 8601 week string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let invalid = || E::invalid_value(de::Unexpected::Str(value), &self);
+            let (year, week) = value.rsplit_once("-W").ok_or_else(invalid)?;
+            if week.len() != 2 || !week.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            let year = year.parse::<i32>().map_err(|_| invalid())?;
+            let week = week.parse::<u32>().map_err(|_| invalid())?;
+            if !(MIN_YEAR..=MAX_YEAR + 1).contains(&year) {
+                return Err(invalid());
+            }
+            // The first or last week in range is only partially covered by `NaiveDate`.
+            NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+                .or_else(|| NaiveDate::from_isoywd_opt(year, week, Weekday::Sun))
+                .map(|date| date.iso_week())
+                .ok_or_else(invalid)
+        }
+    }
+
+    /// Deserialize from an ISO 8601 week string, such as `2015-W36`.
+    impl<'de> de::Deserialize<'de> for IsoWeek {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_str(IsoWeekVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::naive::{IsoWeek, NaiveDate};
+        use crate::Datelike;
+
+        #[test]
+        fn test_serde_serialize() {
+            let week = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().iso_week();
+            assert_eq!(serde_json::to_string(&week).unwrap(), r#""2015-W36""#);
+            let week = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap().iso_week();
+            assert_eq!(serde_json::to_string(&week).unwrap(), r#""-0002-W53""#);
+        }
+
+        #[test]
+        fn test_serde_deserialize() {
+            let from_str = |s| serde_json::from_str::<IsoWeek>(s);
+            let week = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().iso_week();
+            assert_eq!(from_str(r#""2015-W36""#).unwrap(), week);
+            let week = NaiveDate::from_ymd_opt(2020, 12, 31).unwrap().iso_week();
+            assert_eq!(from_str(r#""2020-W53""#).unwrap(), week);
+            let week = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap().iso_week();
+            assert_eq!(from_str(r#""-0002-W53""#).unwrap(), week);
+
+            for week in [NaiveDate::MIN.iso_week(), NaiveDate::MAX.iso_week()] {
+                let json = serde_json::to_string(&week).unwrap();
+                assert_eq!(serde_json::from_str::<IsoWeek>(&json).unwrap(), week);
+            }
+
+            assert!(from_str(r#""2015-W1""#).is_err());
+            assert!(from_str(r#""2015-W00""#).is_err());
+            assert!(from_str(r#""2019-W53""#).is_err());
+            assert!(from_str(r#""2015W36""#).is_err());
+            assert!(from_str(r#""2015-W36-1""#).is_err());
+            assert!(from_str(r#""+999999-W01""#).is_err());
+            assert!(from_str("201536").is_err());
+        }
+
+        #[test]
+        fn test_serde_bincode() {
+            use bincode::{deserialize, serialize};
+
+            let week = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().iso_week();
+            let encoded = serialize(&week).unwrap();
+            let decoded: IsoWeek = deserialize(&encoded).unwrap();
+            assert_eq!(week, decoded);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "rkyv-validation")]
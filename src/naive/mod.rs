@@ -141,6 +141,147 @@ impl Days {
     }
 }
 
+#[cfg(feature = "serde")]
+mod days_serde {
+    use super::Days;
+    use serde::{de, ser};
+
+    /// Serialize into the number of days as an integer.
+    impl ser::Serialize for Days {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_u64(self.0)
+        }
+    }
+
+    /// Deserialize from the number of days as an integer.
+    impl<'de> de::Deserialize<'de> for Days {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            u64::deserialize(deserializer).map(Days)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod naive_week_serde {
+    use super::{NaiveDate, NaiveWeek};
+    use crate::Weekday;
+    use core::fmt;
+    use serde::ser::SerializeStruct;
+    use serde::{de, ser};
+
+    const FIELDS: &[&str] = &["date", "start"];
+
+    /// Serialize into a struct with the `date` in the week and the `start` day of the week.
+    impl ser::Serialize for NaiveWeek {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            let mut state = serializer.serialize_struct("NaiveWeek", 2)?;
+            state.serialize_field("date", &self.date)?;
+            state.serialize_field("start", &self.start)?;
+            state.end()
+        }
+    }
+
+    enum Field {
+        Date,
+        Start,
+    }
+
+    struct FieldVisitor;
+
+    impl<'de> de::Visitor<'de> for FieldVisitor {
+        type Value = Field;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("`date` or `start`")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match value {
+                "date" => Ok(Field::Date),
+                "start" => Ok(Field::Start),
+                _ => Err(E::unknown_field(value, FIELDS)),
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Field {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct NaiveWeekVisitor;
+
+    impl<'de> de::Visitor<'de> for NaiveWeekVisitor {
+        type Value = NaiveWeek;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("struct NaiveWeek")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let date = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let start = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            Ok(NaiveWeek::new(date, start))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut date: Option<NaiveDate> = None;
+            let mut start: Option<Weekday> = None;
+            while let Some(key) = map.next_key()? {
+                match key {
+                    Field::Date => {
+                        if date.is_some() {
+                            return Err(de::Error::duplicate_field("date"));
+                        }
+                        date = Some(map.next_value()?);
+                    }
+                    Field::Start => {
+                        if start.is_some() {
+                            return Err(de::Error::duplicate_field("start"));
+                        }
+                        start = Some(map.next_value()?);
+                    }
+                }
+            }
+            let date = date.ok_or_else(|| de::Error::missing_field("date"))?;
+            let start = start.ok_or_else(|| de::Error::missing_field("start"))?;
+            Ok(NaiveWeek::new(date, start))
+        }
+    }
+
+    /// Deserialize from a struct with the `date` in the week and the `start` day of the week.
+    impl<'de> de::Deserialize<'de> for NaiveWeek {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("NaiveWeek", FIELDS, NaiveWeekVisitor)
+        }
+    }
+}
+
 /// Serialization/Deserialization of naive types in alternate formats
 ///
 /// The various modules in here are intended to be used with serde's [`with`
@@ -185,4 +326,43 @@ mod test {
         let date_min = NaiveDate::MIN;
         assert!(date_min.week(Weekday::Mon).last_day() >= date_min);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_days() {
+        use crate::Days;
+
+        assert_eq!(serde_json::to_string(&Days::new(3)).unwrap(), "3");
+        assert_eq!(serde_json::from_str::<Days>("3").unwrap(), Days::new(3));
+        assert!(serde_json::from_str::<Days>("-3").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_naiveweek() {
+        use crate::naive::NaiveWeek;
+
+        let week = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap().week(Weekday::Sun);
+        let json = serde_json::to_string(&week).unwrap();
+        assert_eq!(json, r#"{"date":"2022-05-18","start":"Sun"}"#);
+
+        let decoded: NaiveWeek = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.first_day(), week.first_day());
+        assert_eq!(decoded.last_day(), week.last_day());
+        let decoded: NaiveWeek =
+            serde_json::from_str(r#"{"start":"Mon","date":"2022-05-18"}"#).unwrap();
+        assert_eq!(decoded.first_day(), NaiveDate::from_ymd_opt(2022, 5, 16).unwrap());
+
+        assert!(serde_json::from_str::<NaiveWeek>(r#"{"date":"2022-05-18"}"#).is_err());
+        assert!(serde_json::from_str::<NaiveWeek>(r#"{"date":"2022-05-18","start":"Sun","x":1}"#)
+            .is_err());
+        assert!(serde_json::from_str::<NaiveWeek>(
+            r#"{"date":"2022-05-18","date":"2022-05-18","start":"Sun"}"#
+        )
+        .is_err());
+
+        let encoded = bincode::serialize(&week).unwrap();
+        let decoded: NaiveWeek = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded.first_day(), week.first_day());
+    }
 }
@@ -18,6 +18,8 @@ use crate::format::{
     parse, parse_and_remainder, write_hundreds, Fixed, Item, Numeric, Pad, ParseError, ParseResult,
     Parsed, StrftimeItems,
 };
+#[cfg(feature = "unstable-locales")]
+use crate::format::{parse_localized, Locale};
 use crate::{expect, try_opt};
 use crate::{FixedOffset, TimeDelta, Timelike};
 
@@ -561,6 +563,28 @@ impl NaiveTime {
         parsed.to_naive_time()
     }
 
+    /// Parses a string with the specified format string and locale into a new `NaiveTime`.
+    ///
+    /// AM/PM is parsed with the designators of `locale`, ignoring case and accents. See
+    /// [`format::parse_localized`](crate::format::parse_localized) for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Locale, NaiveTime};
+    ///
+    /// assert_eq!(
+    ///     NaiveTime::parse_from_str_localized("오후 3:07", "%p %I:%M", Locale::ko_KR),
+    ///     Ok(NaiveTime::from_hms_opt(15, 7, 0).unwrap())
+    /// );
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    pub fn parse_from_str_localized(s: &str, fmt: &str, locale: Locale) -> ParseResult<NaiveTime> {
+        let mut parsed = Parsed::new();
+        parse_localized(&mut parsed, s, StrftimeItems::new_with_locale(fmt, locale), locale)?;
+        parsed.to_naive_time()
+    }
+
     /// Parses a string from a user-specified format into a new `NaiveTime` value, and a slice with
     /// the remaining portion of the string.
     /// See the [`format::strftime` module](crate::format::strftime)
@@ -1621,10 +1645,11 @@ impl str::FromStr for NaiveTime {
         const TRAILING_WHITESPACE: [Item<'static>; 1] = [Item::Space("")];
 
         let mut parsed = Parsed::new();
-        let s = parse_and_remainder(&mut parsed, s, HOUR_AND_MINUTE.iter())?;
+        let rest = parse_and_remainder(&mut parsed, s, HOUR_AND_MINUTE.iter())?;
         // Seconds are optional, don't fail if parsing them doesn't succeed.
-        let s = parse_and_remainder(&mut parsed, s, SECOND_AND_NANOS.iter()).unwrap_or(s);
-        parse(&mut parsed, s, TRAILING_WHITESPACE.iter())?;
+        let rest = parse_and_remainder(&mut parsed, rest, SECOND_AND_NANOS.iter()).unwrap_or(rest);
+        // Report the position of trailing input relative to all of `s`.
+        parse(&mut parsed, rest, TRAILING_WHITESPACE.iter()).map_err(|e| e.locate(s))?;
         parsed.to_naive_time()
     }
 }
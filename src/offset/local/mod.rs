@@ -88,9 +88,6 @@ mod inner {
     }
 }
 
-#[cfg(unix)]
-mod tz_info;
-
 /// The local timescale.
 ///
 /// Using the [`TimeZone`](./trait.TimeZone.html) methods
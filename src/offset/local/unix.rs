@@ -10,8 +10,8 @@
 
 use std::{cell::RefCell, collections::hash_map, env, fs, hash::Hasher, time::SystemTime};
 
-use super::tz_info::TimeZone;
 use super::{FixedOffset, NaiveDateTime};
+use crate::offset::tz_info::TimeZone;
 use crate::{Datelike, LocalResult};
 
 pub(super) fn offset_from_utc_datetime(utc: &NaiveDateTime) -> LocalResult<FixedOffset> {
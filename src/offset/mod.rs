@@ -22,9 +22,9 @@ use core::fmt;
 
 use crate::format::{parse, ParseResult, Parsed, StrftimeItems};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
-use crate::Weekday;
 #[allow(deprecated)]
 use crate::{Date, DateTime};
+use crate::{TimeDelta, Weekday};
 
 pub(crate) mod fixed;
 pub use self::fixed::FixedOffset;
@@ -37,6 +37,11 @@ pub use self::local::Local;
 pub(crate) mod utc;
 pub use self::utc::Utc;
 
+#[cfg(feature = "std")]
+pub(crate) mod tz_info;
+#[cfg(feature = "std")]
+pub use self::tz_info::{TzInfo, TzInfoError, TzInfoOffset, TzInfoTransition, TzInfoTransitions};
+
 /// The conversion result from the local time to the timezone-aware datetime types.
 #[derive(Clone, PartialEq, Debug, Copy, Eq, Hash)]
 pub enum LocalResult<T> {
@@ -203,10 +208,97 @@ impl<T: fmt::Debug> LocalResult<T> {
     }
 }
 
+impl<Tz: TimeZone> LocalResult<DateTime<Tz>> {
+    /// Resolves the result to a single `DateTime` according to the [`Disambiguation`] policy.
+    ///
+    /// An ambiguous result is resolved by comparing the instants of both candidates, so this
+    /// works regardless of the order in which a time zone returns them.
+    ///
+    /// `LocalResult::None` carries no information about the gap it fell in and always resolves
+    /// to `None`. Use [`TimeZone::from_local_datetime_with`] or
+    /// [`NaiveDateTime::and_local_timezone_with`] to also resolve non-existent local times.
+    #[must_use]
+    pub fn resolve(self, policy: Disambiguation) -> Option<DateTime<Tz>> {
+        match self {
+            LocalResult::None => None,
+            LocalResult::Single(t) => Some(t),
+            LocalResult::Ambiguous(t1, t2) => {
+                let (earlier, later) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+                match policy {
+                    Disambiguation::Earlier | Disambiguation::Compatible => Some(earlier),
+                    Disambiguation::Later => Some(later),
+                    Disambiguation::Reject => None,
+                }
+            }
+        }
+    }
+}
+
+/// Policy for converting a local time that is ambiguous or does not exist in a time zone.
+///
+/// Around an offset transition a local time can occur twice (when clocks are set back, a fold)
+/// or not at all (when clocks are set forward, a gap). The variants follow the `disambiguation`
+/// option of the JavaScript Temporal proposal.
+///
+/// | Policy       | Local time in a fold  | Local time in a gap                      |
+/// |--------------|-----------------------|------------------------------------------|
+/// | `Earlier`    | the earlier instant   | moved back by the length of the gap      |
+/// | `Later`      | the later instant     | moved forward by the length of the gap   |
+/// | `Compatible` | the earlier instant   | moved forward by the length of the gap   |
+/// | `Reject`     | no result             | no result                                |
+///
+/// `Compatible` matches the behavior of RFC 5545 (iCalendar) and of most operating systems.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    /// Use the earlier of two instants; shift a time in a gap backward.
+    Earlier,
+    /// Use the later of two instants; shift a time in a gap forward.
+    Later,
+    /// Use the earlier of two instants; shift a time in a gap forward.
+    Compatible,
+    /// Do not return a result for an ambiguous or non-existent local time.
+    Reject,
+}
+
+/// Resolves a local time that falls in a gap of `tz` according to `policy`.
+fn resolve_gap<Tz: TimeZone>(
+    tz: &Tz,
+    local: &NaiveDateTime,
+    policy: Disambiguation,
+) -> Option<DateTime<Tz>> {
+    if policy == Disambiguation::Reject {
+        return None;
+    }
+    // The offsets one day before and after `local` are the offsets on either side of the gap,
+    // assuming a time zone does not have two transitions less than a day apart.
+    let day = TimeDelta::try_days(1)?;
+    let before = tz.offset_from_utc_datetime(&local.checked_sub_signed(day)?).fix();
+    let after = tz.offset_from_utc_datetime(&local.checked_add_signed(day)?).fix();
+    if after.local_minus_utc() <= before.local_minus_utc() {
+        return None;
+    }
+    // Interpreting the local time with the offset after the gap gives an instant before the
+    // transition, and vice versa.
+    let utc = match policy {
+        Disambiguation::Earlier => local.checked_sub_offset(after)?,
+        _ => local.checked_sub_offset(before)?,
+    };
+    Some(tz.from_utc_datetime(&utc))
+}
+
 /// The offset from the local time to UTC.
 pub trait Offset: Sized + Clone + fmt::Debug {
     /// Returns the fixed offset from UTC to the local time stored.
     fn fix(&self) -> FixedOffset;
+
+    /// Returns the identifier of the time zone of this offset, such as `Europe/Prague`, if it
+    /// has one.
+    ///
+    /// This is written by the `%:Z` formatting specifier. The default implementation returns
+    /// `None`, in which case `%:Z` writes the same as `%Z`.
+    fn time_zone_id(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// The time zone.
@@ -527,6 +619,45 @@ pub trait TimeZone: Sized + Clone {
     /// Creates the offset for given UTC `NaiveDateTime`. This cannot fail.
     fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset;
 
+    /// Converts the local `NaiveDateTime` to the timezone-aware `DateTime`, resolving a local
+    /// time that is ambiguous or does not exist with the given [`Disambiguation`] policy.
+    ///
+    /// For a local time in a gap the offsets on both sides of the gap are looked up, and the
+    /// local time is shifted by the length of the gap.
+    ///
+    /// Returns `None` if the policy is [`Disambiguation::Reject`] and the local time is not
+    /// unique, or if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    #[cfg_attr(feature = "std", doc = "```")]
+    /// use chrono::{Disambiguation, NaiveDate, TimeZone, TzInfo};
+    ///
+    /// let prague = TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    /// let gap = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().and_hms_opt(2, 30, 0).unwrap();
+    /// let dt = prague.from_local_datetime_with(&gap, Disambiguation::Compatible).unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2024-03-31T03:30:00+02:00");
+    /// let dt = prague.from_local_datetime_with(&gap, Disambiguation::Earlier).unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2024-03-31T01:30:00+01:00");
+    ///
+    /// let fold = NaiveDate::from_ymd_opt(2024, 10, 27).unwrap().and_hms_opt(2, 30, 0).unwrap();
+    /// let dt = prague.from_local_datetime_with(&fold, Disambiguation::Later).unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2024-10-27T02:30:00+01:00");
+    /// assert!(prague.from_local_datetime_with(&fold, Disambiguation::Reject).is_none());
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_local_datetime_with(
+        &self,
+        local: &NaiveDateTime,
+        policy: Disambiguation,
+    ) -> Option<DateTime<Self>> {
+        match self.from_local_datetime(local) {
+            LocalResult::None => resolve_gap(self, local, policy),
+            result => result.resolve(policy),
+        }
+    }
+
     /// Converts the UTC `NaiveDate` to the local time.
     /// The UTC is continuous and thus this cannot fail (but can give the duplicate local time).
     #[allow(clippy::wrong_self_convention)]
@@ -623,9 +754,9 @@ mod tests {
 
     #[test]
     fn test_nanos_never_panics() {
-        Utc.timestamp_nanos(i64::max_value());
+        Utc.timestamp_nanos(i64::MAX);
         Utc.timestamp_nanos(i64::default());
-        Utc.timestamp_nanos(i64::min_value());
+        Utc.timestamp_nanos(i64::MIN);
     }
 
     #[test]
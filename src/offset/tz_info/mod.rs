@@ -13,6 +13,9 @@ pub(crate) use timezone::TimeZone;
 mod parser;
 mod rule;
 
+mod tz;
+pub use tz::{TzInfo, TzInfoError, TzInfoOffset, TzInfoTransition, TzInfoTransitions};
+
 /// Unified error type for everything in the crate
 #[derive(Debug)]
 pub(crate) enum Error {
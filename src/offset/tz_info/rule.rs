@@ -93,6 +93,26 @@ impl TransitionRule {
             }
         }
     }
+
+    /// Find the first transition of the transition rule strictly after the specified Unix time in seconds
+    pub(super) fn find_next_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        match self {
+            TransitionRule::Fixed(_) => Ok(None),
+            TransitionRule::Alternate(alternate_time) => {
+                alternate_time.find_next_transition(unix_time)
+            }
+        }
+    }
+
+    /// Find the last transition of the transition rule strictly before the specified Unix time in seconds
+    pub(super) fn find_prev_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        match self {
+            TransitionRule::Fixed(_) => Ok(None),
+            TransitionRule::Alternate(alternate_time) => {
+                alternate_time.find_prev_transition(unix_time)
+            }
+        }
+    }
 }
 
 impl From<LocalTimeType> for TransitionRule {
@@ -156,7 +176,7 @@ impl AlternateTime {
         };
 
         // Check if the current year is valid for the following computations
-        if !(i32::min_value() + 2 <= current_year && current_year <= i32::max_value() - 2) {
+        if !(i32::MIN + 2..=i32::MAX - 2).contains(&current_year) {
             return Err(Error::OutOfRange("out of range date time"));
         }
 
@@ -227,13 +247,71 @@ impl AlternateTime {
         }
     }
 
+    /// Find the first transition of the alternate transition rule strictly after the specified Unix time in seconds
+    fn find_next_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        let current_year = UtcDateTime::from_timespec(unix_time)?.year;
+        if !(i32::MIN + 2..=i32::MAX - 3).contains(&current_year) {
+            return Err(Error::OutOfRange("out of range date time"));
+        }
+
+        // Transition day times may be outside of [0h, 24h], so start with the previous year.
+        // Transitions that do not change the local time type (as in a rule describing DST
+        // all year) are skipped; if there are none in the next two years there are none at all.
+        let mut transitions = self.transitions_in_years(current_year - 1, current_year + 2);
+        transitions.sort_unstable();
+        for transition in transitions {
+            if transition > unix_time && self.is_effective_transition(transition)? {
+                return Ok(Some(transition));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the last transition of the alternate transition rule strictly before the specified Unix time in seconds
+    fn find_prev_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        let current_year = UtcDateTime::from_timespec(unix_time)?.year;
+        if !(i32::MIN + 3..=i32::MAX - 2).contains(&current_year) {
+            return Err(Error::OutOfRange("out of range date time"));
+        }
+
+        let mut transitions = self.transitions_in_years(current_year - 2, current_year + 1);
+        transitions.sort_unstable();
+        for transition in transitions.into_iter().rev() {
+            if transition < unix_time && self.is_effective_transition(transition)? {
+                return Ok(Some(transition));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the Unix times of the DST start and end transitions in the inclusive range of years
+    fn transitions_in_years(&self, first_year: i32, last_year: i32) -> Vec<i64> {
+        // Overflow is not possible
+        let dst_start_time_in_utc = self.dst_start_time as i64 - self.std.ut_offset as i64;
+        let dst_end_time_in_utc = self.dst_end_time as i64 - self.dst.ut_offset as i64;
+
+        (first_year..=last_year)
+            .flat_map(|year| {
+                [
+                    self.dst_start.unix_time(year, dst_start_time_in_utc),
+                    self.dst_end.unix_time(year, dst_end_time_in_utc),
+                ]
+            })
+            .collect()
+    }
+
+    /// Check if the local time type changes at the specified Unix time in seconds
+    fn is_effective_transition(&self, unix_time: i64) -> Result<bool, Error> {
+        Ok(self.find_local_time_type(unix_time - 1)? != self.find_local_time_type(unix_time)?)
+    }
+
     fn find_local_time_type_from_local(
         &self,
         local_time: i64,
         current_year: i32,
     ) -> Result<crate::LocalResult<LocalTimeType>, Error> {
         // Check if the current year is valid for the following computations
-        if !(i32::min_value() + 2 <= current_year && current_year <= i32::max_value() - 2) {
+        if !(i32::MIN + 2..=i32::MAX - 2).contains(&current_year) {
             return Err(Error::OutOfRange("out of range date time"));
         }
 
@@ -687,7 +765,7 @@ impl UtcDateTime {
         let minute = (remaining_seconds / SECONDS_PER_MINUTE) % MINUTES_PER_HOUR;
         let second = remaining_seconds % SECONDS_PER_MINUTE;
 
-        let year = match year >= i32::min_value() as i64 && year <= i32::max_value() as i64 {
+        let year = match year >= i32::MIN as i64 && year <= i32::MAX as i64 {
             true => year as i32,
             false => return Err(Error::OutOfRange("i64 is out of range for i32")),
         };
@@ -870,6 +948,10 @@ mod tests {
             )?
             .into()
         );
+
+        let transition_rule = TransitionRule::from_tz_string(tz_string, true)?;
+        assert_eq!(transition_rule.find_next_transition(1704067200)?, None);
+        assert_eq!(transition_rule.find_prev_transition(1704067200)?, None);
         Ok(())
     }
 
@@ -937,6 +1019,11 @@ mod tests {
         assert_eq!(transition_rule_dst.find_local_time_type(953384400)?.offset(), 43200);
         assert_eq!(transition_rule_dst.find_local_time_type(970322399)?.offset(), 43200);
         assert_eq!(transition_rule_dst.find_local_time_type(970322400)?.offset(), 46800);
+        assert_eq!(transition_rule_dst.find_next_transition(953000000)?, Some(953384400));
+        assert_eq!(transition_rule_dst.find_next_transition(953384400)?, Some(970322400));
+        assert_eq!(transition_rule_dst.find_prev_transition(970322400)?, Some(953384400));
+        assert_eq!(transition_rule_dst.find_prev_transition(970322401)?, Some(970322400));
+        assert_eq!(transition_rule_fixed.find_next_transition(0)?, None);
 
         let transition_rule_negative_dst = TransitionRule::from(AlternateTime::new(
             LocalTimeType::new(3600, false, Some(b"IST"))?,
@@ -43,7 +43,7 @@ impl TimeZone {
         }
 
         // attributes are not allowed on if blocks in Rust 1.38
-        #[cfg(target_os = "android")]
+        #[cfg(all(target_os = "android", feature = "clock"))]
         {
             if let Ok(bytes) = android_tzdata::find_tz_data(tz_string) {
                 return Self::from_tz_data(&bytes);
@@ -59,6 +59,42 @@ impl TimeZone {
             return Self::from_file(&mut file);
         }
 
+        Self::from_tz_string(tz_string)
+    }
+
+    /// Construct a time zone from a time zone name in the system time zone database
+    pub(crate) fn from_zoneinfo(name: &str) -> Result<Self, Error> {
+        #[cfg(all(target_os = "android", feature = "clock"))]
+        {
+            if let Ok(bytes) = android_tzdata::find_tz_data(name) {
+                return Self::from_tz_data(&bytes);
+            }
+        }
+
+        Self::from_file(&mut find_tz_file(name)?)
+    }
+
+    /// Construct a time zone from a relative time zone name, only looking in the system time zone
+    /// directories
+    ///
+    /// Fails on platforms without such directories.
+    pub(crate) fn from_zoneinfo_directories(name: &str) -> Result<Self, Error> {
+        #[cfg(all(target_os = "android", feature = "clock"))]
+        {
+            if let Ok(bytes) = android_tzdata::find_tz_data(name) {
+                return Self::from_tz_data(&bytes);
+            }
+        }
+
+        Self::from_file(&mut find_in_zoneinfo_directories(Path::new(name))?)
+    }
+
+    /// Construct a time zone from the rule part of a POSIX TZ string, without looking up any file
+    pub(crate) fn from_tz_string(tz_string: &str) -> Result<Self, Error> {
+        if tz_string.is_empty() {
+            return Err(Error::InvalidTzString("empty TZ string"));
+        }
+
         // TZ string extensions are not allowed
         let tz_string = tz_string.trim_matches(|c: char| c.is_ascii_whitespace());
         let rule = TransitionRule::from_tz_string(tz_string.as_bytes(), false)?;
@@ -85,6 +121,18 @@ impl TimeZone {
         Ok(new)
     }
 
+    /// Returns all local time types, including those of the extra transition rule
+    pub(crate) fn local_time_types(&self) -> impl Iterator<Item = &LocalTimeType> {
+        let (std, dst) = match self.extra_rule {
+            Some(TransitionRule::Fixed(ref local_time_type)) => (Some(local_time_type), None),
+            Some(TransitionRule::Alternate(ref alternate_time)) => {
+                (Some(&alternate_time.std), Some(&alternate_time.dst))
+            }
+            None => (None, None),
+        };
+        self.local_time_types.iter().chain(std).chain(dst)
+    }
+
     /// Construct a time zone from the contents of a time zone file
     fn from_file(file: &mut File) -> Result<Self, Error> {
         let mut bytes = Vec::new();
@@ -133,8 +181,18 @@ impl TimeZone {
         self.as_ref().find_local_time_type_from_local(local_time, year)
     }
 
+    /// Find the first transition strictly after the specified Unix time in seconds
+    pub(crate) fn find_next_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        self.as_ref().find_next_transition(unix_time)
+    }
+
+    /// Find the last transition strictly before the specified Unix time in seconds
+    pub(crate) fn find_prev_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        self.as_ref().find_prev_transition(unix_time)
+    }
+
     /// Returns a reference to the time zone
-    fn as_ref(&self) -> TimeZoneRef {
+    fn as_ref(&self) -> TimeZoneRef<'_> {
         TimeZoneRef {
             transitions: &self.transitions,
             local_time_types: &self.local_time_types,
@@ -302,6 +360,74 @@ impl<'a> TimeZoneRef<'a> {
         }
     }
 
+    /// Find the first transition strictly after the specified Unix time in seconds
+    ///
+    /// Transitions which do not change the local time type are skipped.
+    pub(crate) fn find_next_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        let unix_leap_time = self.unix_time_to_unix_leap_time(unix_time)?;
+        let mut index = self.transitions.partition_point(|t| t.unix_leap_time <= unix_leap_time);
+        while index < self.transitions.len() {
+            if self.is_effective_transition(index) {
+                let transition = self.transitions[index];
+                return self.unix_leap_time_to_unix_time(transition.unix_leap_time).map(Some);
+            }
+            index += 1;
+        }
+
+        // The extra rule only applies after the last transition
+        let extra_rule = match self.extra_rule {
+            Some(extra_rule) => extra_rule,
+            None => return Ok(None),
+        };
+        match self.transitions.last() {
+            Some(last) => {
+                let last_unix_time = self.unix_leap_time_to_unix_time(last.unix_leap_time)?;
+                extra_rule.find_next_transition(unix_time.max(last_unix_time))
+            }
+            None => extra_rule.find_next_transition(unix_time),
+        }
+    }
+
+    /// Find the last transition strictly before the specified Unix time in seconds
+    ///
+    /// Transitions which do not change the local time type are skipped.
+    pub(crate) fn find_prev_transition(&self, unix_time: i64) -> Result<Option<i64>, Error> {
+        if let Some(extra_rule) = self.extra_rule {
+            if let Some(transition) = extra_rule.find_prev_transition(unix_time)? {
+                let after_last_transition = match self.transitions.last() {
+                    Some(last) => {
+                        transition > self.unix_leap_time_to_unix_time(last.unix_leap_time)?
+                    }
+                    None => true,
+                };
+                if after_last_transition {
+                    return Ok(Some(transition));
+                }
+            }
+        }
+
+        let unix_leap_time = self.unix_time_to_unix_leap_time(unix_time)?;
+        let mut index = self.transitions.partition_point(|t| t.unix_leap_time < unix_leap_time);
+        while index > 0 {
+            index -= 1;
+            if self.is_effective_transition(index) {
+                let transition = self.transitions[index];
+                return self.unix_leap_time_to_unix_time(transition.unix_leap_time).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Check if the transition at the specified index changes the local time type
+    fn is_effective_transition(&self, index: usize) -> bool {
+        let before = match index {
+            0 => 0,
+            _ => self.transitions[index - 1].local_time_type_index,
+        };
+        let after = self.transitions[index].local_time_type_index;
+        self.local_time_types[before] != self.local_time_types[after]
+    }
+
     /// Check time zone inputs
     fn validate(&self) -> Result<(), Error> {
         // Check local time types
@@ -415,7 +541,7 @@ impl<'a> TimeZoneRef<'a> {
 
     /// Convert Unix leap time to Unix time, from the list of leap seconds in a time zone
     fn unix_leap_time_to_unix_time(&self, unix_leap_time: i64) -> Result<i64, Error> {
-        if unix_leap_time == i64::min_value() {
+        if unix_leap_time == i64::MIN {
             return Err(Error::OutOfRange("out of range operation"));
         }
 
@@ -572,7 +698,7 @@ pub(crate) struct LocalTimeType {
 impl LocalTimeType {
     /// Construct a local time type
     pub(super) fn new(ut_offset: i32, is_dst: bool, name: Option<&[u8]>) -> Result<Self, Error> {
-        if ut_offset == i32::min_value() {
+        if ut_offset == i32::MIN {
             return Err(Error::LocalTimeType("invalid UTC offset"));
         }
 
@@ -586,7 +712,7 @@ impl LocalTimeType {
 
     /// Construct a local time type with the specified UTC offset in seconds
     pub(super) const fn with_offset(ut_offset: i32) -> Result<Self, Error> {
-        if ut_offset == i32::min_value() {
+        if ut_offset == i32::MIN {
             return Err(Error::LocalTimeType("invalid UTC offset"));
         }
 
@@ -599,10 +725,15 @@ impl LocalTimeType {
     }
 
     /// Returns daylight saving time indicator
-    pub(super) const fn is_dst(&self) -> bool {
+    pub(crate) const fn is_dst(&self) -> bool {
         self.is_dst
     }
 
+    /// Returns time zone name
+    pub(crate) fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|name| name.as_ref())
+    }
+
     pub(super) const UTC: LocalTimeType = Self { ut_offset: 0, is_dst: false, name: None };
 }
 
@@ -619,20 +750,32 @@ fn find_tz_file(path: impl AsRef<Path>) -> Result<File, Error> {
             return Ok(File::open(path)?);
         }
 
+        find_in_zoneinfo_directories(path)
+    }
+}
+
+/// Open a relative path in one of the system timezone directories
+fn find_in_zoneinfo_directories(path: &Path) -> Result<File, Error> {
+    if path.is_relative() {
         for folder in &ZONE_INFO_DIRECTORIES {
             if let Ok(file) = File::open(PathBuf::from(folder).join(path)) {
                 return Ok(file);
             }
         }
-
-        Err(Error::Io(io::ErrorKind::NotFound.into()))
     }
+
+    Err(Error::Io(io::ErrorKind::NotFound.into()))
 }
 
 // Possible system timezone directories
 #[cfg(unix)]
 const ZONE_INFO_DIRECTORIES: [&str; 4] =
     ["/usr/share/zoneinfo", "/share/zoneinfo", "/etc/zoneinfo", "/usr/share/lib/zoneinfo"];
+#[cfg(not(unix))]
+const ZONE_INFO_DIRECTORIES: [&str; 0] = [];
+
+/// Whether there are system timezone directories to look zones up in
+pub(crate) const HAS_ZONE_INFO_DIRECTORIES: bool = !ZONE_INFO_DIRECTORIES.is_empty();
 
 /// Number of seconds in one week
 pub(crate) const SECONDS_PER_WEEK: i64 = SECONDS_PER_DAY * DAYS_PER_WEEK;
@@ -818,7 +961,7 @@ mod tests {
         let time_zone_3 =
             TimeZone::new(vec![Transition::new(0, 0)], utc_local_time_types.clone(), vec![], None)?;
         let time_zone_4 = TimeZone::new(
-            vec![Transition::new(i32::min_value().into(), 0), Transition::new(0, 1)],
+            vec![Transition::new(i32::MIN.into(), 0), Transition::new(0, 1)],
             vec![utc, cet],
             Vec::new(),
             Some(fixed_extra_rule),
@@ -926,7 +1069,7 @@ mod tests {
     #[test]
     fn test_leap_seconds_overflow() -> Result<(), Error> {
         let time_zone_err = TimeZone::new(
-            vec![Transition::new(i64::min_value(), 0)],
+            vec![Transition::new(i64::MIN, 0)],
             vec![LocalTimeType::UTC],
             vec![LeapSecond::new(0, 1)],
             Some(TransitionRule::from(LocalTimeType::UTC)),
@@ -934,13 +1077,13 @@ mod tests {
         assert!(time_zone_err.is_err());
 
         let time_zone = TimeZone::new(
-            vec![Transition::new(i64::max_value(), 0)],
+            vec![Transition::new(i64::MAX, 0)],
             vec![LocalTimeType::UTC],
             vec![LeapSecond::new(0, 1)],
             None,
         )?;
         assert!(matches!(
-            time_zone.find_local_time_type(i64::max_value()),
+            time_zone.find_local_time_type(i64::MAX),
             Err(Error::FindLocalTimeType(_))
         ));
 
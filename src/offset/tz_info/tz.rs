@@ -0,0 +1,567 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A named time zone backed by TZif data or a POSIX TZ string.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::Range;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error;
+use std::sync::Arc;
+
+use super::timezone::LocalTimeType;
+use super::Error;
+use super::TimeZone as Zone;
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone};
+use crate::{DateTime, Datelike, Utc};
+
+/// A time zone with a full set of transitions, as described by the time zone database.
+///
+/// `TzInfo` can be loaded from the contents of a [TZif file](https://datatracker.ietf.org/doc/html/rfc8536),
+/// from a POSIX TZ string such as `"CET-1CEST,M3.5.0,M10.5.0/3"`, or from a zone name in the
+/// system time zone database such as `"Europe/Prague"`.
+///
+/// The value is cheap to clone: the parsed zone data is shared between clones and between the
+/// offsets of all `DateTime<TzInfo>` values created from it.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, TzInfo};
+///
+/// let prague = TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let dt = prague.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2024-07-01T12:00:00+02:00");
+/// assert_eq!(dt.format("%H:%M %Z").to_string(), "12:00 CEST");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct TzInfo {
+    inner: Arc<Inner>,
+}
+
+#[derive(PartialEq, Eq)]
+struct Inner {
+    name: Option<Box<str>>,
+    zone: Zone,
+}
+
+impl TzInfo {
+    /// Makes a new `TzInfo` from the contents of a TZif file.
+    ///
+    /// Both version 1 and the 64-bit version 2+ formats are supported, including the leap second
+    /// records and the POSIX TZ string footer describing transitions after the last one listed.
+    pub fn from_tzif_bytes(bytes: &[u8]) -> Result<TzInfo, TzInfoError> {
+        TzInfo::new(None, Zone::from_tz_data(bytes)?)
+    }
+
+    /// Makes a new `TzInfo` from a POSIX TZ string, for example `"EST5EDT,M3.2.0,M11.1.0"`.
+    ///
+    /// Unlike the `TZ` environment variable this does not look up any file; the string must
+    /// describe the offsets and transition rules itself.
+    pub fn from_posix_tz(tz_string: &str) -> Result<TzInfo, TzInfoError> {
+        TzInfo::new(Some(tz_string), Zone::from_tz_string(tz_string)?)
+    }
+
+    /// Makes a new `TzInfo` by loading a zone from the system time zone database, for example
+    /// `"Europe/Prague"`.
+    ///
+    /// An absolute path to a TZif file is accepted as well.
+    pub fn from_zoneinfo(name: &str) -> Result<TzInfo, TzInfoError> {
+        TzInfo::new(Some(name), Zone::from_zoneinfo(name)?)
+    }
+
+    /// Whether there are system time zone directories for [`TzInfo::from_system_zoneinfo`].
+    pub(crate) const HAS_SYSTEM_ZONEINFO: bool = super::timezone::HAS_ZONE_INFO_DIRECTORIES;
+
+    /// Looks up a zone name in the system time zone directories only, as done when parsing `%:Z`.
+    ///
+    /// The zones found are cached per thread, so each one is only read from disk once.
+    pub(crate) fn from_system_zoneinfo(name: &str) -> Option<TzInfo> {
+        thread_local! {
+            static ZONES: RefCell<HashMap<Box<str>, TzInfo>> = RefCell::new(HashMap::new());
+        }
+
+        ZONES.with(|zones| {
+            if let Some(tz) = zones.borrow().get(name) {
+                return Some(tz.clone());
+            }
+            let tz = TzInfo::new(Some(name), Zone::from_zoneinfo_directories(name).ok()?).ok()?;
+            zones.borrow_mut().insert(name.into(), tz.clone());
+            Some(tz)
+        })
+    }
+
+    /// Returns the UTC time zone as a `TzInfo`.
+    #[must_use]
+    pub fn utc() -> TzInfo {
+        TzInfo { inner: Arc::new(Inner { name: Some("UTC".into()), zone: Zone::utc() }) }
+    }
+
+    /// Returns the name this time zone was loaded with, if any.
+    ///
+    /// This is the zone name passed to [`TzInfo::from_zoneinfo`] or the string passed to
+    /// [`TzInfo::from_posix_tz`]. Zones loaded from raw TZif data have no name.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name.as_deref()
+    }
+
+    /// Returns the first transition of this time zone strictly after `instant`, or `None` if the
+    /// offset no longer changes.
+    ///
+    /// Transitions given by the POSIX TZ string footer of TZif data, or by a POSIX TZ string
+    /// itself, are expanded into concrete instants.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, TzInfo, Utc};
+    ///
+    /// let prague = TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let transition = prague.next_transition(&now).unwrap();
+    /// assert_eq!(transition.instant(), Utc.with_ymd_and_hms(2024, 3, 31, 1, 0, 0).unwrap());
+    /// assert_eq!(transition.offset_before().to_string(), "CET");
+    /// assert_eq!(transition.offset_after().to_string(), "CEST");
+    /// ```
+    #[must_use]
+    pub fn next_transition<Tz2: TimeZone>(
+        &self,
+        instant: &DateTime<Tz2>,
+    ) -> Option<TzInfoTransition> {
+        // A transition at a whole second `t` is after `instant` if `t > instant.timestamp()`.
+        let unix_time = self.zone().find_next_transition(instant.timestamp()).ok()??;
+        self.transition(unix_time)
+    }
+
+    /// Returns the last transition of this time zone strictly before `instant`, or `None` if
+    /// there is none.
+    #[must_use]
+    pub fn prev_transition<Tz2: TimeZone>(
+        &self,
+        instant: &DateTime<Tz2>,
+    ) -> Option<TzInfoTransition> {
+        let mut unix_time = instant.timestamp();
+        if instant.timestamp_subsec_nanos() > 0 {
+            unix_time = unix_time.checked_add(1)?;
+        }
+        let unix_time = self.zone().find_prev_transition(unix_time).ok()??;
+        self.transition(unix_time)
+    }
+
+    /// Returns an iterator over the transitions of this time zone within the half-open `range`,
+    /// in chronological order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, TzInfo, Utc};
+    ///
+    /// let prague = TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    /// let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(prague.transitions(start..end).count(), 20);
+    /// ```
+    pub fn transitions<Tz2: TimeZone>(&self, range: Range<DateTime<Tz2>>) -> TzInfoTransitions {
+        let start = range.start.timestamp();
+        let next = match range.start.timestamp_subsec_nanos() {
+            0 => start.checked_sub(1),
+            _ => Some(start),
+        };
+        let end = range.end.timestamp();
+        let end = match range.end.timestamp_subsec_nanos() {
+            0 => end,
+            _ => end.saturating_add(1),
+        };
+        TzInfoTransitions { tz: self.clone(), next, end }
+    }
+
+    fn transition(&self, unix_time: i64) -> Option<TzInfoTransition> {
+        let instant = DateTime::from_timestamp(unix_time, 0)?;
+        let before = self.zone().find_local_time_type(unix_time.checked_sub(1)?).ok()?;
+        let after = self.zone().find_local_time_type(unix_time).ok()?;
+        Some(TzInfoTransition { instant, before: self.offset(before), after: self.offset(after) })
+    }
+
+    fn new(name: Option<&str>, zone: Zone) -> Result<TzInfo, TzInfoError> {
+        // `FixedOffset` only supports offsets of less than a day.
+        if zone.local_time_types().any(|ltt| FixedOffset::east_opt(ltt.offset()).is_none()) {
+            return Err(Error::LocalTimeType("UTC offset out of range").into());
+        }
+        Ok(TzInfo { inner: Arc::new(Inner { name: name.map(Into::into), zone }) })
+    }
+
+    /// Returns the underlying time zone data.
+    pub(crate) fn zone(&self) -> &Zone {
+        &self.inner.zone
+    }
+
+    fn offset(&self, local_time_type: &LocalTimeType) -> TzInfoOffset {
+        // The offsets of all local time types are checked in `TzInfo::new`.
+        let fixed = FixedOffset::east_opt(local_time_type.offset()).unwrap_or(Utc.fix());
+        TzInfoOffset { tz: self.clone(), local_time_type: *local_time_type, fixed }
+    }
+}
+
+impl Hash for TzInfo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.name.hash(state)
+    }
+}
+
+impl fmt::Debug for TzInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.debug_tuple("TzInfo").field(&name).finish(),
+            None => f.write_str("TzInfo"),
+        }
+    }
+}
+
+impl TimeZone for TzInfo {
+    type Offset = TzInfoOffset;
+
+    fn from_offset(offset: &TzInfoOffset) -> TzInfo {
+        offset.tz.clone()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzInfoOffset> {
+        // Get the offset at local midnight.
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzInfoOffset> {
+        match self.zone().find_local_time_type_from_local(local.and_utc().timestamp(), local.year())
+        {
+            Ok(result) => result.map(|local_time_type| self.offset(&local_time_type)),
+            Err(_) => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzInfoOffset {
+        // Get the offset at midnight.
+        self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzInfoOffset {
+        // This can only fail on overflow while applying leap second corrections, which doesn't
+        // happen within the range of `NaiveDateTime`.
+        let local_time_type = self
+            .zone()
+            .find_local_time_type(utc.and_utc().timestamp())
+            .unwrap_or(&LocalTimeType::UTC);
+        self.offset(local_time_type)
+    }
+}
+
+/// The offset of a [`TzInfo`] time zone at some instant.
+///
+/// Besides the offset from UTC this carries the time zone abbreviation (such as `CEST`) and
+/// whether daylight saving time is in effect. The abbreviation is used by the `Display`
+/// implementation, and therefore also by the `%Z` formatting specifier.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TzInfoOffset {
+    tz: TzInfo,
+    local_time_type: LocalTimeType,
+    fixed: FixedOffset,
+}
+
+impl TzInfoOffset {
+    /// Returns the time zone abbreviation, for example `"CEST"`, if the zone data provides one.
+    #[must_use]
+    pub fn abbreviation(&self) -> Option<&str> {
+        self.local_time_type.name()
+    }
+
+    /// Returns `true` if daylight saving time is in effect.
+    #[must_use]
+    pub fn is_dst(&self) -> bool {
+        self.local_time_type.is_dst()
+    }
+}
+
+impl Offset for TzInfoOffset {
+    fn fix(&self) -> FixedOffset {
+        self.fixed
+    }
+
+    fn time_zone_id(&self) -> Option<&str> {
+        self.tz.name()
+    }
+}
+
+impl fmt::Debug for TzInfoOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.fixed, f)
+    }
+}
+
+impl fmt::Display for TzInfoOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.abbreviation() {
+            Some(abbreviation) => f.write_str(abbreviation),
+            None => fmt::Display::fmt(&self.fixed, f),
+        }
+    }
+}
+
+/// A change of the offset, abbreviation or DST flag of a [`TzInfo`] time zone.
+///
+/// Returned by [`TzInfo::next_transition`], [`TzInfo::prev_transition`] and
+/// [`TzInfo::transitions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TzInfoTransition {
+    instant: DateTime<Utc>,
+    before: TzInfoOffset,
+    after: TzInfoOffset,
+}
+
+impl TzInfoTransition {
+    /// Returns the instant at which the new offset takes effect.
+    #[must_use]
+    pub fn instant(&self) -> DateTime<Utc> {
+        self.instant
+    }
+
+    /// Returns the offset in effect just before the transition.
+    #[must_use]
+    pub fn offset_before(&self) -> &TzInfoOffset {
+        &self.before
+    }
+
+    /// Returns the offset in effect from the transition on.
+    #[must_use]
+    pub fn offset_after(&self) -> &TzInfoOffset {
+        &self.after
+    }
+}
+
+/// Iterator over the transitions of a [`TzInfo`] time zone within a range.
+///
+/// Created by [`TzInfo::transitions`].
+#[derive(Clone, Debug)]
+pub struct TzInfoTransitions {
+    tz: TzInfo,
+    /// Unix time after which to look for the next transition, `None` when exhausted.
+    next: Option<i64>,
+    /// Unix time (exclusive) before which transitions are returned.
+    end: i64,
+}
+
+impl Iterator for TzInfoTransitions {
+    type Item = TzInfoTransition;
+
+    fn next(&mut self) -> Option<TzInfoTransition> {
+        let after = self.next.take()?;
+        let unix_time = self.tz.zone().find_next_transition(after).ok()??;
+        if unix_time >= self.end {
+            return None;
+        }
+        self.next = Some(unix_time);
+        self.tz.transition(unix_time)
+    }
+}
+
+impl FusedIterator for TzInfoTransitions {}
+
+/// An error from loading a [`TzInfo`] time zone.
+#[derive(Debug)]
+pub struct TzInfoError(Error);
+
+impl fmt::Display for TzInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl error::Error for TzInfoError {}
+
+impl From<Error> for TzInfoError {
+    fn from(error: Error) -> Self {
+        TzInfoError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TzInfo;
+    use crate::offset::{LocalResult, Offset, TimeZone};
+    use crate::{DateTime, Days, NaiveDate, TimeDelta, Utc};
+
+    const PRAGUE: &str = "CET-1CEST,M3.5.0,M10.5.0/3";
+    // Pacific/Honolulu
+    const HONOLULU: &[u8] = b"TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\x06\0\0\0\0\0\0\0\x07\0\0\0\x06\0\0\0\x14\x80\0\0\0\xbb\x05\x43\x48\xbb\x21\x71\x58\xcb\x89\x3d\xc8\xd2\x23\xf4\x70\xd2\x61\x49\x38\xd5\x8d\x73\x48\x01\x02\x01\x03\x04\x01\x05\xff\xff\x6c\x02\0\0\xff\xff\x6c\x58\0\x04\xff\xff\x7a\x68\x01\x08\xff\xff\x7a\x68\x01\x0c\xff\xff\x7a\x68\x01\x10\xff\xff\x73\x60\0\x04LMT\0HST\0HDT\0HWT\0HPT\0\0\0\0\0\x01\0\0\0\0\0\x01\0TZif2\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\x06\0\0\0\0\0\0\0\x07\0\0\0\x06\0\0\0\x14\xff\xff\xff\xff\x74\xe0\x70\xbe\xff\xff\xff\xff\xbb\x05\x43\x48\xff\xff\xff\xff\xbb\x21\x71\x58\xff\xff\xff\xff\xcb\x89\x3d\xc8\xff\xff\xff\xff\xd2\x23\xf4\x70\xff\xff\xff\xff\xd2\x61\x49\x38\xff\xff\xff\xff\xd5\x8d\x73\x48\x01\x02\x01\x03\x04\x01\x05\xff\xff\x6c\x02\0\0\xff\xff\x6c\x58\0\x04\xff\xff\x7a\x68\x01\x08\xff\xff\x7a\x68\x01\x0c\xff\xff\x7a\x68\x01\x10\xff\xff\x73\x60\0\x04LMT\0HST\0HDT\0HWT\0HPT\0\0\0\0\0\x01\0\0\0\0\0\x01\0\x0aHST10\x0a";
+
+    #[test]
+    fn test_from_posix_tz() {
+        let tz = TzInfo::from_posix_tz(PRAGUE).unwrap();
+        assert_eq!(tz.name(), Some(PRAGUE));
+
+        let winter = tz.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        assert_eq!(winter.offset().fix().local_minus_utc(), 3600);
+        assert_eq!(winter.offset().abbreviation(), Some("CET"));
+        assert!(!winter.offset().is_dst());
+
+        let summer = tz.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        assert_eq!(summer.offset().fix().local_minus_utc(), 7200);
+        assert_eq!(summer.offset().abbreviation(), Some("CEST"));
+        assert!(summer.offset().is_dst());
+
+        assert!(TzInfo::from_posix_tz("").is_err());
+        assert!(TzInfo::from_posix_tz("Europe/Prague").is_err());
+
+        // offsets of a day or more don't fit in a `FixedOffset`
+        assert!(TzInfo::from_posix_tz("XXX+24").is_err());
+        assert!(TzInfo::from_posix_tz("XXX-24:59").is_err());
+        assert!(TzInfo::from_posix_tz("AAA-24BBB,M3.5.0,M10.5.0").is_err());
+        assert!(TzInfo::from_posix_tz("AAA-23BBB,M3.5.0,M10.5.0").is_err());
+        let tz = TzInfo::from_posix_tz("XXX-23:59:59").unwrap();
+        assert_eq!(tz.offset_from_utc_date(&NaiveDate::MIN).fix().local_minus_utc(), 86399);
+    }
+
+    #[test]
+    fn test_gap_and_fold() {
+        let tz = TzInfo::from_posix_tz(PRAGUE).unwrap();
+        let gap = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        assert_eq!(tz.from_local_datetime(&gap), LocalResult::None);
+
+        let fold = NaiveDate::from_ymd_opt(2024, 10, 27).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        match tz.from_local_datetime(&fold) {
+            LocalResult::Ambiguous(a, b) => {
+                let mut offsets = [a.offset().to_string(), b.offset().to_string()];
+                offsets.sort();
+                assert_eq!(offsets, ["CEST", "CET"]);
+            }
+            result => panic!("expected an ambiguous result, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let tz = TzInfo::from_posix_tz(PRAGUE).unwrap();
+        let dt = tz.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(dt.to_string(), "2024-07-01 12:00:00 CEST");
+        assert_eq!(format!("{:?}", dt), "2024-07-01T12:00:00+02:00");
+        assert_eq!(dt.format("%Z %z").to_string(), "CEST +0200");
+        assert_eq!(dt.format("%:Z").to_string(), PRAGUE);
+
+        let utc = dt.with_timezone(&TzInfo::utc());
+        assert_eq!(utc.to_string(), "2024-07-01 10:00:00 +00:00");
+
+        let honolulu = TzInfo::from_tzif_bytes(HONOLULU).unwrap();
+        let dt = honolulu.timestamp_opt(1546300800, 0).unwrap();
+        assert_eq!(dt.format("%:Z").to_string(), "HST");
+    }
+
+    #[test]
+    fn test_from_tzif_bytes() {
+        let tz = TzInfo::from_tzif_bytes(HONOLULU).unwrap();
+        assert_eq!(tz.name(), None);
+
+        let dt = tz.timestamp_opt(-1156939200, 0).unwrap();
+        assert_eq!(dt.offset().abbreviation(), Some("HDT"));
+        assert_eq!(dt.offset().fix().local_minus_utc(), -34200);
+        let dt = tz.timestamp_opt(1546300800, 0).unwrap();
+        assert_eq!(dt.offset().abbreviation(), Some("HST"));
+        assert_eq!(dt.to_rfc3339(), "2018-12-31T14:00:00-10:00");
+
+        assert!(TzInfo::from_tzif_bytes(b"TZif").is_err());
+
+        // a single local time type with an offset of a full day
+        let one_day = b"TZif\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04\0\x01\x51\x80\0\0XXX\0";
+        assert!(TzInfo::from_tzif_bytes(one_day).is_err());
+        let mut almost_one_day = *one_day;
+        almost_one_day[47] = 0x7f;
+        let tz = TzInfo::from_tzif_bytes(&almost_one_day).unwrap();
+        assert_eq!(tz.offset_from_utc_date(&NaiveDate::MIN).fix().local_minus_utc(), 86399);
+    }
+
+    #[test]
+    fn test_transitions_from_rule() {
+        let tz = TzInfo::from_posix_tz(PRAGUE).unwrap();
+        let new_year = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let next = tz.next_transition(&new_year).unwrap();
+        assert_eq!(next.instant(), Utc.with_ymd_and_hms(2024, 3, 31, 1, 0, 0).unwrap());
+        assert_eq!(next.offset_before().fix().local_minus_utc(), 3600);
+        assert_eq!(next.offset_after().fix().local_minus_utc(), 7200);
+        assert!(next.offset_after().is_dst());
+
+        let prev = tz.prev_transition(&new_year).unwrap();
+        assert_eq!(prev.instant(), Utc.with_ymd_and_hms(2023, 10, 29, 1, 0, 0).unwrap());
+        assert_eq!(prev.offset_before().abbreviation(), Some("CEST"));
+        assert_eq!(prev.offset_after().abbreviation(), Some("CET"));
+
+        // strictly after and strictly before
+        let at = next.instant();
+        assert_eq!(
+            tz.next_transition(&at).unwrap().instant(),
+            Utc.with_ymd_and_hms(2024, 10, 27, 1, 0, 0).unwrap()
+        );
+        assert_eq!(tz.prev_transition(&at).unwrap(), prev);
+        let just_after = at + TimeDelta::nanoseconds(1);
+        assert_eq!(tz.prev_transition(&just_after).unwrap(), next);
+
+        // the range is half-open
+        let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+        let transitions: Vec<_> = tz.transitions(start..end).collect();
+        assert_eq!(transitions.len(), 20);
+        assert!(transitions.windows(2).all(|w| w[0].instant() < w[1].instant()));
+        assert_eq!(tz.transitions(at..at).count(), 0);
+        assert_eq!(tz.transitions(at..just_after).count(), 1);
+        assert_eq!(tz.transitions(just_after..at + Days(1)).count(), 0);
+
+        // works with any time zone
+        let local_start = start.with_timezone(&tz);
+        assert_eq!(tz.next_transition(&local_start), tz.next_transition(&start));
+
+        let fixed = TzInfo::from_posix_tz("JST-9").unwrap();
+        assert_eq!(fixed.next_transition(&new_year), None);
+        assert_eq!(fixed.prev_transition(&new_year), None);
+        assert_eq!(fixed.transitions(start..end).count(), 0);
+    }
+
+    #[test]
+    fn test_transitions_from_tzif() {
+        let tz = TzInfo::from_tzif_bytes(HONOLULU).unwrap();
+        let dt = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(tz.next_transition(&dt), None);
+
+        let prev = tz.prev_transition(&dt).unwrap();
+        assert_eq!(prev.instant().timestamp(), -712150200);
+        assert_eq!(prev.offset_before().abbreviation(), Some("HST"));
+        assert_eq!(prev.offset_after().abbreviation(), Some("HST"));
+        assert_eq!(prev.offset_before().fix().local_minus_utc(), -37800);
+        assert_eq!(prev.offset_after().fix().local_minus_utc(), -36000);
+
+        let all: Vec<_> = tz.transitions(DateTime::<Utc>::MIN_UTC..dt).collect();
+        let instants: Vec<_> = all.iter().map(|t| t.instant().timestamp()).collect();
+        assert_eq!(
+            instants,
+            [-2334101314, -1157283000, -1155436200, -880198200, -769395600, -765376200, -712150200]
+        );
+        // HWT to HPT only changes the abbreviation
+        assert_eq!(all[4].offset_before().abbreviation(), Some("HWT"));
+        assert_eq!(all[4].offset_after().abbreviation(), Some("HPT"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_from_zoneinfo() {
+        // The time zone database is not available on every system that runs the tests.
+        let tz = match TzInfo::from_zoneinfo("Europe/Prague") {
+            Ok(tz) => tz,
+            Err(_) => return,
+        };
+        assert_eq!(tz.name(), Some("Europe/Prague"));
+        let dt = tz.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(dt.format("%Z").to_string(), "CEST");
+
+        assert!(TzInfo::from_zoneinfo("Not/A_Zone").is_err());
+    }
+}
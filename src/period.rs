@@ -0,0 +1,454 @@
+//! A calendar period combining years, months, days and a time span.
+
+use core::fmt;
+use core::ops::Neg;
+
+use crate::naive::{NaiveDate, NaiveDateTime};
+use crate::{try_opt, Datelike, TimeDelta};
+
+/// A calendar period of years, months, days and a [`TimeDelta`].
+///
+/// Unlike a [`TimeDelta`], which is an exact amount of time, the length of a `Period` depends on
+/// the date it is applied to: one month can be anywhere from 28 to 31 days, and one day on a local
+/// time line can be 23 or 25 hours during a daylight saving time transition.
+///
+/// The components may have mixed signs, and are kept as given: `Period::new(1, 0, 0, ..)` and
+/// `Period::new(0, 12, 0, ..)` compare as different values even though they always give the same
+/// result.
+///
+/// # Application order
+///
+/// A `Period` is applied to a date in the following order:
+///
+/// 1. The years and months are added together as a single number of months. If the day of the
+///    month does not exist in the resulting month, the last day of that month is used, as in
+///    [`NaiveDate::checked_add_months`].
+/// 2. The days are added.
+/// 3. The time span is added.
+///
+/// Adding the years and months in one step makes `2020-02-29 + P1Y1M` equal to `2021-03-29`,
+/// instead of first clamping to `2021-02-28`.
+///
+/// Subtracting a `Period` applies the negated components in the same order.
+///
+/// [`NaiveDate`] only has a resolution of days, so it applies the whole days of the time span, like
+/// [`NaiveDate::checked_add_signed`]. For a [`DateTime`](crate::DateTime) all steps are done on
+/// the local date and time; see [`CalendarSpan`](crate::CalendarSpan) for how the result is mapped
+/// back to the time zone.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Period, TimeDelta};
+///
+/// let period = Period::new(1, 2, 3, TimeDelta::try_hours(4).unwrap());
+/// let dt = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap().and_hms_opt(22, 0, 0).unwrap();
+/// assert_eq!(dt + period, NaiveDate::from_ymd_opt(2025, 3, 4).unwrap().and_hms_opt(2, 0, 0).unwrap());
+/// assert_eq!(dt + period - period, NaiveDate::from_ymd_opt(2023, 12, 31).unwrap().and_hms_opt(22, 0, 0).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    years: i32,
+    months: i32,
+    days: i32,
+    time: TimeDelta,
+}
+
+impl Period {
+    /// A `Period` with all components zero.
+    pub const ZERO: Period = Period::new(0, 0, 0, TimeDelta::zero());
+
+    /// Makes a new `Period` from its components.
+    pub const fn new(years: i32, months: i32, days: i32, time: TimeDelta) -> Period {
+        Period { years, months, days, time }
+    }
+
+    /// Makes a new `Period` of the given number of years.
+    pub const fn years(years: i32) -> Period {
+        Period::new(years, 0, 0, TimeDelta::zero())
+    }
+
+    /// Makes a new `Period` of the given number of months.
+    pub const fn months(months: i32) -> Period {
+        Period::new(0, months, 0, TimeDelta::zero())
+    }
+
+    /// Makes a new `Period` of the given number of days.
+    pub const fn days(days: i32) -> Period {
+        Period::new(0, 0, days, TimeDelta::zero())
+    }
+
+    /// Makes a new `Period` consisting of only a time span.
+    pub const fn time(time: TimeDelta) -> Period {
+        Period::new(0, 0, 0, time)
+    }
+
+    /// Returns the number of years.
+    pub const fn num_years(&self) -> i32 {
+        self.years
+    }
+
+    /// Returns the number of months, not including those in [`num_years`](Self::num_years).
+    pub const fn num_months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the number of days.
+    pub const fn num_days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the time span.
+    pub const fn time_delta(&self) -> TimeDelta {
+        self.time
+    }
+
+    /// Returns `true` if all components are zero.
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0 && self.time.is_zero()
+    }
+
+    /// Negates every component of the `Period`.
+    ///
+    /// Returns `None` if one of the components is `i32::MIN`.
+    pub const fn checked_neg(self) -> Option<Period> {
+        Some(Period {
+            years: try_opt!(self.years.checked_neg()),
+            months: try_opt!(self.months.checked_neg()),
+            days: try_opt!(self.days.checked_neg()),
+            time: self.time.neg(),
+        })
+    }
+
+    /// Multiplies every component of the `Period` by `rhs`.
+    ///
+    /// Returns `None` if one of the components would overflow.
+    pub const fn checked_mul(self, rhs: i32) -> Option<Period> {
+        Some(Period {
+            years: try_opt!(self.years.checked_mul(rhs)),
+            months: try_opt!(self.months.checked_mul(rhs)),
+            days: try_opt!(self.days.checked_mul(rhs)),
+            time: try_opt!(self.time.checked_mul(rhs)),
+        })
+    }
+
+    /// Returns the years and months as a single number of months, if that fits in an `i32`.
+    pub(crate) const fn total_months(&self) -> Option<i32> {
+        let months = self.years as i64 * 12 + self.months as i64;
+        if months < i32::MIN as i64 || months > i32::MAX as i64 {
+            return None;
+        }
+        Some(months as i32)
+    }
+
+    /// Computes the calendar period from `start` to `end`.
+    ///
+    /// The result is the largest whole number of months that can be added to `start` without
+    /// passing `end`, followed by the remaining days. Adding it to `start` always gives `end`
+    /// again. If `end` is before `start` all components are zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Period};
+    ///
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let period = Period::between(from_ymd(2020, 1, 31), from_ymd(2021, 3, 1));
+    /// assert_eq!(period, Period::new(1, 1, 1, Default::default()));
+    /// assert_eq!(from_ymd(2020, 1, 31) + period, from_ymd(2021, 3, 1));
+    ///
+    /// let period = Period::between(from_ymd(2021, 3, 1), from_ymd(2020, 1, 31));
+    /// assert_eq!(period, Period::new(-1, -1, -1, Default::default()));
+    /// assert_eq!(from_ymd(2021, 3, 1) + period, from_ymd(2020, 1, 31));
+    /// ```
+    pub fn between(start: NaiveDate, end: NaiveDate) -> Period {
+        let months = month_difference(start, end, |months| {
+            start.diff_months(months).map(|date| date.cmp(&end))
+        });
+        let base = start.diff_months(months).expect("intermediate date between two valid dates");
+        let days = end.signed_duration_since(base).num_days() as i32;
+        Period::new(months / 12, months % 12, days, TimeDelta::zero())
+    }
+
+    /// Computes the calendar period from `start` to `end`, including the difference in time.
+    ///
+    /// Like [`Period::between`], this takes the largest whole number of months that does not pass
+    /// `end`. The remainder is split into whole days and a time span of less than a day, all with
+    /// the same sign. Adding the result to `start` always gives `end` again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Period, TimeDelta};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_opt(18, 0, 0).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap().and_hms_opt(6, 0, 0).unwrap();
+    ///
+    /// let period = Period::between_datetimes(start, end);
+    /// assert_eq!(period, Period::new(0, 1, 30, TimeDelta::try_hours(12).unwrap()));
+    /// assert_eq!(start + period, end);
+    /// ```
+    pub fn between_datetimes(start: NaiveDateTime, end: NaiveDateTime) -> Period {
+        let months = month_difference(start.date(), end.date(), |months| {
+            start
+                .date()
+                .diff_months(months)
+                .map(|date| NaiveDateTime::new(date, start.time()).cmp(&end))
+        });
+        let base = start
+            .checked_add_period(Period::months(months))
+            .expect("intermediate datetime between two valid datetimes");
+        let remainder = end.signed_duration_since(base);
+        let days = remainder.num_days();
+        let time = remainder - TimeDelta::try_days(days).expect("days in a valid `TimeDelta`");
+        Period::new(months / 12, months % 12, days as i32, time)
+    }
+}
+
+/// Returns the number of whole months from `start` to `end` that does not overshoot `end`,
+/// according to the `compare` function which compares `start` plus a number of months with `end`.
+fn month_difference(
+    start: NaiveDate,
+    end: NaiveDate,
+    compare: impl Fn(i32) -> Option<core::cmp::Ordering>,
+) -> i32 {
+    use core::cmp::Ordering;
+
+    let mut months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    match compare(months) {
+        Some(Ordering::Greater) if months > 0 => months -= 1,
+        Some(Ordering::Less) if months < 0 => months += 1,
+        _ => {}
+    }
+    months
+}
+
+impl fmt::Display for Period {
+    /// Format a `Period` using the [ISO 8601] duration format, for example `P1Y2M3DT4H5M6.5S`.
+    ///
+    /// A `Period` with only zero or negative components is written with a leading `-`. If the
+    /// components have mixed signs, the negative ones get their own `-`. This is not valid ISO
+    /// 8601, but can be parsed back with `FromStr`.
+    ///
+    /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("P0D");
+        }
+        let negative =
+            self.years <= 0 && self.months <= 0 && self.days <= 0 && self.time <= TimeDelta::zero();
+        // widen to `i64` so negating `i32::MIN` can't overflow
+        let sign = if negative { -1 } else { 1 };
+        let (years, months, days) =
+            (self.years as i64 * sign, self.months as i64 * sign, self.days as i64 * sign);
+        let time = if negative { -self.time } else { self.time };
+
+        f.write_str(if negative { "-P" } else { "P" })?;
+        for (value, designator) in [(years, 'Y'), (months, 'M'), (days, 'D')] {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if time.is_zero() {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+        let (abs, sign) = if time < TimeDelta::zero() { (-time, "-") } else { (time, "") };
+        let (secs, nanos) = (abs.num_seconds(), abs.subsec_nanos());
+        let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        if hours != 0 {
+            write!(f, "{}{}H", sign, hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}{}M", sign, minutes)?;
+        }
+        if seconds != 0 || nanos != 0 {
+            write!(f, "{}{}", sign, seconds)?;
+            if nanos != 0 {
+                // write the fraction without trailing zeros
+                let (mut fraction, mut figures) = (nanos, 9);
+                while fraction % 10 == 0 {
+                    fraction /= 10;
+                    figures -= 1;
+                }
+                write!(f, ".{:01$}", fraction, figures)?;
+            }
+            f.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+
+    /// Negates every component of the `Period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the components is `i32::MIN`.
+    /// Consider using `Period::checked_neg` to get an `Option` instead.
+    fn neg(self) -> Period {
+        self.checked_neg().expect("`-Period` out of range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Period;
+    use crate::{NaiveDate, NaiveDateTime, TimeDelta};
+
+    #[test]
+    fn test_period_application_order() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let ymdhms = |y, m, d, h, n, s| ymd(y, m, d).and_hms_opt(h, n, s).unwrap();
+
+        // years and months are applied together
+        assert_eq!(ymd(2020, 2, 29) + Period::new(1, 1, 0, TimeDelta::zero()), ymd(2021, 3, 29));
+        assert_eq!(ymd(2020, 2, 29) + Period::years(1), ymd(2021, 2, 28));
+        // months before days
+        assert_eq!(ymd(2023, 1, 31) + Period::new(0, 1, 1, TimeDelta::zero()), ymd(2023, 3, 1));
+        // days before time
+        assert_eq!(
+            ymdhms(2023, 1, 31, 23, 0, 0) + Period::new(0, 1, 1, TimeDelta::try_hours(2).unwrap()),
+            ymdhms(2023, 3, 2, 1, 0, 0)
+        );
+        // mixed signs
+        assert_eq!(ymd(2023, 3, 31) + Period::new(0, -1, 1, TimeDelta::zero()), ymd(2023, 3, 1));
+        // dates only use whole days of the time span
+        assert_eq!(
+            ymd(2023, 1, 1) + Period::time(TimeDelta::try_hours(47).unwrap()),
+            ymd(2023, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_period_sub() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let ymdhms = |y, m, d, h, n, s| ymd(y, m, d).and_hms_opt(h, n, s).unwrap();
+
+        assert_eq!(ymd(2021, 3, 29) - Period::new(1, 1, 0, TimeDelta::zero()), ymd(2020, 2, 29));
+        assert_eq!(ymd(2021, 3, 31) - Period::new(1, 1, 0, TimeDelta::zero()), ymd(2020, 2, 29));
+        assert_eq!(
+            ymdhms(2023, 3, 2, 1, 0, 0) - Period::new(0, 1, 1, TimeDelta::try_hours(2).unwrap()),
+            ymdhms(2023, 1, 31, 23, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_period_out_of_range() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(NaiveDate::MAX.checked_add_period(Period::days(1)), None);
+        assert_eq!(NaiveDate::MIN.checked_sub_period(Period::months(1)), None);
+        assert_eq!(ymd(2023, 1, 1).checked_add_period(Period::years(i32::MAX)), None);
+        assert_eq!(ymd(2023, 1, 1).checked_sub_period(Period::days(i32::MIN)), None);
+        assert_eq!(
+            NaiveDateTime::MAX.checked_add_period(Period::time(TimeDelta::try_seconds(1).unwrap())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_period_neg() {
+        let period = Period::new(1, -2, 3, TimeDelta::try_seconds(-4).unwrap());
+        assert_eq!(-period, Period::new(-1, 2, -3, TimeDelta::try_seconds(4).unwrap()));
+        assert_eq!(-(-period), period);
+        assert_eq!(Period::years(i32::MIN).checked_neg(), None);
+        assert!(Period::ZERO.is_zero());
+        assert!(!period.is_zero());
+    }
+
+    #[test]
+    fn test_period_checked_mul() {
+        let period = Period::new(1, -2, 3, TimeDelta::try_seconds(-4).unwrap());
+        assert_eq!(
+            period.checked_mul(3),
+            Some(Period::new(3, -6, 9, TimeDelta::try_seconds(-12).unwrap()))
+        );
+        assert_eq!(period.checked_mul(0), Some(Period::ZERO));
+        assert_eq!(period.checked_mul(-1), Some(-period));
+        assert_eq!(Period::days(i32::MAX).checked_mul(2), None);
+    }
+
+    #[test]
+    fn test_period_display() {
+        let display = |y, m, d, secs, nanos| {
+            Period::new(y, m, d, TimeDelta::new(secs, nanos).unwrap()).to_string()
+        };
+        assert_eq!(display(0, 0, 0, 0, 0), "P0D");
+        assert_eq!(display(1, 2, 3, 4 * 3600 + 5 * 60 + 6, 500_000_000), "P1Y2M3DT4H5M6.5S");
+        assert_eq!(display(0, 14, 0, 0, 0), "P14M");
+        assert_eq!(display(0, 0, 0, 90_000, 0), "PT25H");
+        assert_eq!(display(0, 0, 0, 60, 1), "PT1M0.000000001S");
+        assert_eq!(display(-1, 0, -3, -2, 0), "-P1Y3DT2S");
+        assert_eq!(display(1, -2, 0, -3600, 0), "P1Y-2MT-1H");
+        assert_eq!(display(0, 0, 1, -1, 999_999_999), "P1DT-0.000000001S");
+        assert_eq!(Period::days(i32::MIN).to_string(), "-P2147483648D");
+    }
+
+    #[test]
+    fn test_period_between() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let dates = [
+            ymd(2020, 1, 31),
+            ymd(2020, 2, 29),
+            ymd(2020, 3, 1),
+            ymd(2020, 12, 31),
+            ymd(2021, 2, 28),
+            ymd(2021, 3, 31),
+            ymd(1999, 7, 15),
+            ymd(2024, 11, 30),
+        ];
+        for &start in &dates {
+            for &end in &dates {
+                let period = Period::between(start, end);
+                assert_eq!(start + period, end, "{:?} + {:?}", start, period);
+                let signs = [period.years.signum(), period.months.signum(), period.days.signum()];
+                assert!(signs.iter().all(|&s| s >= 0) || signs.iter().all(|&s| s <= 0));
+            }
+        }
+        assert_eq!(
+            Period::between(ymd(2020, 1, 31), ymd(2020, 2, 28)),
+            Period::new(0, 0, 28, TimeDelta::zero())
+        );
+        assert_eq!(Period::between(ymd(2020, 1, 31), ymd(2020, 2, 29)), Period::months(1));
+        assert_eq!(
+            Period::between(ymd(2019, 2, 28), ymd(2024, 2, 29)),
+            Period::new(5, 0, 1, TimeDelta::zero())
+        );
+        assert_eq!(
+            Period::between(ymd(2020, 3, 31), ymd(2020, 2, 29)),
+            Period::new(0, -1, 0, TimeDelta::zero())
+        );
+        assert_eq!(Period::between(ymd(2020, 5, 5), ymd(2020, 5, 5)), Period::ZERO);
+    }
+
+    #[test]
+    fn test_period_between_datetimes() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let ymdhms = |y, m, d, h, n, s| ymd(y, m, d).and_hms_opt(h, n, s).unwrap();
+
+        let datetimes = [
+            ymdhms(2020, 1, 31, 12, 0, 0),
+            ymdhms(2020, 2, 29, 6, 30, 0),
+            ymdhms(2020, 2, 29, 18, 0, 0),
+            ymdhms(2020, 3, 31, 0, 0, 1),
+            ymdhms(2021, 3, 31, 23, 59, 59),
+        ];
+        for &start in &datetimes {
+            for &end in &datetimes {
+                let period = Period::between_datetimes(start, end);
+                assert_eq!(start + period, end, "{:?} + {:?}", start, period);
+                assert!(period.time.abs() < TimeDelta::try_days(1).unwrap());
+            }
+        }
+        assert_eq!(
+            Period::between_datetimes(ymdhms(2020, 1, 31, 12, 0, 0), ymdhms(2020, 2, 29, 6, 30, 0)),
+            Period::new(0, 0, 28, TimeDelta::try_minutes(18 * 60 + 30).unwrap())
+        );
+    }
+}
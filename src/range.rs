@@ -0,0 +1,754 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Half-open ranges of dates, and of dates and times.
+
+#[cfg(all(feature = "alloc", not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::naive::{NaiveDate, NaiveTime};
+use crate::offset::TimeZone;
+use crate::{CalendarArithmetic, CalendarSpan, DateTime, DateTimeInterval, Days, TimeDelta};
+
+/// A half-open range of dates, from a start date up to but not including an end date.
+///
+/// Unlike a `Range<NaiveDate>` the end is never before the start, and the range has set
+/// operations such as [`intersection`](NaiveDateRange::intersection),
+/// [`union`](NaiveDateRange::union) and [`gap`](NaiveDateRange::gap).
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Months, NaiveDate, NaiveDateRange};
+///
+/// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// let booked = NaiveDateRange::new(from_ymd(2024, 7, 1), from_ymd(2024, 7, 15)).unwrap();
+/// let holiday = NaiveDateRange::new(from_ymd(2024, 7, 10), from_ymd(2024, 7, 20)).unwrap();
+/// assert_eq!(booked.num_days(), 14);
+/// assert!(booked.overlaps(&holiday));
+/// assert_eq!(booked.intersection(&holiday).map(|r| r.num_days()), Some(5));
+/// assert_eq!(booked.union(&holiday).map(|r| r.end()), Some(from_ymd(2024, 7, 20)));
+///
+/// let year = NaiveDateRange::new(from_ymd(2024, 1, 31), from_ymd(2025, 1, 1)).unwrap();
+/// let month_ends: Vec<_> = year.step_by(Months::new(1)).unwrap().map(|d| d.to_string()).collect();
+/// assert_eq!(month_ends[..3], ["2024-01-31", "2024-02-29", "2024-03-31"]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NaiveDateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl NaiveDateRange {
+    /// Makes a new range from `start` up to but not including `end`.
+    ///
+    /// Returns `None` if `end` is before `start`.
+    #[must_use]
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Option<NaiveDateRange> {
+        if end < start {
+            return None;
+        }
+        Some(NaiveDateRange { start, end })
+    }
+
+    /// Returns the first date in the range.
+    #[must_use]
+    pub const fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Returns the end of the range, which is the first date after the range.
+    #[must_use]
+    pub const fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Returns `true` if the range contains no dates.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the number of dates in the range.
+    #[must_use]
+    pub fn num_days(&self) -> i64 {
+        self.duration().num_days()
+    }
+
+    /// Returns the length of the range.
+    #[must_use]
+    pub fn duration(&self) -> TimeDelta {
+        self.end.signed_duration_since(self.start)
+    }
+
+    /// Returns `true` if `date` is in the range.
+    #[must_use]
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.start <= *date && *date < self.end
+    }
+
+    /// Returns `true` if all dates of `other` are in this range.
+    #[must_use]
+    pub fn contains_range(&self, other: &NaiveDateRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true` if this range and `other` have a date in common.
+    #[must_use]
+    pub fn overlaps(&self, other: &NaiveDateRange) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+
+    /// Returns the dates that are in both this range and `other`, or `None` if the ranges don't
+    /// overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &NaiveDateRange) -> Option<NaiveDateRange> {
+        let (start, end) = (self.start.max(other.start), self.end.min(other.end));
+        if start >= end {
+            return None;
+        }
+        Some(NaiveDateRange { start, end })
+    }
+
+    /// Returns the range covering both this range and `other`.
+    ///
+    /// Returns `None` if there is a [`gap`](NaiveDateRange::gap) between the ranges, so the result
+    /// would contain dates that are in neither range.
+    #[must_use]
+    pub fn union(&self, other: &NaiveDateRange) -> Option<NaiveDateRange> {
+        if self.start.max(other.start) > self.end.min(other.end) {
+            return None;
+        }
+        Some(NaiveDateRange { start: self.start.min(other.start), end: self.end.max(other.end) })
+    }
+
+    /// Returns the dates between this range and `other`, or `None` if the ranges overlap or are
+    /// adjacent.
+    #[must_use]
+    pub fn gap(&self, other: &NaiveDateRange) -> Option<NaiveDateRange> {
+        let (start, end) = (self.end.min(other.end), self.start.max(other.start));
+        if start >= end {
+            return None;
+        }
+        Some(NaiveDateRange { start, end })
+    }
+
+    /// Splits the range into the dates before `date`, and the dates from `date` on.
+    ///
+    /// Returns `None` if `date` is before the start or after the end of the range.
+    #[must_use]
+    pub fn split_at(&self, date: NaiveDate) -> Option<(NaiveDateRange, NaiveDateRange)> {
+        if date < self.start || date > self.end {
+            return None;
+        }
+        Some((
+            NaiveDateRange { start: self.start, end: date },
+            NaiveDateRange { start: date, end: self.end },
+        ))
+    }
+
+    /// Returns an iterator over all dates in the range.
+    #[must_use]
+    pub fn iter(&self) -> NaiveDateRangeIter<Days> {
+        NaiveDateRangeIter { range: *self, step: Days::new(1), index: 0, last: None }
+    }
+
+    /// Returns an iterator over the dates in the range that are a multiple of `step` after the
+    /// start.
+    ///
+    /// The `n`-th date is computed as `start + n × step`, so stepping by months from the 31st
+    /// returns the last day of shorter months without drifting to earlier days. Steps that are
+    /// not whole days, such as `TimeDelta::try_hours(36)`, are applied to the start of the day and
+    /// truncated to a date.
+    ///
+    /// Returns `None` if the step doesn't advance the start by at least one day, such as a step of
+    /// zero, a negative step or a `TimeDelta` shorter than a day.
+    #[must_use]
+    pub fn step_by<S: CalendarSpan>(&self, step: S) -> Option<NaiveDateRangeIter<S>> {
+        let start = self.start.and_time(NaiveTime::MIN);
+        if step.checked_add_to(start).map_or(false, |next| next.date() <= self.start) {
+            return None;
+        }
+        Some(NaiveDateRangeIter { range: *self, step, index: 0, last: None })
+    }
+
+    /// Merges a set of ranges into the smallest list of sorted, non-adjacent ranges that cover
+    /// the same dates.
+    ///
+    /// Empty ranges are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateRange};
+    ///
+    /// let range = |from, to| {
+    ///     let from_md = |(m, d)| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+    ///     NaiveDateRange::new(from_md(from), from_md(to)).unwrap()
+    /// };
+    ///
+    /// let available = NaiveDateRange::merge([
+    ///     range((3, 10), (3, 12)),
+    ///     range((3, 1), (3, 5)),
+    ///     range((3, 5), (3, 7)),
+    ///     range((3, 11), (3, 20)),
+    /// ]);
+    /// assert_eq!(available, [range((3, 1), (3, 7)), range((3, 10), (3, 20))]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn merge<I: IntoIterator<Item = NaiveDateRange>>(ranges: I) -> Vec<NaiveDateRange> {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_unstable_by_key(|r| r.start);
+        let mut merged: Vec<NaiveDateRange> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+}
+
+impl From<NaiveDateRange> for Range<NaiveDate> {
+    fn from(range: NaiveDateRange) -> Range<NaiveDate> {
+        range.start..range.end
+    }
+}
+
+impl IntoIterator for NaiveDateRange {
+    type Item = NaiveDate;
+    type IntoIter = NaiveDateRangeIter<Days>;
+
+    fn into_iter(self) -> NaiveDateRangeIter<Days> {
+        self.iter()
+    }
+}
+
+/// Iterator over the dates in a [`NaiveDateRange`] with a step size of `S`.
+///
+/// Created by [`NaiveDateRange::iter`] and [`NaiveDateRange::step_by`].
+#[derive(Clone, Copy, Debug)]
+pub struct NaiveDateRangeIter<S> {
+    range: NaiveDateRange,
+    step: S,
+    /// The number of steps from the start to the next date.
+    index: i32,
+    last: Option<NaiveDate>,
+}
+
+impl<S: CalendarSpan> Iterator for NaiveDateRangeIter<S> {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let start = self.range.start.and_time(NaiveTime::MIN);
+        let date = self
+            .step
+            .checked_mul(self.index)
+            .and_then(|offset| offset.checked_add_to(start))
+            .map(|dt| dt.date())
+            .filter(|date| {
+                self.range.contains(date) && self.last.map_or(true, |last| *date > last)
+            });
+        match date {
+            Some(date) => {
+                self.index = self.index.saturating_add(1);
+                self.last = Some(date);
+            }
+            // fuse the iterator
+            None => self.range.end = self.range.start,
+        }
+        date
+    }
+}
+
+impl<S: CalendarSpan> FusedIterator for NaiveDateRangeIter<S> {}
+
+/// A half-open range of dates and times, from a start up to but not including an end.
+///
+/// Unlike a `Range<DateTime<Tz>>` the end is never before the start, and the range has set
+/// operations such as [`intersection`](DateTimeRange::intersection),
+/// [`union`](DateTimeRange::union) and [`gap`](DateTimeRange::gap).
+///
+/// # Example
+///
+/// ```
+/// use chrono::{CalendarArithmetic, DateTimeRange, TimeDelta, TimeZone, Utc};
+///
+/// let at = |h, m| Utc.with_ymd_and_hms(2024, 5, 6, h, m, 0).unwrap();
+///
+/// let meeting = DateTimeRange::new(at(9, 0), at(10, 30)).unwrap();
+/// let lunch = DateTimeRange::new(at(12, 0), at(13, 0)).unwrap();
+/// assert_eq!(meeting.duration(), TimeDelta::try_minutes(90).unwrap());
+/// assert!(!meeting.overlaps(&lunch));
+/// assert_eq!(meeting.gap(&lunch), DateTimeRange::new(at(10, 30), at(12, 0)));
+///
+/// let slots = DateTimeRange::new(at(9, 0), at(10, 0)).unwrap();
+/// let slots: Vec<_> = slots
+///     .step_by(TimeDelta::try_minutes(20).unwrap(), CalendarArithmetic::Exact)
+///     .unwrap()
+///     .map(|dt| dt.format("%H:%M").to_string())
+///     .collect();
+/// assert_eq!(slots, ["09:00", "09:20", "09:40"]);
+/// ```
+#[derive(Clone)]
+pub struct DateTimeRange<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> DateTimeRange<Tz> {
+    /// Makes a new range from `start` up to but not including `end`.
+    ///
+    /// Returns `None` if `end` is before `start`.
+    #[must_use]
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Option<DateTimeRange<Tz>> {
+        if end < start {
+            return None;
+        }
+        Some(DateTimeRange { start, end })
+    }
+
+    /// Returns the start of the range, which is contained in the range.
+    #[must_use]
+    pub fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the range, which is not contained in the range.
+    #[must_use]
+    pub fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns `true` if the start and the end are the same instant.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the exact amount of time from the start to the end.
+    #[must_use]
+    pub fn duration(&self) -> TimeDelta {
+        self.end.clone().signed_duration_since(&self.start)
+    }
+
+    /// Returns `true` if `dt` is at or after the start, and before the end.
+    #[must_use]
+    pub fn contains<Tz2: TimeZone>(&self, dt: &DateTime<Tz2>) -> bool {
+        self.start <= *dt && *dt < self.end
+    }
+
+    /// Returns `true` if `other` lies completely within this range.
+    #[must_use]
+    pub fn contains_range<Tz2: TimeZone>(&self, other: &DateTimeRange<Tz2>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true` if this range and `other` have an instant in common.
+    #[must_use]
+    pub fn overlaps<Tz2: TimeZone>(&self, other: &DateTimeRange<Tz2>) -> bool {
+        self.start < other.end && other.start < self.end && !self.is_empty() && !other.is_empty()
+    }
+
+    /// Returns the part of the range that is also in `other`, or `None` if the ranges don't
+    /// overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &DateTimeRange<Tz>) -> Option<DateTimeRange<Tz>> {
+        let start = self.start.clone().max(other.start.clone());
+        let end = self.end.clone().min(other.end.clone());
+        if start >= end {
+            return None;
+        }
+        Some(DateTimeRange { start, end })
+    }
+
+    /// Returns the range covering both this range and `other`.
+    ///
+    /// Returns `None` if there is a [`gap`](DateTimeRange::gap) between the ranges.
+    #[must_use]
+    pub fn union(&self, other: &DateTimeRange<Tz>) -> Option<DateTimeRange<Tz>> {
+        if self.start > other.end || other.start > self.end {
+            return None;
+        }
+        Some(DateTimeRange {
+            start: self.start.clone().min(other.start.clone()),
+            end: self.end.clone().max(other.end.clone()),
+        })
+    }
+
+    /// Returns the range between this range and `other`, or `None` if the ranges overlap or are
+    /// adjacent.
+    #[must_use]
+    pub fn gap(&self, other: &DateTimeRange<Tz>) -> Option<DateTimeRange<Tz>> {
+        let start = self.end.clone().min(other.end.clone());
+        let end = self.start.clone().max(other.start.clone());
+        if start >= end {
+            return None;
+        }
+        Some(DateTimeRange { start, end })
+    }
+
+    /// Splits the range into the part before `dt`, and the part from `dt` on.
+    ///
+    /// Returns `None` if `dt` is before the start or after the end of the range.
+    #[must_use]
+    pub fn split_at(&self, dt: DateTime<Tz>) -> Option<(DateTimeRange<Tz>, DateTimeRange<Tz>)> {
+        if dt < self.start || dt > self.end {
+            return None;
+        }
+        Some((
+            DateTimeRange { start: self.start.clone(), end: dt.clone() },
+            DateTimeRange { start: dt, end: self.end.clone() },
+        ))
+    }
+
+    /// Returns an iterator over the dates and times in the range that are a multiple of `step`
+    /// after the start.
+    ///
+    /// The `n`-th value is computed as `start + n × step` with
+    /// [`DateTime::checked_add_span`], so `arithmetic` decides whether steps of days or months
+    /// keep the wall clock time across daylight saving time transitions. The iterator stops at
+    /// the first value that would not be after the previous one, or that doesn't exist with
+    /// [`Disambiguation::Reject`](crate::Disambiguation::Reject).
+    ///
+    /// Returns `None` if the step doesn't advance the start, such as a step of zero or a negative
+    /// step.
+    #[must_use]
+    pub fn step_by<S: CalendarSpan>(
+        &self,
+        step: S,
+        arithmetic: CalendarArithmetic,
+    ) -> Option<DateTimeRangeIter<Tz, S>> {
+        let next = self.start.clone().checked_add_span(step, arithmetic);
+        if next.map_or(false, |next| next <= self.start) {
+            return None;
+        }
+        Some(DateTimeRangeIter { range: self.clone(), step, arithmetic, index: 0, last: None })
+    }
+
+    /// Converts the range to another time zone.
+    #[must_use]
+    pub fn with_timezone<Tz2: TimeZone>(&self, tz: &Tz2) -> DateTimeRange<Tz2> {
+        DateTimeRange { start: self.start.with_timezone(tz), end: self.end.with_timezone(tz) }
+    }
+
+    /// Merges a set of ranges into the smallest list of sorted, non-adjacent ranges that cover
+    /// the same instants.
+    ///
+    /// Empty ranges are dropped.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn merge<I: IntoIterator<Item = DateTimeRange<Tz>>>(ranges: I) -> Vec<DateTimeRange<Tz>> {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_by(|a, b| a.start.cmp(&b.start));
+        let mut merged: Vec<DateTimeRange<Tz>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => {
+                    if range.end > last.end {
+                        last.end = range.end;
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<DateTimeRange<Tz2>> for DateTimeRange<Tz> {
+    fn eq(&self, other: &DateTimeRange<Tz2>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Tz: TimeZone> Eq for DateTimeRange<Tz> {}
+
+impl<Tz: TimeZone> Copy for DateTimeRange<Tz> where DateTime<Tz>: Copy {}
+
+impl<Tz: TimeZone> core::hash::Hash for DateTimeRange<Tz> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<Tz: TimeZone> fmt::Debug for DateTimeRange<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DateTimeRange").field("start", &self.start).field("end", &self.end).finish()
+    }
+}
+
+impl<Tz: TimeZone> From<DateTimeInterval<Tz>> for DateTimeRange<Tz> {
+    fn from(interval: DateTimeInterval<Tz>) -> DateTimeRange<Tz> {
+        interval.range().clone()
+    }
+}
+
+impl<Tz: TimeZone> From<DateTimeRange<Tz>> for Range<DateTime<Tz>> {
+    fn from(range: DateTimeRange<Tz>) -> Range<DateTime<Tz>> {
+        range.start..range.end
+    }
+}
+
+/// Iterator over the dates and times in a [`DateTimeRange`] with a step size of `S`.
+///
+/// Created by [`DateTimeRange::step_by`].
+#[derive(Clone)]
+pub struct DateTimeRangeIter<Tz: TimeZone, S> {
+    range: DateTimeRange<Tz>,
+    step: S,
+    arithmetic: CalendarArithmetic,
+    /// The number of steps from the start to the next value.
+    index: i32,
+    last: Option<DateTime<Tz>>,
+}
+
+impl<Tz: TimeZone, S: CalendarSpan> Iterator for DateTimeRangeIter<Tz, S> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        let dt = self
+            .step
+            .checked_mul(self.index)
+            .and_then(|offset| self.range.start.clone().checked_add_span(offset, self.arithmetic))
+            .filter(|dt| {
+                self.range.contains(dt) && self.last.as_ref().map_or(true, |last| dt > last)
+            });
+        match dt {
+            Some(ref dt) => {
+                self.index = self.index.saturating_add(1);
+                self.last = Some(dt.clone());
+            }
+            // fuse the iterator
+            None => self.range.end = self.range.start.clone(),
+        }
+        dt
+    }
+}
+
+impl<Tz: TimeZone, S: CalendarSpan> FusedIterator for DateTimeRangeIter<Tz, S> {}
+
+impl<Tz: TimeZone, S: fmt::Debug> fmt::Debug for DateTimeRangeIter<Tz, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DateTimeRangeIter")
+            .field("range", &self.range)
+            .field("step", &self.step)
+            .field("arithmetic", &self.arithmetic)
+            .field("index", &self.index)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateTimeRange, NaiveDateRange};
+    use crate::offset::{FixedOffset, TimeZone, Utc};
+    use crate::{CalendarArithmetic, DateTime, Days, Months, NaiveDate, TimeDelta};
+
+    fn at(h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 6, h, m, 0).unwrap()
+    }
+
+    fn times(from: (u32, u32), to: (u32, u32)) -> DateTimeRange<Utc> {
+        DateTimeRange::new(at(from.0, from.1), at(to.0, to.1)).unwrap()
+    }
+
+    #[test]
+    fn test_naive_date_range_set_operations() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let dates = |from: (u32, u32), to: (u32, u32)| {
+            NaiveDateRange::new(ymd(2024, from.0, from.1), ymd(2024, to.0, to.1)).unwrap()
+        };
+
+        let march = dates((3, 1), (4, 1));
+        assert_eq!(NaiveDateRange::new(ymd(2024, 4, 1), ymd(2024, 3, 1)), None);
+        assert_eq!(march.num_days(), 31);
+        assert_eq!(march.duration(), TimeDelta::try_days(31).unwrap());
+        assert!(march.contains(&ymd(2024, 3, 31)) && !march.contains(&ymd(2024, 4, 1)));
+        assert!(dates((3, 1), (3, 1)).is_empty());
+
+        let spring = dates((3, 20), (6, 21));
+        assert!(march.overlaps(&spring) && spring.overlaps(&march));
+        assert_eq!(march.intersection(&spring), Some(dates((3, 20), (4, 1))));
+        assert_eq!(march.union(&spring), Some(dates((3, 1), (6, 21))));
+        assert_eq!(march.gap(&spring), None);
+        assert!(!march.contains_range(&spring));
+        assert!(march.contains_range(&dates((3, 5), (3, 6))));
+
+        // adjacent ranges
+        let april = dates((4, 1), (5, 1));
+        assert!(!march.overlaps(&april));
+        assert_eq!(march.intersection(&april), None);
+        assert_eq!(march.union(&april), Some(dates((3, 1), (5, 1))));
+        assert_eq!(march.gap(&april), None);
+
+        // disjoint ranges
+        let may = dates((5, 1), (6, 1));
+        assert_eq!(march.union(&may), None);
+        assert_eq!(march.gap(&may), Some(april));
+        assert_eq!(may.gap(&march), Some(april));
+
+        assert_eq!(
+            march.split_at(ymd(2024, 3, 15)),
+            Some((dates((3, 1), (3, 15)), dates((3, 15), (4, 1))))
+        );
+        assert_eq!(march.split_at(ymd(2024, 4, 1)), Some((march, dates((4, 1), (4, 1)))));
+        assert_eq!(march.split_at(ymd(2024, 4, 2)), None);
+        assert_eq!(core::ops::Range::from(march), ymd(2024, 3, 1)..ymd(2024, 4, 1));
+    }
+
+    #[test]
+    fn test_naive_date_range_iter() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let dates = |from: (u32, u32), to: (u32, u32)| {
+            NaiveDateRange::new(ymd(2024, from.0, from.1), ymd(2024, to.0, to.1)).unwrap()
+        };
+
+        let week = dates((2, 26), (3, 4));
+        assert_eq!(week.iter().count(), 7);
+        assert_eq!(week.into_iter().last(), Some(ymd(2024, 3, 3)));
+        let mut days = week.step_by(Days::new(3)).unwrap();
+        assert_eq!(days.next(), Some(ymd(2024, 2, 26)));
+        assert_eq!(days.next(), Some(ymd(2024, 2, 29)));
+        assert_eq!(days.next(), Some(ymd(2024, 3, 3)));
+        assert_eq!(days.next(), None);
+        assert_eq!(days.next(), None);
+
+        let months = NaiveDateRange::new(ymd(2024, 1, 31), ymd(2024, 5, 31)).unwrap();
+        let month_ends: Vec<_> = months.step_by(Months::new(1)).unwrap().collect();
+        assert_eq!(
+            month_ends,
+            [ymd(2024, 1, 31), ymd(2024, 2, 29), ymd(2024, 3, 31), ymd(2024, 4, 30)]
+        );
+
+        let hours = week.step_by(TimeDelta::try_hours(36).unwrap()).unwrap();
+        assert_eq!(
+            hours.collect::<Vec<_>>(),
+            [
+                ymd(2024, 2, 26),
+                ymd(2024, 2, 27),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 1),
+                ymd(2024, 3, 3)
+            ]
+        );
+        // steps that don't advance the date
+        assert!(week.step_by(Days::new(0)).is_none());
+        assert!(week.step_by(TimeDelta::try_hours(-24).unwrap()).is_none());
+        assert!(week.step_by(TimeDelta::try_hours(12).unwrap()).is_none());
+        assert_eq!(week.step_by(TimeDelta::try_hours(24).unwrap()).unwrap().count(), 7);
+        assert_eq!(dates((3, 1), (3, 1)).iter().next(), None);
+
+        // stops at the end of the supported range
+        let end = NaiveDateRange::new(ymd(2024, 1, 1), NaiveDate::MAX).unwrap();
+        assert_eq!(end.step_by(Months::new(1_000_000)).unwrap().count(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_naive_date_range_merge() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let dates = |from: (u32, u32), to: (u32, u32)| {
+            NaiveDateRange::new(ymd(2024, from.0, from.1), ymd(2024, to.0, to.1)).unwrap()
+        };
+
+        let merged = NaiveDateRange::merge([
+            dates((5, 1), (5, 3)),
+            dates((1, 1), (2, 1)),
+            dates((3, 1), (3, 1)),
+            dates((1, 15), (2, 10)),
+            dates((2, 10), (2, 20)),
+            dates((1, 2), (1, 5)),
+        ]);
+        assert_eq!(merged, [dates((1, 1), (2, 20)), dates((5, 1), (5, 3))]);
+        assert_eq!(NaiveDateRange::merge([]), []);
+    }
+
+    #[test]
+    fn test_datetime_range_set_operations() {
+        let morning = times((8, 0), (12, 0));
+        let meeting = times((11, 0), (13, 0));
+        assert_eq!(DateTimeRange::new(at(12, 0), at(8, 0)), None);
+        assert_eq!(morning.duration(), TimeDelta::try_hours(4).unwrap());
+        assert!(morning.contains(&at(8, 0)) && !morning.contains(&at(12, 0)));
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+        assert!(morning.contains(&cest.with_ymd_and_hms(2024, 5, 6, 13, 59, 0).unwrap()));
+
+        assert!(morning.overlaps(&meeting));
+        assert!(!morning.overlaps(&times((12, 0), (13, 0))));
+        assert!(!morning.overlaps(&times((9, 0), (9, 0))));
+        assert_eq!(morning.intersection(&meeting), Some(times((11, 0), (12, 0))));
+        assert_eq!(morning.union(&meeting), Some(times((8, 0), (13, 0))));
+        assert_eq!(morning.gap(&meeting), None);
+        assert_eq!(morning.gap(&times((14, 0), (15, 0))), Some(times((12, 0), (14, 0))));
+        assert_eq!(morning.union(&times((14, 0), (15, 0))), None);
+        assert!(morning.contains_range(&times((9, 0), (10, 0))));
+        assert_eq!(
+            morning.split_at(at(10, 0)),
+            Some((times((8, 0), (10, 0)), times((10, 0), (12, 0))))
+        );
+        assert_eq!(morning.split_at(at(7, 0)), None);
+
+        let converted = morning.with_timezone(&cest);
+        assert_eq!(converted, morning);
+        #[cfg(feature = "alloc")]
+        assert_eq!(converted.start().to_rfc3339(), "2024-05-06T10:00:00+02:00");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_datetime_range_merge() {
+        let morning = times((8, 0), (12, 0));
+        let meeting = times((11, 0), (13, 0));
+        let merged = DateTimeRange::merge([meeting, times((14, 0), (15, 0)), morning]);
+        assert_eq!(merged, [times((8, 0), (13, 0)), times((14, 0), (15, 0))]);
+    }
+
+    #[test]
+    fn test_datetime_range_step_by() {
+        let range = times((9, 0), (10, 0));
+        let quarters =
+            range.step_by(TimeDelta::try_minutes(15).unwrap(), CalendarArithmetic::Exact).unwrap();
+        assert_eq!(quarters.collect::<Vec<_>>(), [at(9, 0), at(9, 15), at(9, 30), at(9, 45)]);
+        assert!(range.step_by(TimeDelta::zero(), CalendarArithmetic::Exact).is_none());
+        assert!(range
+            .step_by(TimeDelta::try_minutes(-15).unwrap(), CalendarArithmetic::Exact)
+            .is_none());
+        let empty = times((9, 0), (9, 0));
+        assert_eq!(empty.step_by(Days::new(1), CalendarArithmetic::Exact).unwrap().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_datetime_range_step_by_dst() {
+        let prague = crate::TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let start = prague.with_ymd_and_hms(2024, 3, 30, 9, 0, 0).unwrap();
+        let end = prague.with_ymd_and_hms(2024, 4, 2, 0, 0, 0).unwrap();
+        let range = DateTimeRange::new(start, end).unwrap();
+
+        let wall_clock = CalendarArithmetic::WallClock(crate::Disambiguation::Compatible);
+        let days: Vec<_> =
+            range.step_by(Days::new(1), wall_clock).unwrap().map(|dt| dt.to_rfc3339()).collect();
+        assert_eq!(
+            days,
+            ["2024-03-30T09:00:00+01:00", "2024-03-31T09:00:00+02:00", "2024-04-01T09:00:00+02:00"]
+        );
+        let days: Vec<_> = range
+            .step_by(Days::new(1), CalendarArithmetic::Exact)
+            .unwrap()
+            .map(|dt| dt.to_rfc3339())
+            .collect();
+        assert_eq!(
+            days,
+            ["2024-03-30T09:00:00+01:00", "2024-03-31T10:00:00+02:00", "2024-04-01T10:00:00+02:00"]
+        );
+    }
+}
@@ -0,0 +1,1180 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Recurrence rules as defined by [RFC 5545] (iCalendar).
+//!
+//! An [`RRule`] describes a repeating schedule, such as "every second Tuesday of the month". It
+//! can be parsed from and formatted as the value of an iCalendar `RRULE` property, and expands to
+//! the dates and times of its occurrences starting at a given date and time.
+//!
+//! The rule parts `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, `BYMONTH`, `BYMONTHDAY`, `BYDAY`,
+//! `BYSETPOS` and `WKST` are supported.
+//!
+//! [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use crate::format::iso8601::{format_iso8601, parse_iso8601, Iso8601Format};
+use crate::format::{ParseError, ParseErrorKind, ParseResult};
+use crate::format::{INVALID, NOT_ENOUGH, OUT_OF_RANGE, TOO_SHORT};
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{TimeZone, Utc};
+use crate::{DateTime, Datelike, Days, Disambiguation, SecondsFormat, TimeDelta, Weekday};
+
+/// How often a recurrence rule repeats, the `FREQ` rule part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Frequency {
+    /// Every year, `YEARLY`.
+    Yearly,
+    /// Every month, `MONTHLY`.
+    Monthly,
+    /// Every week, `WEEKLY`.
+    Weekly,
+    /// Every day, `DAILY`.
+    Daily,
+    /// Every hour, `HOURLY`.
+    Hourly,
+    /// Every minute, `MINUTELY`.
+    Minutely,
+    /// Every second, `SECONDLY`.
+    Secondly,
+}
+
+impl Frequency {
+    const ALL: [(Frequency, &'static str); 7] = [
+        (Frequency::Yearly, "YEARLY"),
+        (Frequency::Monthly, "MONTHLY"),
+        (Frequency::Weekly, "WEEKLY"),
+        (Frequency::Daily, "DAILY"),
+        (Frequency::Hourly, "HOURLY"),
+        (Frequency::Minutely, "MINUTELY"),
+        (Frequency::Secondly, "SECONDLY"),
+    ];
+
+    /// The length of a period in seconds, for frequencies shorter than a day.
+    const fn seconds(self) -> Option<i64> {
+        match self {
+            Frequency::Hourly => Some(3600),
+            Frequency::Minutely => Some(60),
+            Frequency::Secondly => Some(1),
+            _ => None,
+        }
+    }
+
+    /// The number of periods after which the dates of the periods repeat.
+    ///
+    /// The Gregorian calendar repeats every 400 years, or 4800 months, or 20871 weeks, or 146097
+    /// days. A rule that has no occurrences in that many periods in a row never has any.
+    const fn cycle(self) -> u32 {
+        match self {
+            Frequency::Yearly => 400,
+            Frequency::Monthly => 4800,
+            Frequency::Weekly => 20871,
+            _ => 146097,
+        }
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = Frequency::ALL.iter().find(|(freq, _)| freq == self).map(|(_, name)| *name);
+        f.write_str(name.unwrap_or_default())
+    }
+}
+
+/// A day of the week, optionally limited to its `n`-th occurrence in the month or year.
+///
+/// This is an entry of the `BYDAY` rule part, such as `TU` for every Tuesday, `2TU` for the
+/// second Tuesday and `-1FR` for the last Friday.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NthWeekday {
+    weekday: Weekday,
+    /// The occurrence, counted from the end if negative, or 0 for every occurrence.
+    n: i8,
+}
+
+impl NthWeekday {
+    /// Every occurrence of `weekday`.
+    #[must_use]
+    pub const fn every(weekday: Weekday) -> NthWeekday {
+        NthWeekday { weekday, n: 0 }
+    }
+
+    /// The `n`-th occurrence of `weekday`, or the `-n`-th occurrence from the end if `n` is
+    /// negative.
+    ///
+    /// Returns `None` if `n` is zero or not in the range `-53..=53`.
+    #[must_use]
+    pub const fn nth(n: i32, weekday: Weekday) -> Option<NthWeekday> {
+        if n == 0 || n < -53 || n > 53 {
+            return None;
+        }
+        Some(NthWeekday { weekday, n: n as i8 })
+    }
+
+    /// Returns the day of the week.
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the occurrence, counted from the end if negative, or `None` for every occurrence.
+    #[must_use]
+    pub const fn n(&self) -> Option<i32> {
+        match self.n {
+            0 => None,
+            n => Some(n as i32),
+        }
+    }
+
+    /// Returns `true` if the day at `index` in a month or year of `len` days matches.
+    fn matches(&self, weekday: Weekday, index: u32, len: u32) -> bool {
+        weekday == self.weekday
+            && match self.n {
+                0 => true,
+                n if n > 0 => index / 7 + 1 == n as u32,
+                n => (len - 1 - index) / 7 + 1 == n.unsigned_abs() as u32,
+            }
+    }
+}
+
+impl fmt::Display for NthWeekday {
+    /// Formats the weekday as in `BYDAY`, for example `MO` or `-1FR`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.n != 0 {
+            write!(f, "{}", self.n)?;
+        }
+        f.write_str(WEEKDAYS[self.weekday.num_days_from_monday() as usize].1)
+    }
+}
+
+/// Parsing a `str` into an `NthWeekday` accepts `BYDAY` entries such as `MO`, `2TU` or `-1FR`.
+impl FromStr for NthWeekday {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<NthWeekday> {
+        let split = s.len().checked_sub(2).filter(|&i| s.is_char_boundary(i)).ok_or(TOO_SHORT)?;
+        let (n, code) = s.split_at(split);
+        let weekday = WEEKDAYS
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(code))
+            .map(|(weekday, _)| *weekday)
+            .ok_or(INVALID)?;
+        match n {
+            "" => Ok(NthWeekday::every(weekday)),
+            n => NthWeekday::nth(parse_int(n)?, weekday).ok_or(OUT_OF_RANGE),
+        }
+    }
+}
+
+/// The end of a recurrence rule, the `UNTIL` rule part.
+///
+/// Occurrences after this date or time are not part of the recurrence. An occurrence at exactly
+/// this date or time is included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Until {
+    /// The last date, written as `20250101`. Used with rules for dates without a time.
+    Date(NaiveDate),
+    /// The last local date and time, written as `20250101T000000`.
+    Local(NaiveDateTime),
+    /// The last instant, written as `20250101T000000Z`. Used with rules for dates and times in
+    /// a time zone.
+    Utc(DateTime<Utc>),
+}
+
+impl Until {
+    /// Returns `true` if the occurrence at `local`, which is `instant` in its time zone, is after
+    /// the end.
+    fn excludes(&self, local: NaiveDateTime, instant: Option<DateTime<Utc>>) -> bool {
+        match *self {
+            Until::Date(date) => local.date() > date,
+            Until::Local(until) => local > until,
+            Until::Utc(until) => instant.map_or(local > until.naive_utc(), |dt| dt > until),
+        }
+    }
+}
+
+impl fmt::Display for Until {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = Iso8601Format::BASIC.with_seconds_format(SecondsFormat::Secs);
+        match *self {
+            Until::Date(date) => write!(f, "{}", format_iso8601(date, format)),
+            Until::Local(local) => write!(f, "{}", format_iso8601(local, format)),
+            Until::Utc(dt) => write!(f, "{}Z", format_iso8601(dt.naive_utc(), format)),
+        }
+    }
+}
+
+/// Parsing a `str` into an `Until` accepts the `UNTIL` values `20250101`, `20250101T000000` and
+/// `20250101T000000Z`.
+impl FromStr for Until {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Until> {
+        if let Some(utc) = s.strip_suffix('Z') {
+            return Ok(Until::Utc(parse_iso8601::<NaiveDateTime>(utc)?.and_utc()));
+        }
+        if s.contains('T') {
+            return Ok(Until::Local(parse_iso8601(s)?));
+        }
+        Ok(Until::Date(parse_iso8601(s)?))
+    }
+}
+
+/// A recurrence rule as defined by RFC 5545, such as `FREQ=MONTHLY;BYDAY=2TU`.
+///
+/// A rule repeats every [`interval`](RRule::interval) periods of its [`Frequency`], starting with
+/// the period that contains the start date. Within each period the `BYMONTH`, `BYMONTHDAY` and
+/// `BYDAY` rule parts select the dates, and `BYSETPOS` selects some of them by their position.
+/// Without these rule parts the rule uses the month, day of the month or day of the week of the
+/// start. The time of an occurrence is the time of the start, except for rules that repeat more
+/// often than daily.
+///
+/// The occurrences are computed lazily with [`naive_occurrences`](RRule::naive_occurrences) or,
+/// in a time zone, with [`occurrences`](RRule::occurrences). They end after
+/// [`count`](RRule::count) occurrences or at the [`until`](RRule::until) date, or never.
+///
+/// # Example
+///
+/// ```
+/// use chrono::rrule::{Frequency, NthWeekday, Until};
+/// use chrono::{NaiveDate, RRule, Weekday};
+///
+/// let rule: RRule = "FREQ=MONTHLY;BYDAY=2TU;UNTIL=20250101".parse()?;
+/// assert_eq!(rule.frequency(), Frequency::Monthly);
+/// assert_eq!(rule.by_day(), [NthWeekday::nth(2, Weekday::Tue).unwrap()]);
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap().and_hms_opt(9, 30, 0).unwrap();
+/// let dates: Vec<_> = rule.naive_occurrences(start).map(|dt| dt.to_string()).collect();
+/// assert_eq!(dates, ["2024-10-08 09:30:00", "2024-11-12 09:30:00", "2024-12-10 09:30:00"]);
+///
+/// // the same rule, made with the builder methods
+/// let built = RRule::new(Frequency::Monthly)
+///     .with_by_day([NthWeekday::nth(2, Weekday::Tue).unwrap()])
+///     .unwrap()
+///     .with_until(Until::Date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()));
+/// assert_eq!(built, rule);
+/// assert_eq!(built.to_string(), "FREQ=MONTHLY;UNTIL=20250101;BYDAY=2TU");
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RRule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<Until>,
+    by_month: Vec<u32>,
+    by_month_day: Vec<i32>,
+    by_day: Vec<NthWeekday>,
+    by_set_pos: Vec<i32>,
+    week_start: Weekday,
+}
+
+impl RRule {
+    /// Makes a new rule that repeats every period of `frequency` without end.
+    #[must_use]
+    pub const fn new(frequency: Frequency) -> RRule {
+        RRule {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+        }
+    }
+
+    /// Returns the rule repeating every `interval` periods.
+    ///
+    /// Returns `None` if `interval` is zero.
+    #[must_use]
+    pub fn with_interval(self, interval: u32) -> Option<RRule> {
+        if interval == 0 {
+            return None;
+        }
+        Some(RRule { interval, ..self })
+    }
+
+    /// Returns the rule ending after `count` occurrences, replacing any `until` date.
+    #[must_use]
+    pub fn with_count(self, count: u32) -> RRule {
+        RRule { count: Some(count), until: None, ..self }
+    }
+
+    /// Returns the rule ending at `until`, replacing any `count`.
+    #[must_use]
+    pub fn with_until(self, until: Until) -> RRule {
+        RRule { until: Some(until), count: None, ..self }
+    }
+
+    /// Returns the rule limited to the given months, numbered from 1 to 12.
+    ///
+    /// Returns `None` if a month is out of range.
+    #[must_use]
+    pub fn with_by_month<I: IntoIterator<Item = u32>>(self, months: I) -> Option<RRule> {
+        let by_month: Vec<_> = months.into_iter().collect();
+        if by_month.iter().any(|month| !(1..=12).contains(month)) {
+            return None;
+        }
+        Some(RRule { by_month, ..self })
+    }
+
+    /// Returns the rule limited to the given days of the month, counted from the end of the month
+    /// if negative.
+    ///
+    /// Returns `None` if a day is zero or not in the range `-31..=31`, or if the frequency is
+    /// [`Frequency::Weekly`].
+    #[must_use]
+    pub fn with_by_month_day<I: IntoIterator<Item = i32>>(self, days: I) -> Option<RRule> {
+        let by_month_day: Vec<_> = days.into_iter().collect();
+        if by_month_day.iter().any(|&day| day == 0 || !(-31..=31).contains(&day))
+            || (self.frequency == Frequency::Weekly && !by_month_day.is_empty())
+        {
+            return None;
+        }
+        Some(RRule { by_month_day, ..self })
+    }
+
+    /// Returns the rule limited to the given days of the week.
+    ///
+    /// With a frequency of [`Frequency::Monthly`] the `n`-th weekday is counted within the month.
+    /// With [`Frequency::Yearly`] it is counted within the year, or within the month if the rule
+    /// is limited to some months.
+    ///
+    /// Returns `None` if an `n`-th weekday is used with another frequency.
+    #[must_use]
+    pub fn with_by_day<I: IntoIterator<Item = NthWeekday>>(self, weekdays: I) -> Option<RRule> {
+        let by_day: Vec<_> = weekdays.into_iter().collect();
+        let ordinals_allowed = matches!(self.frequency, Frequency::Yearly | Frequency::Monthly);
+        if !ordinals_allowed && by_day.iter().any(|weekday| weekday.n != 0) {
+            return None;
+        }
+        Some(RRule { by_day, ..self })
+    }
+
+    /// Returns the rule limited to the occurrences at the given positions within each period,
+    /// counted from the end if negative.
+    ///
+    /// Returns `None` if a position is zero or not in the range `-366..=366`.
+    #[must_use]
+    pub fn with_by_set_pos<I: IntoIterator<Item = i32>>(self, positions: I) -> Option<RRule> {
+        let by_set_pos: Vec<_> = positions.into_iter().collect();
+        if by_set_pos.iter().any(|&pos| pos == 0 || !(-366..=366).contains(&pos)) {
+            return None;
+        }
+        Some(RRule { by_set_pos, ..self })
+    }
+
+    /// Returns the rule with weeks starting on `week_start`.
+    ///
+    /// This decides which weeks a weekly rule with an interval of more than one selects. The
+    /// default is Monday.
+    #[must_use]
+    pub fn with_week_start(self, week_start: Weekday) -> RRule {
+        RRule { week_start, ..self }
+    }
+
+    /// Returns the frequency.
+    #[must_use]
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the number of periods between occurrences, 1 by default.
+    #[must_use]
+    pub const fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the number of occurrences, if limited.
+    #[must_use]
+    pub const fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Returns the end of the rule, if any.
+    #[must_use]
+    pub const fn until(&self) -> Option<Until> {
+        self.until
+    }
+
+    /// Returns the months of the `BYMONTH` rule part.
+    #[must_use]
+    pub fn by_month(&self) -> &[u32] {
+        &self.by_month
+    }
+
+    /// Returns the days of the `BYMONTHDAY` rule part.
+    #[must_use]
+    pub fn by_month_day(&self) -> &[i32] {
+        &self.by_month_day
+    }
+
+    /// Returns the weekdays of the `BYDAY` rule part.
+    #[must_use]
+    pub fn by_day(&self) -> &[NthWeekday] {
+        &self.by_day
+    }
+
+    /// Returns the positions of the `BYSETPOS` rule part.
+    #[must_use]
+    pub fn by_set_pos(&self) -> &[i32] {
+        &self.by_set_pos
+    }
+
+    /// Returns the first day of the week, the `WKST` rule part.
+    #[must_use]
+    pub const fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Returns an iterator over the occurrences of the rule at or after `start`.
+    ///
+    /// An [`Until::Utc`] end is compared to the local date and time as if it were in UTC.
+    #[must_use]
+    pub fn naive_occurrences(&self, start: NaiveDateTime) -> NaiveOccurrences<'_> {
+        NaiveOccurrences { generator: Generator::new(self, start), remaining: self.count }
+    }
+
+    /// Returns an iterator over the occurrences of the rule at or after `start`, in the time zone
+    /// of `start`.
+    ///
+    /// The occurrences are computed on the local wall clock time, so a daily meeting at 09:00
+    /// stays at 09:00 across daylight saving time transitions. A local time that falls in a gap
+    /// is moved forward by the length of the gap, and an ambiguous local time uses the earlier
+    /// instant, as RFC 5545 specifies (see [`Disambiguation::Compatible`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, RRule, TimeZone};
+    ///
+    /// let rule: RRule = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=4".parse()?;
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let start = tz.with_ymd_and_hms(2024, 1, 3, 14, 0, 0).unwrap();
+    /// let dates: Vec<_> = rule.occurrences(&start).map(|dt| dt.to_rfc3339()).collect();
+    /// assert_eq!(
+    ///     dates,
+    ///     [
+    ///         "2024-01-05T14:00:00+01:00",
+    ///         "2024-01-15T14:00:00+01:00",
+    ///         "2024-01-19T14:00:00+01:00",
+    ///         "2024-01-29T14:00:00+01:00",
+    ///     ]
+    /// );
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn occurrences<Tz: TimeZone>(&self, start: &DateTime<Tz>) -> Occurrences<'_, Tz> {
+        Occurrences {
+            generator: Generator::new(self, start.naive_local()),
+            tz: start.timezone(),
+            remaining: self.count,
+        }
+    }
+}
+
+impl fmt::Display for RRule {
+    /// Formats the rule as the value of an RFC 5545 `RRULE` property, such as
+    /// `FREQ=MONTHLY;COUNT=3;BYDAY=2TU`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn list<T: fmt::Display>(f: &mut fmt::Formatter, name: &str, items: &[T]) -> fmt::Result {
+            for (i, item) in items.iter().enumerate() {
+                let separator = if i == 0 { name } else { "," };
+                write!(f, "{}{}", separator, item)?;
+            }
+            Ok(())
+        }
+
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until)?;
+        }
+        list(f, ";BYMONTH=", &self.by_month)?;
+        list(f, ";BYMONTHDAY=", &self.by_month_day)?;
+        list(f, ";BYDAY=", &self.by_day)?;
+        list(f, ";BYSETPOS=", &self.by_set_pos)?;
+        if self.week_start != Weekday::Mon {
+            write!(f, ";WKST={}", NthWeekday::every(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parsing a `str` into an `RRule` accepts the value of an RFC 5545 `RRULE` property, optionally
+/// prefixed with `RRULE:`.
+///
+/// Rule part names and values are not case sensitive. The rule parts can be in any order, but
+/// each may appear only once.
+///
+/// # Errors
+///
+/// Returns an error if the `FREQ` rule part is missing (`NotEnough`), if a value is out of range
+/// (`OutOfRange`), or if a rule part is unknown, not supported, repeated or not valid with the
+/// frequency, or if both `COUNT` and `UNTIL` are given (`Invalid`).
+impl FromStr for RRule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<RRule> {
+        let s = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &s[6..],
+            _ => s,
+        };
+        if s.is_empty() {
+            return Err(TOO_SHORT);
+        }
+
+        let mut parts = [None; 9];
+        const NAMES: [&str; 9] = [
+            "FREQ",
+            "INTERVAL",
+            "COUNT",
+            "UNTIL",
+            "BYMONTH",
+            "BYMONTHDAY",
+            "BYDAY",
+            "BYSETPOS",
+            "WKST",
+        ];
+        for part in s.split(';') {
+            let (name, value) = part.split_once('=').ok_or(INVALID)?;
+            let index = NAMES.iter().position(|n| n.eq_ignore_ascii_case(name)).ok_or(INVALID)?;
+            if parts[index].replace(value).is_some() {
+                return Err(INVALID);
+            }
+        }
+        let frequency = parts[0].ok_or(NOT_ENOUGH)?;
+        let frequency = Frequency::ALL
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(frequency))
+            .map(|(freq, _)| *freq)
+            .ok_or(INVALID)?;
+        let mut rule = RRule::new(frequency);
+
+        if let Some(value) = parts[1] {
+            rule = rule.with_interval(parse_int(value)?).ok_or(OUT_OF_RANGE)?;
+        }
+        match (parts[2], parts[3]) {
+            (Some(_), Some(_)) => return Err(INVALID),
+            (Some(count), None) => rule = rule.with_count(parse_int(count)?),
+            (None, Some(until)) => {
+                let until = until.parse().map_err(|e: ParseError| match e.kind() {
+                    ParseErrorKind::OutOfRange => OUT_OF_RANGE,
+                    _ => INVALID,
+                })?;
+                rule = rule.with_until(until);
+            }
+            (None, None) => {}
+        }
+        if let Some(value) = parts[4] {
+            let months = parse_list(value, |s| match parse_int(s)? {
+                month @ 1..=12 => Ok(month),
+                _ => Err(OUT_OF_RANGE),
+            })?;
+            rule.by_month = months;
+        }
+        if let Some(value) = parts[5] {
+            let days = parse_list(value, |s| match parse_int(s)? {
+                day @ (-31..=-1 | 1..=31) => Ok(day),
+                _ => Err(OUT_OF_RANGE),
+            })?;
+            rule = rule.with_by_month_day(days).ok_or(INVALID)?;
+        }
+        if let Some(value) = parts[6] {
+            rule = rule.with_by_day(parse_list(value, str::parse)?).ok_or(INVALID)?;
+        }
+        if let Some(value) = parts[7] {
+            let positions = parse_list(value, |s| match parse_int(s)? {
+                pos @ (-366..=-1 | 1..=366) => Ok(pos),
+                _ => Err(OUT_OF_RANGE),
+            })?;
+            rule.by_set_pos = positions;
+        }
+        if let Some(value) = parts[8] {
+            let week_start: NthWeekday = value.parse()?;
+            if week_start.n != 0 {
+                return Err(INVALID);
+            }
+            rule.week_start = week_start.weekday;
+        }
+        Ok(rule)
+    }
+}
+
+/// Iterator over the occurrences of an [`RRule`] as local dates and times.
+///
+/// Created by [`RRule::naive_occurrences`].
+#[derive(Clone, Debug)]
+pub struct NaiveOccurrences<'a> {
+    generator: Generator<'a>,
+    remaining: Option<u32>,
+}
+
+impl Iterator for NaiveOccurrences<'_> {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let local = self.generator.next()?;
+        if self.generator.rule.until.map_or(false, |until| until.excludes(local, None)) {
+            self.remaining = Some(0);
+            return None;
+        }
+        self.remaining = self.remaining.map(|n| n - 1);
+        Some(local)
+    }
+}
+
+impl FusedIterator for NaiveOccurrences<'_> {}
+
+/// Iterator over the occurrences of an [`RRule`] in a time zone.
+///
+/// Created by [`RRule::occurrences`].
+#[derive(Clone)]
+pub struct Occurrences<'a, Tz: TimeZone> {
+    generator: Generator<'a>,
+    tz: Tz,
+    remaining: Option<u32>,
+}
+
+impl<Tz: TimeZone> Iterator for Occurrences<'_, Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let dt = self.generator.next().and_then(|local| {
+            let dt = self.tz.from_local_datetime_with(&local, Disambiguation::Compatible)?;
+            let until = self.generator.rule.until;
+            match until.map_or(false, |until| until.excludes(local, Some(dt.to_utc()))) {
+                true => None,
+                false => Some(dt),
+            }
+        });
+        self.remaining = match dt {
+            Some(_) => self.remaining.map(|n| n - 1),
+            None => Some(0),
+        };
+        dt
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Occurrences<'_, Tz> {}
+
+impl<Tz: TimeZone> fmt::Debug for Occurrences<'_, Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Occurrences")
+            .field("generator", &self.generator)
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Expands a rule into local dates and times, period by period, without applying `COUNT` and
+/// `UNTIL`.
+#[derive(Clone, Debug)]
+struct Generator<'a> {
+    rule: &'a RRule,
+    start: NaiveDateTime,
+    /// The number of the next period, counted in multiples of the interval from the start.
+    period: i64,
+    /// The occurrences left in the current period, in reverse order.
+    pending: Vec<NaiveDateTime>,
+    /// The number of periods in a row without occurrences.
+    empty_periods: u32,
+    done: bool,
+}
+
+impl<'a> Generator<'a> {
+    fn new(rule: &'a RRule, start: NaiveDateTime) -> Generator<'a> {
+        Generator { rule, start, period: 0, pending: Vec::new(), empty_periods: 0, done: false }
+    }
+
+    /// Computes the occurrences of the next period into `pending`.
+    ///
+    /// Returns `None` if the period is out of range.
+    fn expand_period(&mut self) -> Option<()> {
+        let rule = self.rule;
+        let steps = i64::from(rule.interval).checked_mul(self.period)?;
+        let start_date = self.start.date();
+        let mut next_period = self.period + 1;
+        let mut dates = Vec::new();
+        let mut occurrences = match rule.frequency {
+            Frequency::Yearly => {
+                let year = i32::try_from(i64::from(start_date.year()) + steps).ok()?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                if !rule.by_month.is_empty() {
+                    for &month in &rule.by_month {
+                        self.expand_month(year, month, &mut dates);
+                    }
+                } else if !rule.by_month_day.is_empty() {
+                    for month in 1..=12 {
+                        self.expand_month(year, month, &mut dates);
+                    }
+                } else if !rule.by_day.is_empty() {
+                    self.expand_scope(first, if first.leap_year() { 366 } else { 365 }, &mut dates);
+                } else {
+                    self.expand_month(year, start_date.month(), &mut dates);
+                }
+                self.at_start_time(dates)
+            }
+            Frequency::Monthly => {
+                let months = i64::from(start_date.year()) * 12 + i64::from(start_date.month0());
+                let months = months.checked_add(steps)?;
+                let year = i32::try_from(months.div_euclid(12)).ok()?;
+                let month = months.rem_euclid(12) as u32 + 1;
+                NaiveDate::from_ymd_opt(year, month, 1)?;
+                if rule.by_month.is_empty() || rule.by_month.contains(&month) {
+                    self.expand_month(year, month, &mut dates);
+                }
+                self.at_start_time(dates)
+            }
+            Frequency::Weekly => {
+                let week_start = start_date.weekday().num_days_from_monday() + 7
+                    - rule.week_start.num_days_from_monday();
+                let first = i64::from(start_date.num_days_from_ce()) - i64::from(week_start % 7);
+                let first = first.checked_add(steps.checked_mul(7)?)?;
+                for day in first..first + 7 {
+                    let date = NaiveDate::from_num_days_from_ce_opt(i32::try_from(day).ok()?)?;
+                    let weekday_matches = match rule.by_day.is_empty() {
+                        true => date.weekday() == start_date.weekday(),
+                        false => rule.by_day.iter().any(|wd| wd.weekday == date.weekday()),
+                    };
+                    if weekday_matches
+                        && (rule.by_month.is_empty() || rule.by_month.contains(&date.month()))
+                    {
+                        dates.push(date);
+                    }
+                }
+                self.at_start_time(dates)
+            }
+            Frequency::Daily => {
+                let day = i64::from(start_date.num_days_from_ce()).checked_add(steps)?;
+                let date = NaiveDate::from_num_days_from_ce_opt(i32::try_from(day).ok()?)?;
+                if self.date_matches(date) {
+                    dates.push(date);
+                }
+                self.at_start_time(dates)
+            }
+            frequency => {
+                let step = frequency.seconds()?.checked_mul(i64::from(rule.interval))?;
+                let offset = TimeDelta::try_seconds(steps.checked_mul(frequency.seconds()?)?)?;
+                let dt = self.start.checked_add_signed(offset)?;
+                if self.date_matches(dt.date()) {
+                    Vec::from([dt])
+                } else {
+                    // skip to the first period on the next day
+                    let next_day = dt.date().succ_opt()?.and_time(NaiveTime::MIN);
+                    let seconds = next_day.signed_duration_since(self.start).num_seconds();
+                    next_period = next_period.max((seconds + step - 1) / step);
+                    Vec::new()
+                }
+            }
+        };
+
+        if !rule.by_set_pos.is_empty() {
+            let len = occurrences.len() as i32;
+            let mut selected: Vec<_> = (rule.by_set_pos.iter())
+                .map(|&pos| if pos > 0 { pos - 1 } else { len + pos })
+                .filter(|index| (0..len).contains(index))
+                .map(|index| occurrences[index as usize])
+                .collect();
+            selected.sort_unstable();
+            selected.dedup();
+            occurrences = selected;
+        }
+        occurrences.retain(|dt| *dt >= self.start);
+        occurrences.reverse();
+        self.empty_periods = if occurrences.is_empty() { self.empty_periods + 1 } else { 0 };
+        self.pending = occurrences;
+        self.period = next_period;
+        Some(())
+    }
+
+    /// Adds the dates selected by the rule in `month` of `year`.
+    fn expand_month(&self, year: i32, month: u32, dates: &mut Vec<NaiveDate>) {
+        if let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) {
+            self.expand_scope(first, days_in_month(first), dates);
+        }
+    }
+
+    /// Adds the dates selected by `BYMONTHDAY` and `BYDAY` in the `len` days from `first`, which
+    /// is a month or, without `BYMONTHDAY`, a year.
+    fn expand_scope(&self, first: NaiveDate, len: u32, dates: &mut Vec<NaiveDate>) {
+        let rule = self.rule;
+        let mut push = |index: u32| dates.extend(first.checked_add_days(Days::new(index.into())));
+        if !rule.by_month_day.is_empty() {
+            for &day in &rule.by_month_day {
+                let day = if day > 0 { day } else { len as i32 + 1 + day };
+                if day < 1 || day > len as i32 {
+                    continue;
+                }
+                let index = day as u32 - 1;
+                let weekday = first.weekday().num_days_from_monday() + index;
+                let weekday = WEEKDAYS[weekday as usize % 7].0;
+                if rule.by_day.is_empty()
+                    || rule.by_day.iter().any(|wd| wd.matches(weekday, index, len))
+                {
+                    push(index);
+                }
+            }
+        } else if !rule.by_day.is_empty() {
+            for wd in &rule.by_day {
+                let offset = (wd.weekday.num_days_from_monday() + 7
+                    - first.weekday().num_days_from_monday())
+                    % 7;
+                let count = (len - 1 - offset) / 7 + 1;
+                match wd.n {
+                    0 => (0..count).for_each(|i| push(offset + 7 * i)),
+                    n if n > 0 && n as u32 <= count => push(offset + 7 * (n as u32 - 1)),
+                    n if n < 0 && n.unsigned_abs() as u32 <= count => {
+                        push(offset + 7 * (count - n.unsigned_abs() as u32))
+                    }
+                    _ => {}
+                }
+            }
+        } else if self.start.day() <= len {
+            push(self.start.day() - 1);
+        }
+    }
+
+    /// Returns `true` if `date` matches the `BYMONTH`, `BYMONTHDAY` and `BYDAY` rule parts, for
+    /// rules that repeat daily or more often.
+    fn date_matches(&self, date: NaiveDate) -> bool {
+        let rule = self.rule;
+        let len = days_in_month(date) as i32;
+        (rule.by_month.is_empty() || rule.by_month.contains(&date.month()))
+            && (rule.by_month_day.is_empty()
+                || rule.by_month_day.iter().any(|&day| {
+                    let day = if day > 0 { day } else { len + 1 + day };
+                    day == date.day() as i32
+                }))
+            && (rule.by_day.is_empty() || rule.by_day.iter().any(|wd| wd.weekday == date.weekday()))
+    }
+
+    /// Sorts the dates and combines them with the time of the start.
+    fn at_start_time(&self, mut dates: Vec<NaiveDate>) -> Vec<NaiveDateTime> {
+        dates.sort_unstable();
+        dates.dedup();
+        dates.into_iter().map(|date| date.and_time(self.start.time())).collect()
+    }
+}
+
+impl Iterator for Generator<'_> {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        loop {
+            if let Some(dt) = self.pending.pop() {
+                return Some(dt);
+            }
+            if self.done || self.empty_periods > self.rule.frequency.cycle() {
+                self.done = true;
+                return None;
+            }
+            if self.expand_period().is_none() {
+                self.done = true;
+            }
+        }
+    }
+}
+
+/// The two-letter codes of the days of the week in RFC 5545.
+const WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Mon, "MO"),
+    (Weekday::Tue, "TU"),
+    (Weekday::Wed, "WE"),
+    (Weekday::Thu, "TH"),
+    (Weekday::Fri, "FR"),
+    (Weekday::Sat, "SA"),
+    (Weekday::Sun, "SU"),
+];
+
+/// Returns the number of days in the month of `date`.
+fn days_in_month(date: NaiveDate) -> u32 {
+    match date.month() {
+        2 if date.leap_year() => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses an integer with an optional sign.
+fn parse_int<T: FromStr>(s: &str) -> ParseResult<T> {
+    let digits = s.strip_prefix(|c: char| c == '+' || c == '-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(INVALID);
+    }
+    s.parse().map_err(|_| OUT_OF_RANGE)
+}
+
+/// Parses a comma-separated list of values.
+fn parse_list<T>(s: &str, f: impl Fn(&str) -> ParseResult<T>) -> ParseResult<Vec<T>> {
+    s.split(',').map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frequency, NthWeekday, RRule, Until};
+    use crate::format::ParseErrorKind;
+    use crate::naive::{NaiveDate, NaiveDateTime};
+    use crate::offset::{TimeZone, Utc};
+    use crate::Weekday;
+
+    /// Returns the first occurrences of `rule` from `start` as `YYYY-MM-DD` strings.
+    fn dates(rule: &str, start: NaiveDateTime, limit: usize) -> Vec<String> {
+        let rule: RRule = rule.parse().unwrap();
+        rule.naive_occurrences(start).take(limit).map(|dt| dt.date().to_string()).collect()
+    }
+
+    #[test]
+    fn test_rrule_parse_and_format() {
+        let rule: RRule =
+            "RRULE:freq=monthly;interval=2;byday=+2tu,-1FR;bysetpos=1;wkst=SU;count=5"
+                .parse()
+                .unwrap();
+        assert_eq!(rule.frequency(), Frequency::Monthly);
+        assert_eq!(rule.interval(), 2);
+        assert_eq!(rule.count(), Some(5));
+        assert_eq!(
+            rule.by_day(),
+            [NthWeekday::nth(2, Weekday::Tue).unwrap(), NthWeekday::nth(-1, Weekday::Fri).unwrap()]
+        );
+        assert_eq!(rule.by_set_pos(), [1]);
+        assert_eq!(rule.week_start(), Weekday::Sun);
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=5;BYDAY=2TU,-1FR;BYSETPOS=1;WKST=SU"
+        );
+
+        for s in [
+            "FREQ=DAILY",
+            "FREQ=YEARLY;UNTIL=20250101;BYMONTH=1,7;BYMONTHDAY=1,-1",
+            "FREQ=WEEKLY;UNTIL=20250101T090000;BYDAY=MO,WE",
+            "FREQ=HOURLY;INTERVAL=6;UNTIL=20250101T090000Z",
+        ] {
+            assert_eq!(s.parse::<RRule>().unwrap().to_string(), s);
+        }
+        let until = "FREQ=DAILY;UNTIL=19971224T000000Z".parse::<RRule>().unwrap().until();
+        assert_eq!(until, Some(Until::Utc(Utc.with_ymd_and_hms(1997, 12, 24, 0, 0, 0).unwrap())));
+
+        let parse = |s: &str| s.parse::<RRule>().map_err(|e| e.kind());
+        assert_eq!(parse(""), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("COUNT=5"), Err(ParseErrorKind::NotEnough));
+        assert_eq!(parse("FREQ=FORTNIGHTLY"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;FREQ=DAILY"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;BYHOUR=9"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;COUNT"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;COUNT=5;UNTIL=20250101"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;INTERVAL=0"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("FREQ=DAILY;INTERVAL=x"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;BYMONTH=13"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("FREQ=DAILY;BYMONTHDAY=0"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("FREQ=WEEKLY;BYMONTHDAY=1"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=WEEKLY;BYDAY=1MO"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=MONTHLY;BYDAY=54MO"), Err(ParseErrorKind::OutOfRange));
+        assert_eq!(parse("FREQ=MONTHLY;BYDAY=XX"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=MONTHLY;BYDAY=MO,"), Err(ParseErrorKind::TooShort));
+        assert_eq!(parse("FREQ=MONTHLY;WKST=1MO"), Err(ParseErrorKind::Invalid));
+        assert_eq!(parse("FREQ=DAILY;UNTIL=20251301"), Err(ParseErrorKind::OutOfRange));
+    }
+
+    #[test]
+    fn test_rrule_builder() {
+        let rule = RRule::new(Frequency::Weekly);
+        assert_eq!(rule.clone().with_interval(0), None);
+        assert_eq!(rule.clone().with_by_month([0]), None);
+        assert_eq!(rule.clone().with_by_month_day([1]), None);
+        assert_eq!(rule.clone().with_by_day([NthWeekday::nth(1, Weekday::Mon).unwrap()]), None);
+        assert_eq!(rule.clone().with_by_set_pos([367]), None);
+        assert_eq!(NthWeekday::nth(0, Weekday::Mon), None);
+        assert_eq!(NthWeekday::nth(-53, Weekday::Mon).unwrap().n(), Some(-53));
+
+        let until = Until::Date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        let rule = rule.with_count(3).with_until(until);
+        assert_eq!((rule.count(), rule.until()), (None, Some(until)));
+        let rule = rule.with_count(3);
+        assert_eq!((rule.count(), rule.until()), (Some(3), None));
+    }
+
+    #[test]
+    fn test_rrule_rfc5545_examples() {
+        let ymd_hms = |y, m, d, h, n, s| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap()
+        };
+
+        let start = ymd_hms(1997, 9, 2, 9, 0, 0);
+        // every other week on Tuesday and Thursday, for 8 occurrences
+        assert_eq!(
+            dates("FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH", start, 100),
+            [
+                "1997-09-02",
+                "1997-09-04",
+                "1997-09-16",
+                "1997-09-18",
+                "1997-09-30",
+                "1997-10-02",
+                "1997-10-14",
+                "1997-10-16"
+            ]
+        );
+        // the week start changes which weeks are selected
+        let start = ymd_hms(1997, 8, 5, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", start, 100),
+            ["1997-08-05", "1997-08-10", "1997-08-19", "1997-08-24"]
+        );
+        assert_eq!(
+            dates("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", start, 100),
+            ["1997-08-05", "1997-08-17", "1997-08-19", "1997-08-31"]
+        );
+        // the third instance of one of Tuesday, Wednesday or Thursday, for 3 months
+        let start = ymd_hms(1997, 9, 4, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3", start, 100),
+            ["1997-09-04", "1997-10-07", "1997-11-06"]
+        );
+        // every Friday the 13th
+        let start = ymd_hms(1997, 9, 2, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", start, 5),
+            ["1998-02-13", "1998-03-13", "1998-11-13", "1999-08-13", "2000-10-13"]
+        );
+        // the third to last day of the month
+        let start = ymd_hms(1997, 9, 28, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYMONTHDAY=-3", start, 4),
+            ["1997-09-28", "1997-10-29", "1997-11-28", "1997-12-29"]
+        );
+        // Monday of week 20 of the year, as the 20th Monday
+        let start = ymd_hms(1997, 5, 19, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=YEARLY;BYDAY=20MO", start, 3),
+            ["1997-05-19", "1998-05-18", "1999-05-17"]
+        );
+        // every Thursday in March
+        let start = ymd_hms(1997, 3, 13, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=YEARLY;BYMONTH=3;BYDAY=TH", start, 5),
+            ["1997-03-13", "1997-03-20", "1997-03-27", "1998-03-05", "1998-03-12"]
+        );
+        // the last work day of the month
+        let start = ymd_hms(2024, 1, 1, 9, 0, 0);
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", start, 4),
+            ["2024-01-31", "2024-02-29", "2024-03-29", "2024-04-30"]
+        );
+    }
+
+    #[test]
+    fn test_rrule_defaults_from_start() {
+        let ymd_hms = |y, m, d, h, n, s| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap()
+        };
+
+        // the day of the month of the start, skipping months without it
+        let start = ymd_hms(2024, 1, 31, 9, 0, 0);
+        assert_eq!(dates("FREQ=MONTHLY", start, 3), ["2024-01-31", "2024-03-31", "2024-05-31"]);
+        let start = ymd_hms(2024, 2, 29, 9, 0, 0);
+        assert_eq!(dates("FREQ=YEARLY", start, 2), ["2024-02-29", "2028-02-29"]);
+        let start = ymd_hms(2024, 1, 3, 9, 0, 0);
+        assert_eq!(dates("FREQ=WEEKLY;BYMONTH=2", start, 2), ["2024-02-07", "2024-02-14"]);
+        assert_eq!(
+            dates("FREQ=DAILY;INTERVAL=10;BYDAY=SA,SU", start, 3),
+            ["2024-01-13", "2024-03-03", "2024-03-23"]
+        );
+        assert_eq!(
+            dates("FREQ=YEARLY;BYMONTHDAY=1", start, 3),
+            ["2024-02-01", "2024-03-01", "2024-04-01"]
+        );
+    }
+
+    #[test]
+    fn test_rrule_end() {
+        let ymd_hms = |y, m, d, h, n, s| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap()
+        };
+
+        let start = ymd_hms(2024, 10, 1, 9, 30, 0);
+        let rule: RRule = "FREQ=MONTHLY;BYDAY=2TU;UNTIL=20241112T093000".parse().unwrap();
+        assert_eq!(rule.naive_occurrences(start).count(), 2);
+        let rule: RRule = "FREQ=MONTHLY;BYDAY=2TU;UNTIL=20241112T092959".parse().unwrap();
+        assert_eq!(rule.naive_occurrences(start).count(), 1);
+        let rule: RRule = "FREQ=MONTHLY;BYDAY=2TU;COUNT=0".parse().unwrap();
+        assert_eq!(rule.naive_occurrences(start).next(), None);
+
+        // rules without occurrences end
+        let rule: RRule = "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30".parse().unwrap();
+        assert_eq!(rule.naive_occurrences(start).next(), None);
+        let rule: RRule = "FREQ=DAILY;BYMONTH=2;BYMONTHDAY=30".parse().unwrap();
+        assert_eq!(rule.naive_occurrences(start).next(), None);
+        let rule: RRule = "FREQ=MINUTELY;BYMONTH=4;BYMONTHDAY=31".parse().unwrap();
+        assert_eq!(rule.naive_occurrences(start).next(), None);
+
+        // the end of the supported range
+        let rule = RRule::new(Frequency::Yearly).with_interval(100_000).unwrap();
+        assert_eq!(rule.naive_occurrences(start).count(), 3);
+    }
+
+    #[test]
+    fn test_rrule_sub_daily() {
+        let ymd_hms = |y, m, d, h, n, s| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap()
+        };
+
+        let start = ymd_hms(2024, 1, 5, 22, 0, 0);
+        let rule: RRule = "FREQ=HOURLY;INTERVAL=5;BYDAY=FR,MO;COUNT=4".parse().unwrap();
+        let times: Vec<_> = rule.naive_occurrences(start).map(|dt| dt.to_string()).collect();
+        assert_eq!(
+            times,
+            [
+                "2024-01-05 22:00:00",
+                "2024-01-08 00:00:00",
+                "2024-01-08 05:00:00",
+                "2024-01-08 10:00:00"
+            ]
+        );
+        let rule: RRule = "FREQ=MINUTELY;INTERVAL=90;COUNT=3".parse().unwrap();
+        let times: Vec<_> = rule.naive_occurrences(start).map(|dt| dt.to_string()).collect();
+        assert_eq!(times, ["2024-01-05 22:00:00", "2024-01-05 23:30:00", "2024-01-06 01:00:00"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_rrule_occurrences_dst() {
+        let prague = crate::TzInfo::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let start = prague.with_ymd_and_hms(2024, 3, 29, 2, 30, 0).unwrap();
+        let rule: RRule = "FREQ=DAILY;COUNT=3".parse().unwrap();
+        let times: Vec<_> = rule.occurrences(&start).map(|dt| dt.to_rfc3339()).collect();
+        // 02:30 does not exist on March 31
+        assert_eq!(
+            times,
+            ["2024-03-29T02:30:00+01:00", "2024-03-30T02:30:00+01:00", "2024-03-31T03:30:00+02:00"]
+        );
+
+        // the end in UTC is compared to the instant
+        let rule: RRule = "FREQ=DAILY;UNTIL=20240330T013000Z".parse().unwrap();
+        assert_eq!(rule.occurrences(&start).count(), 2);
+        let rule: RRule = "FREQ=DAILY;UNTIL=20240330T012959Z".parse().unwrap();
+        assert_eq!(rule.occurrences(&start).count(), 1);
+    }
+}
@@ -10,9 +10,9 @@
 
 //! Temporal quantification
 
+use core::fmt;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 use core::time::Duration;
-use core::{fmt, i64};
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -378,6 +378,17 @@ impl TimeDelta {
         TimeDelta::new(secs, nanos as u32)
     }
 
+    /// Multiply a `TimeDelta` with a i32, returning `None` if overflow occurred.
+    #[must_use]
+    pub const fn checked_mul(&self, rhs: i32) -> Option<TimeDelta> {
+        // Multiply nanoseconds as i64, because it cannot overflow that way.
+        let total_nanos = self.nanos as i64 * rhs as i64;
+        let (extra_secs, nanos) = div_mod_floor_64(total_nanos, NANOS_PER_SEC as i64);
+        let secs = try_opt!(self.secs.checked_mul(rhs as i64));
+        let secs = try_opt!(secs.checked_add(extra_secs));
+        TimeDelta::new(secs, nanos as u32)
+    }
+
     /// Returns the `TimeDelta` as an absolute (non-negative) value.
     #[inline]
     pub const fn abs(&self) -> TimeDelta {
@@ -618,6 +629,605 @@ impl arbitrary::Arbitrary<'_> for TimeDelta {
     }
 }
 
+/// documented at re-export site
+#[cfg(feature = "serde")]
+pub(crate) mod serde {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::TimeDelta;
+
+    /// Serialize into an ISO 8601 duration string, as written by the `Display` implementation.
+    ///
+    /// See [the `serde::time_delta` module](crate::serde::time_delta) for alternate
+    /// serializations.
+    impl ser::Serialize for TimeDelta {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct TimeDeltaVisitor;
+
+    impl<'de> de::Visitor<'de> for TimeDeltaVisitor {
+        type Value = TimeDelta;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an ISO 8601 duration string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            value.parse().map_err(E::custom)
+        }
+    }
+
+    /// Deserialize from an ISO 8601 duration string, see the `FromStr` implementation.
+    ///
+    /// See [the `serde::time_delta` module](crate::serde::time_delta) for alternate
+    /// deserialization formats.
+    impl<'de> de::Deserialize<'de> for TimeDelta {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_str(TimeDeltaVisitor)
+        }
+    }
+
+    struct SecondsVisitor;
+
+    impl<'de> de::Visitor<'de> for SecondsVisitor {
+        type Value = TimeDelta;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a duration in seconds")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            TimeDelta::try_seconds(value)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            i64::try_from(value)
+                .ok()
+                .and_then(TimeDelta::try_seconds)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+
+    struct MillisecondsVisitor;
+
+    impl<'de> de::Visitor<'de> for MillisecondsVisitor {
+        type Value = TimeDelta;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a duration in milliseconds")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            TimeDelta::try_milliseconds(value)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            i64::try_from(value)
+                .ok()
+                .and_then(TimeDelta::try_milliseconds)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+
+    struct NanosecondsVisitor;
+
+    impl<'de> de::Visitor<'de> for NanosecondsVisitor {
+        type Value = TimeDelta;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a duration in nanoseconds")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(TimeDelta::nanoseconds(value))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            i64::try_from(value)
+                .map(TimeDelta::nanoseconds)
+                .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+
+    /// Visitor for the `*_option` modules, which defers to the visitor for the inner value.
+    struct OptionVisitor<V>(V);
+
+    impl<'de, V: de::Visitor<'de, Value = TimeDelta>> de::Visitor<'de> for OptionVisitor<V> {
+        type Value = Option<TimeDelta>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            self.0.expecting(formatter)?;
+            formatter.write_str(" or none")
+        }
+
+        fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(self.0).map(Some)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of seconds
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serializing truncates the `TimeDelta`
+    /// to whole seconds, towards zero.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::time_delta::seconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "seconds")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::try_minutes(5).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":300}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::try_minutes(5).unwrap());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod seconds {
+        use serde::{de, ser};
+
+        use super::SecondsVisitor;
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` into an integer number of whole seconds
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_seconds())
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of seconds
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(SecondsVisitor)
+        }
+    }
+
+    /// Ser/de an optional `TimeDelta` to/from an integer number of seconds or none
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::time_delta::seconds_option;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(default, with = "seconds_option")]
+    ///     timeout: Option<TimeDelta>,
+    /// }
+    ///
+    /// let my_s = S { timeout: Some(TimeDelta::try_minutes(5).unwrap()) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":300}"#);
+    /// let my_s: S = serde_json::from_str(r#"{"timeout":null}"#)?;
+    /// assert_eq!(my_s.timeout, None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod seconds_option {
+        use serde::{de, ser};
+
+        use super::{OptionVisitor, SecondsVisitor};
+        use crate::TimeDelta;
+
+        /// Serialize an optional `TimeDelta` into an integer number of whole seconds or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(opt: &Option<TimeDelta>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            match *opt {
+                Some(ref delta) => serializer.serialize_some(&delta.num_seconds()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserialize an optional `TimeDelta` from an integer number of seconds or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<TimeDelta>, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_option(OptionVisitor(SecondsVisitor))
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of milliseconds
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serializing truncates the `TimeDelta`
+    /// to whole milliseconds, towards zero.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::time_delta::milliseconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "milliseconds")]
+    ///     timeout: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { timeout: TimeDelta::try_milliseconds(-1500).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":-1500}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.timeout, TimeDelta::try_milliseconds(-1500).unwrap());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod milliseconds {
+        use serde::{de, ser};
+
+        use super::MillisecondsVisitor;
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` into an integer number of whole milliseconds
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_milliseconds())
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of milliseconds
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(MillisecondsVisitor)
+        }
+    }
+
+    /// Ser/de an optional `TimeDelta` to/from an integer number of milliseconds or none
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::time_delta::milliseconds_option;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(default, with = "milliseconds_option")]
+    ///     timeout: Option<TimeDelta>,
+    /// }
+    ///
+    /// let my_s = S { timeout: Some(TimeDelta::try_milliseconds(1500).unwrap()) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"timeout":1500}"#);
+    /// let my_s: S = serde_json::from_str(r#"{}"#)?;
+    /// assert_eq!(my_s.timeout, None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod milliseconds_option {
+        use serde::{de, ser};
+
+        use super::{MillisecondsVisitor, OptionVisitor};
+        use crate::TimeDelta;
+
+        /// Serialize an optional `TimeDelta` into an integer number of whole milliseconds or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(opt: &Option<TimeDelta>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            match *opt {
+                Some(ref delta) => serializer.serialize_some(&delta.num_milliseconds()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserialize an optional `TimeDelta` from an integer number of milliseconds or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<TimeDelta>, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_option(OptionVisitor(MillisecondsVisitor))
+        }
+    }
+
+    /// Ser/de a `TimeDelta` to/from an integer number of nanoseconds
+    ///
+    /// Intended for use with `serde`'s `with` attribute. Serializing fails for a `TimeDelta` of
+    /// more than about 292 years, which does not fit in an `i64` of nanoseconds.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::time_delta::nanoseconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "nanoseconds")]
+    ///     elapsed: TimeDelta,
+    /// }
+    ///
+    /// let my_s = S { elapsed: TimeDelta::new(1, 234).unwrap() };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"elapsed":1000000234}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.elapsed, TimeDelta::new(1, 234).unwrap());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod nanoseconds {
+        use serde::{de, ser};
+
+        use super::NanosecondsVisitor;
+        use crate::TimeDelta;
+
+        /// Serialize a `TimeDelta` into an integer number of nanoseconds
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the number of nanoseconds does not fit in an `i64`.
+        pub fn serialize<S>(delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_i64(delta.num_nanoseconds().ok_or(ser::Error::custom(
+                "value out of range for a duration with nanosecond precision",
+            ))?)
+        }
+
+        /// Deserialize a `TimeDelta` from an integer number of nanoseconds
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<TimeDelta, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_i64(NanosecondsVisitor)
+        }
+    }
+
+    /// Ser/de an optional `TimeDelta` to/from an integer number of nanoseconds or none
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::TimeDelta;
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use chrono::serde::time_delta::nanoseconds_option;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(default, with = "nanoseconds_option")]
+    ///     elapsed: Option<TimeDelta>,
+    /// }
+    ///
+    /// let my_s = S { elapsed: Some(TimeDelta::new(1, 234).unwrap()) };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"elapsed":1000000234}"#);
+    /// let my_s: S = serde_json::from_str(r#"{"elapsed":null}"#)?;
+    /// assert_eq!(my_s.elapsed, None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mod nanoseconds_option {
+        use serde::{de, ser};
+
+        use super::{NanosecondsVisitor, OptionVisitor};
+        use crate::TimeDelta;
+
+        /// Serialize an optional `TimeDelta` into an integer number of nanoseconds or none
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the number of nanoseconds does not fit in an `i64`.
+        pub fn serialize<S>(opt: &Option<TimeDelta>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            match *opt {
+                Some(ref delta) => serializer.serialize_some(&delta.num_nanoseconds().ok_or(
+                    ser::Error::custom(
+                        "value out of range for a duration with nanosecond precision",
+                    ),
+                )?),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserialize an optional `TimeDelta` from an integer number of nanoseconds or none
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<TimeDelta>, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            d.deserialize_option(OptionVisitor(NanosecondsVisitor))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::TimeDelta;
+        use serde_derive::{Deserialize, Serialize};
+
+        #[test]
+        fn test_serde_serialize() {
+            let to_string = |delta| serde_json::to_string(&delta).unwrap();
+            assert_eq!(to_string(TimeDelta::zero()), r#""P0D""#);
+            assert_eq!(to_string(TimeDelta::new(90, 500_000_000).unwrap()), r#""PT90.5S""#);
+            assert_eq!(to_string(-TimeDelta::new(1, 0).unwrap()), r#""-PT1S""#);
+        }
+
+        #[test]
+        fn test_serde_deserialize() {
+            let from_str = |s| serde_json::from_str::<TimeDelta>(s);
+            assert_eq!(from_str(r#""PT1H30M""#).unwrap(), TimeDelta::try_minutes(90).unwrap());
+            assert_eq!(from_str(r#""-P3W""#).unwrap(), TimeDelta::try_weeks(-3).unwrap());
+            assert!(from_str(r#""P1Y""#).is_err());
+            assert!(from_str(r#""1 hour""#).is_err());
+            assert!(from_str("90").is_err());
+        }
+
+        #[test]
+        fn test_serde_bincode() {
+            // Bincode is relevant to test separately from JSON because
+            // it is not self-describing.
+            use bincode::{deserialize, serialize};
+
+            let delta = TimeDelta::new(-86_400, 1).unwrap();
+            let encoded = serialize(&delta).unwrap();
+            let decoded: TimeDelta = deserialize(&encoded).unwrap();
+            assert_eq!(delta, decoded);
+        }
+
+        #[test]
+        fn test_serde_integer_modules() {
+            #[derive(Debug, PartialEq, Deserialize, Serialize)]
+            struct S {
+                #[serde(with = "crate::serde::time_delta::seconds")]
+                secs: TimeDelta,
+                #[serde(with = "crate::serde::time_delta::milliseconds")]
+                millis: TimeDelta,
+                #[serde(with = "crate::serde::time_delta::nanoseconds")]
+                nanos: TimeDelta,
+            }
+
+            let s = S {
+                secs: TimeDelta::try_seconds(-5).unwrap(),
+                millis: TimeDelta::try_milliseconds(1234).unwrap(),
+                nanos: TimeDelta::nanoseconds(-1),
+            };
+            let json = serde_json::to_string(&s).unwrap();
+            assert_eq!(json, r#"{"secs":-5,"millis":1234,"nanos":-1}"#);
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), s);
+
+            // truncated towards zero
+            let s = S {
+                secs: TimeDelta::try_milliseconds(-1500).unwrap(),
+                millis: TimeDelta::nanoseconds(1_999_999),
+                nanos: TimeDelta::zero(),
+            };
+            let json = serde_json::to_string(&s).unwrap();
+            assert_eq!(json, r#"{"secs":-1,"millis":1,"nanos":0}"#);
+
+            // out of range
+            let too_large = format!(r#"{{"secs":{},"millis":0,"nanos":0}}"#, i64::MAX);
+            assert!(serde_json::from_str::<S>(&too_large).is_err());
+            let too_large = format!(r#"{{"secs":0,"millis":{},"nanos":0}}"#, i64::MIN);
+            assert!(serde_json::from_str::<S>(&too_large).is_err());
+            let too_large = format!(r#"{{"secs":0,"millis":0,"nanos":{}}}"#, u64::MAX);
+            assert!(serde_json::from_str::<S>(&too_large).is_err());
+            let s = S {
+                secs: TimeDelta::zero(),
+                millis: TimeDelta::zero(),
+                nanos: TimeDelta::max_value(),
+            };
+            assert!(serde_json::to_string(&s).is_err());
+        }
+
+        #[test]
+        fn test_serde_option_modules() {
+            #[derive(Debug, PartialEq, Deserialize, Serialize)]
+            struct S {
+                #[serde(default, with = "crate::serde::time_delta::seconds_option")]
+                secs: Option<TimeDelta>,
+                #[serde(default, with = "crate::serde::time_delta::milliseconds_option")]
+                millis: Option<TimeDelta>,
+                #[serde(default, with = "crate::serde::time_delta::nanoseconds_option")]
+                nanos: Option<TimeDelta>,
+            }
+
+            let s = S {
+                secs: Some(TimeDelta::try_seconds(5).unwrap()),
+                millis: None,
+                nanos: Some(TimeDelta::nanoseconds(7)),
+            };
+            let json = serde_json::to_string(&s).unwrap();
+            assert_eq!(json, r#"{"secs":5,"millis":null,"nanos":7}"#);
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), s);
+            let empty = S { secs: None, millis: None, nanos: None };
+            assert_eq!(serde_json::from_str::<S>("{}").unwrap(), empty);
+            assert!(serde_json::from_str::<S>(r#"{"secs":"PT1S"}"#).is_err());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::OutOfRangeError;
@@ -1065,6 +1675,15 @@ mod tests {
         );
         assert!(milliseconds(-i64::MAX).checked_sub(&milliseconds(1)).is_none());
         assert!(milliseconds(-i64::MAX).checked_sub(&TimeDelta::nanoseconds(1)).is_none());
+
+        assert_eq!(
+            TimeDelta::nanoseconds(-1_000_000_001).checked_mul(3),
+            Some(TimeDelta::nanoseconds(-3_000_000_003))
+        );
+        assert_eq!(milliseconds(i64::MAX / 2).checked_mul(2), Some(milliseconds(i64::MAX - 1)));
+        assert!(milliseconds(i64::MAX / 2 + 1).checked_mul(2).is_none());
+        assert!(milliseconds(i64::MAX).checked_mul(-1).is_some());
+        assert!(milliseconds(i64::MAX).checked_mul(i32::MIN).is_none());
     }
 
     #[test]